@@ -10,7 +10,7 @@ use vm_core::{
 };
 use winter_air::{
     Air, AirContext, Assertion, AuxTraceRandElements, EvaluationFrame,
-    ProofOptions as WinterProofOptions, TraceInfo, TransitionConstraintDegree,
+    ProofOptions as WinterProofOptions, TraceInfo,
 };
 
 mod constraints;
@@ -33,7 +33,7 @@ pub use vm_core::{
     utils::{DeserializationError, ToElements},
     Felt, FieldElement, StarkField,
 };
-pub use winter_air::FieldExtension;
+pub use winter_air::{FieldExtension, TransitionConstraintDegree};
 
 // PROCESSOR AIR
 // ================================================================================================
@@ -58,24 +58,8 @@ impl Air for ProcessorAir {
     type PublicInputs = PublicInputs;
 
     fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: WinterProofOptions) -> Self {
-        // --- system -----------------------------------------------------------------------------
-        let mut main_degrees = vec![
-            TransitionConstraintDegree::new(1), // clk' = clk + 1
-        ];
-
-        // --- stack constraints -------------------------------------------------------------------
-        let mut stack_degrees = stack::get_transition_constraint_degrees();
-        main_degrees.append(&mut stack_degrees);
-
-        // --- range checker ----------------------------------------------------------------------
-        let mut range_checker_degrees = range::get_transition_constraint_degrees();
-        main_degrees.append(&mut range_checker_degrees);
-
-        let aux_degrees = range::get_aux_transition_constraint_degrees();
-
-        // --- chiplets (hasher, bitwise, memory) -------------------------
-        let mut chiplets_degrees = chiplets::get_transition_constraint_degrees();
-        main_degrees.append(&mut chiplets_degrees);
+        let main_degrees = get_transition_constraint_degrees();
+        let aux_degrees = get_aux_transition_constraint_degrees();
 
         // Define the transition constraint ranges.
         let constraint_ranges = TransitionConstraintRange::new(
@@ -243,6 +227,103 @@ impl Air for ProcessorAir {
     }
 }
 
+// TRANSITION CONSTRAINT DEGREES
+// ================================================================================================
+
+/// Returns the degrees of the transition constraints of the main trace segment in the order in
+/// which they are evaluated by [ProcessorAir].
+fn get_transition_constraint_degrees() -> Vec<TransitionConstraintDegree> {
+    // --- system ---------------------------------------------------------------------------------
+    let mut main_degrees = vec![
+        TransitionConstraintDegree::new(1), // clk' = clk + 1
+    ];
+
+    // --- stack constraints ----------------------------------------------------------------------
+    let mut stack_degrees = stack::get_transition_constraint_degrees();
+    main_degrees.append(&mut stack_degrees);
+
+    // --- range checker --------------------------------------------------------------------------
+    let mut range_checker_degrees = range::get_transition_constraint_degrees();
+    main_degrees.append(&mut range_checker_degrees);
+
+    // --- chiplets (hasher, bitwise, memory) -----------------------------------------------------
+    let mut chiplets_degrees = chiplets::get_transition_constraint_degrees();
+    main_degrees.append(&mut chiplets_degrees);
+
+    main_degrees
+}
+
+/// Returns the degrees of the transition constraints of the auxiliary trace segment in the order
+/// in which they are evaluated by [ProcessorAir].
+fn get_aux_transition_constraint_degrees() -> Vec<TransitionConstraintDegree> {
+    range::get_aux_transition_constraint_degrees()
+}
+
+// AIR INFO
+// ================================================================================================
+
+/// Returns a description of the shape of [ProcessorAir].
+///
+/// This can be used to check that a prover and a verifier agree on the AIR without generating or
+/// verifying a proof.
+pub fn air_info() -> AirInfo {
+    AirInfo {
+        main_trace_width: TRACE_WIDTH,
+        aux_trace_width: AUX_TRACE_WIDTH,
+        main_constraint_degrees: get_transition_constraint_degrees(),
+        aux_constraint_degrees: get_aux_transition_constraint_degrees(),
+    }
+}
+
+/// Describes the number of trace columns and the transition constraints of [ProcessorAir].
+#[derive(Debug, Clone)]
+pub struct AirInfo {
+    main_trace_width: usize,
+    aux_trace_width: usize,
+    main_constraint_degrees: Vec<TransitionConstraintDegree>,
+    aux_constraint_degrees: Vec<TransitionConstraintDegree>,
+}
+
+impl AirInfo {
+    /// Returns the number of columns in the main segment of the execution trace.
+    pub fn main_trace_width(&self) -> usize {
+        self.main_trace_width
+    }
+
+    /// Returns the number of columns in the auxiliary segment of the execution trace.
+    pub fn aux_trace_width(&self) -> usize {
+        self.aux_trace_width
+    }
+
+    /// Returns the total number of columns in the execution trace.
+    pub fn trace_width(&self) -> usize {
+        self.main_trace_width + self.aux_trace_width
+    }
+
+    /// Returns the number of transition constraints over the main segment of the execution trace.
+    pub fn num_main_transition_constraints(&self) -> usize {
+        self.main_constraint_degrees.len()
+    }
+
+    /// Returns the number of transition constraints over the auxiliary segment of the execution
+    /// trace.
+    pub fn num_aux_transition_constraints(&self) -> usize {
+        self.aux_constraint_degrees.len()
+    }
+
+    /// Returns the degrees of the transition constraints over the main segment of the execution
+    /// trace.
+    pub fn main_transition_constraint_degrees(&self) -> &[TransitionConstraintDegree] {
+        &self.main_constraint_degrees
+    }
+
+    /// Returns the degrees of the transition constraints over the auxiliary segment of the
+    /// execution trace.
+    pub fn aux_transition_constraint_degrees(&self) -> &[TransitionConstraintDegree] {
+        &self.aux_constraint_degrees
+    }
+}
+
 // PUBLIC INPUTS
 // ================================================================================================
 
@@ -283,3 +364,48 @@ impl vm_core::ToElements<Felt> for PublicInputs {
         result
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{air_info, chiplets, range, stack, trace::*};
+
+    #[test]
+    fn air_info_matches_layout() {
+        let info = air_info();
+
+        assert_eq!(
+            SYS_TRACE_WIDTH
+                + DECODER_TRACE_WIDTH
+                + STACK_TRACE_WIDTH
+                + RANGE_CHECK_TRACE_WIDTH
+                + CHIPLETS_WIDTH,
+            info.main_trace_width()
+        );
+        assert_eq!(
+            DECODER_AUX_TRACE_WIDTH
+                + STACK_AUX_TRACE_WIDTH
+                + RANGE_CHECK_AUX_TRACE_WIDTH
+                + HASHER_AUX_TRACE_WIDTH
+                + CHIPLETS_AUX_TRACE_WIDTH,
+            info.aux_trace_width()
+        );
+
+        assert_eq!(
+            1 + stack::get_transition_constraint_count()
+                + range::get_transition_constraint_count()
+                + chiplets::get_transition_constraint_count(),
+            info.num_main_transition_constraints()
+        );
+        assert_eq!(
+            info.num_main_transition_constraints(),
+            info.main_transition_constraint_degrees().len()
+        );
+        assert_eq!(
+            range::get_aux_transition_constraint_degrees().len(),
+            info.num_aux_transition_constraints()
+        );
+    }
+}
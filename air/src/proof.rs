@@ -1,9 +1,15 @@
-use super::DeserializationError;
+use super::{DeserializationError, ProcessorAir, PublicInputs};
 use vm_core::{
-    crypto::hash::{Blake3_192, Blake3_256, Hasher, Rpo256},
+    crypto::{
+        hash::{Blake3_192, Blake3_256, ElementHasher, Hasher, Rpo256},
+        random::{Challenge, RandomCoin, RpoRandomCoin, TranscriptRandomCoin, WinterRandomCoin},
+    },
     utils::collections::Vec,
+    CubeExtension, Felt, FieldElement, QuadExtension, ToElements,
+};
+use winter_air::{
+    proof::StarkProof, Air, FieldExtension, ProofOptions as WinterProofOptions,
 };
-use winter_air::{proof::StarkProof, FieldExtension, ProofOptions as WinterProofOptions};
 
 // EXECUTION PROOF
 // ================================================================================================
@@ -50,6 +56,31 @@ impl ExecutionProof {
         }
     }
 
+    /// Returns the ordered list of Fiat-Shamir challenges drawn from the public coin by the prover
+    /// when this proof was generated against the specified public inputs.
+    ///
+    /// The challenges are obtained by replaying the transcript of this proof: the public coin is
+    /// seeded with the proof context and the public inputs, and is then reseeded with the
+    /// commitments and out-of-domain evaluations contained in the proof in the same order as they
+    /// were sent by the prover. The replay does not check the validity of the proof.
+    ///
+    /// # Errors
+    /// Returns an error if the commitments or the out-of-domain frame of this proof cannot be
+    /// parsed, or if a challenge cannot be drawn from the public coin.
+    pub fn challenges(&self, pub_inputs: PublicInputs) -> Result<Vec<Challenge>, DeserializationError> {
+        match self.hash_fn {
+            HashFunction::Blake3_192 => {
+                replay_transcript::<Blake3_192, WinterRandomCoin<_>>(&self.proof, pub_inputs)
+            }
+            HashFunction::Blake3_256 => {
+                replay_transcript::<Blake3_256, WinterRandomCoin<_>>(&self.proof, pub_inputs)
+            }
+            HashFunction::Rpo256 => {
+                replay_transcript::<Rpo256, RpoRandomCoin>(&self.proof, pub_inputs)
+            }
+        }
+    }
+
     // SERIALIZATION / DESERIALIZATION
     // --------------------------------------------------------------------------------------------
 
@@ -81,6 +112,93 @@ impl ExecutionProof {
     }
 }
 
+// TRANSCRIPT REPLAY
+// ================================================================================================
+
+/// Replays the transcript of the specified proof using a public coin of type `R`, and returns the
+/// challenges drawn from the coin.
+fn replay_transcript<H, R>(
+    proof: &StarkProof,
+    pub_inputs: PublicInputs,
+) -> Result<Vec<Challenge>, DeserializationError>
+where
+    H: ElementHasher<BaseField = Felt>,
+    R: RandomCoin<BaseField = Felt, Hasher = H>,
+{
+    let mut seed = proof.context.to_elements();
+    seed.append(&mut pub_inputs.to_elements());
+    let air = ProcessorAir::new(proof.get_trace_info(), pub_inputs, proof.options().clone());
+    let coin = TranscriptRandomCoin::<R>::new(&seed);
+
+    match air.options().field_extension() {
+        FieldExtension::None => replay_with_extension::<Felt, H, R>(&air, proof, coin),
+        FieldExtension::Quadratic => {
+            replay_with_extension::<QuadExtension<Felt>, H, R>(&air, proof, coin)
+        }
+        FieldExtension::Cubic => {
+            replay_with_extension::<CubeExtension<Felt>, H, R>(&air, proof, coin)
+        }
+    }
+}
+
+/// Draws the challenges from the specified coin in the same order as the prover does, reseeding
+/// the coin with the data the prover sent at each step of the protocol.
+fn replay_with_extension<E, H, R>(
+    air: &ProcessorAir,
+    proof: &StarkProof,
+    mut coin: TranscriptRandomCoin<R>,
+) -> Result<Vec<Challenge>, DeserializationError>
+where
+    E: FieldElement<BaseField = Felt>,
+    H: ElementHasher<BaseField = Felt>,
+    R: RandomCoin<BaseField = Felt, Hasher = H>,
+{
+    let coin_error = |err| DeserializationError::InvalidValue(format!("{err}"));
+
+    let num_fri_layers = air.options().to_fri_options().num_fri_layers(air.lde_domain_size());
+    let (trace_roots, constraint_root, fri_roots) =
+        proof.commitments.clone().parse::<H>(air.trace_layout().num_segments(), num_fri_layers)?;
+
+    // trace commitments, followed by the random elements for each auxiliary trace segment
+    coin.reseed(trace_roots[0]);
+    for (i, root) in trace_roots.iter().skip(1).enumerate() {
+        air.get_aux_trace_segment_random_elements::<E, _>(i, &mut coin).map_err(coin_error)?;
+        coin.reseed(*root);
+    }
+    air.get_constraint_composition_coefficients::<E, _>(&mut coin).map_err(coin_error)?;
+
+    // constraint commitment and the out-of-domain point
+    coin.reseed(constraint_root);
+    coin.draw::<E>().map_err(coin_error)?;
+
+    // out-of-domain evaluations
+    let (ood_trace_states, ood_evaluations) = proof.ood_frame.clone().parse::<E>(
+        air.trace_layout().main_trace_width(),
+        air.trace_layout().aux_trace_width(),
+        air.context().num_constraint_composition_columns(),
+    )?;
+    coin.reseed(H::hash_elements(&ood_trace_states));
+    coin.reseed(H::hash_elements(&ood_evaluations));
+    air.get_deep_composition_coefficients::<E, _>(&mut coin).map_err(coin_error)?;
+
+    // FRI layer commitments; the prover draws a folding challenge after each layer commitment,
+    // but not after the commitment to the remainder
+    if let Some((remainder_root, layer_roots)) = fri_roots.split_last() {
+        for root in layer_roots {
+            coin.reseed(*root);
+            coin.draw::<E>().map_err(coin_error)?;
+        }
+        coin.reseed(*remainder_root);
+    }
+
+    // query positions
+    coin.reseed_with_int(proof.pow_nonce);
+    coin.draw_integers(air.options().num_queries(), air.lde_domain_size())
+        .map_err(coin_error)?;
+
+    Ok(coin.into_challenges())
+}
+
 // PROOF OPTIONS
 // ================================================================================================
 
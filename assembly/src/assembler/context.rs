@@ -1,5 +1,5 @@
 use super::{
-    AssemblyError, CallSet, CodeBlock, CodeBlockTable, Kernel, LibraryPath, Procedure,
+    AssemblyError, BTreeMap, CallSet, CodeBlock, CodeBlockTable, Kernel, LibraryPath, Procedure,
     ProcedureCache, ProcedureId, ToString, Vec,
 };
 use crate::ProcedureName;
@@ -62,6 +62,36 @@ impl AssemblyContext {
         self.current_proc_context().expect("no procedures").num_locals
     }
 
+    /// Returns the hashes of MAST roots of all local procedures compiled so far in the executable
+    /// module of this context.
+    ///
+    /// Procedures are identified by IDs computed from their names (i.e., `hash("#exec::proc_name")`)
+    /// rather than from their indexes, and the MAST root of a procedure depends only on the code
+    /// of the procedure and the procedures it invokes. Thus, the hash of a procedure is not
+    /// affected by changes to procedures it does not invoke, and comparing the hashes returned
+    /// after two compilations of a program can be used to detect which procedures have changed.
+    ///
+    /// This must be called after the program has been compiled via
+    /// [Assembler::compile_in_context()](super::Assembler::compile_in_context) and before the
+    /// context is converted into a code block table. Returns an empty map if this context is not
+    /// a program context.
+    pub fn procedure_hashes(&self) -> BTreeMap<ProcedureId, [u8; 32]> {
+        self.module_stack
+            .first()
+            .filter(|module| module.is_executable())
+            .map(|module| {
+                module
+                    .compiled_procs
+                    .iter()
+                    .map(|proc| {
+                        let proc_id = ProcedureId::from_name(proc.label(), &module.path);
+                        (proc_id, proc.code_root().hash().as_bytes())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -1,7 +1,8 @@
 use super::{
-    mem_ops::local_to_absolute_addr, push_felt, AssemblyContext, AssemblyError, CodeBlock, Felt,
-    Operation::*, SpanBuilder,
+    mem_ops::local_to_absolute_addr, push_felt, validate_param, AssemblyContext, AssemblyError,
+    CodeBlock, Felt, Operation::*, SpanBuilder,
 };
+use vm_core::Decorator;
 
 // CONSTANT INPUTS
 // ================================================================================================
@@ -50,6 +51,25 @@ pub fn locaddr(
     Ok(None)
 }
 
+/// Appends a sequence of operations to the span needed for executing locaddr.base.len
+/// instruction. This puts the absolute address of the local at index `base` onto the stack, the
+/// same way locaddr.base does, after making sure that all `len` locals starting at `base` are
+/// declared by the procedure.
+///
+/// # Errors
+/// Returns an error if base + len is greater than the number of procedure locals.
+pub fn locaddr_range(
+    span: &mut SpanBuilder,
+    base: u16,
+    len: u16,
+    context: &AssemblyContext,
+) -> Result<Option<CodeBlock>, AssemblyError> {
+    let num_locals = context.num_proc_locals();
+    validate_param(base as u32 + len as u32, 1..=num_locals as u32)?;
+    local_to_absolute_addr(span, base, num_locals)?;
+    Ok(None)
+}
+
 /// Appends CALLER operation to the span which puts the hash of the function which initiated the
 /// current SYSCALL onto the stack.
 ///
@@ -64,3 +84,14 @@ pub fn caller(
     }
     span.add_op(Caller)
 }
+
+// HOST EVENTS
+// ================================================================================================
+
+/// Appends an event decorator followed by a NOOP operation to the span. The decorator records the
+/// specified event id in the process when executed, while the NOOP makes sure the instruction
+/// advances the clock by one cycle.
+pub fn emit(span: &mut SpanBuilder, event_id: u32) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.push_decorator(Decorator::Event(event_id));
+    span.add_op(Noop)
+}
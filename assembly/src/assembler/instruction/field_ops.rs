@@ -17,6 +17,13 @@ pub fn assertw(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyErro
     span.add_ops([MovUp4, Eq, Assert, MovUp3, Eq, Assert, MovUp2, Eq, Assert, Eq, Assert])
 }
 
+/// Asserts that all four elements of the word at the top of the stack are zeros.
+///
+/// VM cycles: 8 cycles
+pub fn assertzw(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.add_ops([Eqz, Assert, Eqz, Assert, Eqz, Assert, Eqz, Assert])
+}
+
 // BASIC ARITHMETIC OPERATIONS
 // ================================================================================================
 
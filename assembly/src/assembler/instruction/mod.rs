@@ -41,6 +41,7 @@ impl Assembler {
             Instruction::AssertEq => span.add_ops([Eq, Assert]),
             Instruction::AssertEqw => field_ops::assertw(span),
             Instruction::Assertz => span.add_ops([Eqz, Assert]),
+            Instruction::AssertzW => field_ops::assertzw(span),
 
             Instruction::Add => span.add_op(Add),
             Instruction::AddImm(imm) => field_ops::add_imm(span, *imm),
@@ -53,6 +54,7 @@ impl Assembler {
             Instruction::Neg => span.add_op(Neg),
             Instruction::Inv => span.add_op(Inv),
             Instruction::Incr => span.add_op(Incr),
+            Instruction::Decr => field_ops::sub_imm(span, ONE),
 
             Instruction::Pow2 => field_ops::pow2(span),
             Instruction::Exp => field_ops::exp(span, 64),
@@ -271,6 +273,7 @@ impl Assembler {
             Instruction::MemStream => span.add_op(MStream),
 
             Instruction::Locaddr(v) => env_ops::locaddr(span, *v, ctx),
+            Instruction::LocaddrRange(base, len) => env_ops::locaddr_range(span, *base, *len, ctx),
             Instruction::MemLoad => mem_ops::mem_read(span, ctx, None, false, true),
             Instruction::MemLoadImm(v) => mem_ops::mem_read(span, ctx, Some(*v), false, true),
             Instruction::MemLoadW => mem_ops::mem_read(span, ctx, None, false, false),
@@ -306,6 +309,9 @@ impl Assembler {
             Instruction::CallImported(id) => self.call_imported(id, ctx),
             Instruction::SysCall(id) => self.syscall(id, ctx),
 
+            // ----- host events ------------------------------------------------------------------
+            Instruction::Emit(event_id) => env_ops::emit(span, *event_id),
+
             // ----- debug decorators -------------------------------------------------------------
             Instruction::Breakpoint => {
                 if self.in_debug_mode() {
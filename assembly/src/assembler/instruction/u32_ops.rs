@@ -139,17 +139,24 @@ pub fn u32mul(
 /// VM cycles per mode:
 /// - u32checked_div: 3 cycles
 /// - u32checked_div.b:
-///    - 5 cycles if b is 1
+///    - 3 cycles if b is 1
 ///    - 4 cycles if b is not 1
 /// - u32unchecked_div: 2 cycles
 /// - u32unchecked_div.b:
-///    - 4 cycles if b is 1
+///    - 1 cycle if b is 1
 ///    - 3 cycles if b is not 1
+///
+/// Division by an immediate power of two is translated in the same way as a right shift by the
+/// corresponding number of bits (see [u32shr()]).
 pub fn u32div(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
     imm: Option<u32>,
 ) -> Result<Option<CodeBlock>, AssemblyError> {
+    if let Some(imm) = imm.filter(|imm| imm.is_power_of_two()) {
+        return u32shr(span, op_mode, Some(imm.trailing_zeros() as u8));
+    }
+
     handle_division(span, op_mode, imm)?;
     span.add_op(Drop)
 }
@@ -159,17 +166,27 @@ pub fn u32div(
 /// VM cycles per mode:
 /// - u32checked_mod: 4 cycles
 /// - u32checked_mod.b:
-///    - 6 cycles if b is 1
-///    - 5 cycles if b is not 1
+///    - 2-3 cycles if b is a power of 2
+///    - 5 cycles if b is not a power of 2
 /// - u32unchecked_mod: 3 cycle
 /// - u32unchecked_mod.b:
 ///    - 5 cycles if b is 1
 ///    - 4 cycles if b is not 1
+///
+/// In checked mode, the remainder of division by an immediate power of two is computed by masking
+/// the low bits of the value with a bitwise AND, which also asserts that the value is a u32.
 pub fn u32mod(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
     imm: Option<u32>,
 ) -> Result<Option<CodeBlock>, AssemblyError> {
+    if let (U32OpMode::Checked, Some(imm)) = (&op_mode, imm) {
+        if imm.is_power_of_two() {
+            push_u32_value(span, imm - 1);
+            return span.add_op(U32and);
+        }
+    }
+
     handle_division(span, op_mode, imm)?;
     span.add_ops([Swap, Drop])
 }
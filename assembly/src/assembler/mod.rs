@@ -6,7 +6,7 @@ use super::{
     LibraryError, LibraryPath, Module, Operation, Procedure, ProcedureId, ProcedureName, Program,
     ToString, Vec, ONE, ZERO,
 };
-use core::{borrow::Borrow, cell::RefCell};
+use core::{borrow::Borrow, cell::RefCell, iter::Peekable};
 use vm_core::{utils::group_vector_elements, Decorator, DecoratorList};
 
 mod instruction;
@@ -34,12 +34,15 @@ mod tests;
 /// - If `with_kernel()` or `with_kernel_module()` methods are not used, the assembler will be
 ///   instantiated with a default empty kernel. Programs compiled using such assembler
 ///   cannot make calls to kernel procedures via `syscall` instruction.
+/// - If `with_optimization()` method is not used, the assembler will compile every instruction
+///   as written, without folding constants.
 #[derive(Default)]
 pub struct Assembler {
     kernel: Kernel,
     module_provider: ModuleProvider,
     proc_cache: RefCell<ProcedureCache>,
     in_debug_mode: bool,
+    optimize: bool,
 }
 
 impl Assembler {
@@ -52,6 +55,16 @@ impl Assembler {
         self
     }
 
+    /// Enables or disables the constant folding pass of the assembler.
+    ///
+    /// When enabled, a constant push followed by immediate field operations is folded into a
+    /// single push of the resulting constant at compile time (e.g., `push.3 add.4` is compiled as
+    /// `push.7`, and `push.3 eq.4` is compiled as `push.0`).
+    pub fn with_optimization(mut self, optimize: bool) -> Self {
+        self.optimize = optimize;
+        self
+    }
+
     /// Adds the library to provide modules for the compilation.
     pub fn with_library<L>(mut self, library: &L) -> Result<Self, AssemblyError>
     where
@@ -288,10 +301,18 @@ impl Assembler {
     {
         let mut blocks: Vec<CodeBlock> = Vec::new();
         let mut span = SpanBuilder::new(wrapper);
+        let mut body = body.peekable();
 
-        for node in body {
+        while let Some(node) = body.next() {
             match node.borrow() {
                 Node::Instruction(inner) => {
+                    let folded = if self.optimize {
+                        fold_constant(inner, &mut body)
+                    } else {
+                        None
+                    };
+                    let inner = folded.as_ref().unwrap_or(inner);
+
                     if let Some(block) = self.compile_instruction(inner, &mut span, context)? {
                         span.extract_span_into(&mut blocks);
                         blocks.push(block);
@@ -410,6 +431,52 @@ struct BodyWrapper {
 // UTILITY FUNCTIONS
 // ================================================================================================
 
+/// Folds the immediate field operations which directly follow a constant push into the pushed
+/// constant, consuming the folded nodes from the body.
+///
+/// For example, `push.3 add.4 eq.7` is folded into `push.1`. Returns `None` if the instruction
+/// does not push a single constant, or if it is not followed by any foldable operations.
+fn fold_constant<I, N>(instruction: &Instruction, body: &mut Peekable<I>) -> Option<Instruction>
+where
+    I: Iterator<Item = N>,
+    N: Borrow<Node>,
+{
+    let mut value = match instruction {
+        Instruction::PushU8(imm) => Felt::from(*imm),
+        Instruction::PushU16(imm) => Felt::from(*imm),
+        Instruction::PushU32(imm) => Felt::from(*imm),
+        Instruction::PushFelt(imm) => *imm,
+        _ => return None,
+    };
+
+    let mut is_folded = false;
+    while let Some(result) = body.peek().and_then(|node| match node.borrow() {
+        Node::Instruction(next) => apply_imm_op(value, next),
+        _ => None,
+    }) {
+        value = result;
+        is_folded = true;
+        body.next();
+    }
+
+    is_folded.then_some(Instruction::PushFelt(value))
+}
+
+/// Returns the result of applying the provided immediate field operation to the specified value,
+/// or `None` if the operation cannot be evaluated at compile time.
+fn apply_imm_op(value: Felt, instruction: &Instruction) -> Option<Felt> {
+    match instruction {
+        Instruction::AddImm(imm) => Some(value + *imm),
+        Instruction::SubImm(imm) => Some(value - *imm),
+        Instruction::MulImm(imm) => Some(value * *imm),
+        // division by zero must fail at runtime, and thus, is never folded
+        Instruction::DivImm(imm) if *imm != ZERO => Some(value / *imm),
+        Instruction::EqImm(imm) => Some(if value == *imm { ONE } else { ZERO }),
+        Instruction::NeqImm(imm) => Some(if value != *imm { ONE } else { ZERO }),
+        _ => None,
+    }
+}
+
 pub fn combine_blocks(mut blocks: Vec<CodeBlock>) -> CodeBlock {
     debug_assert!(!blocks.is_empty(), "cannot combine empty block list");
     // merge consecutive Span blocks.
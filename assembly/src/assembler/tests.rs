@@ -1,5 +1,11 @@
-use super::{combine_blocks, Assembler, CodeBlock, Library, Module, Operation};
-use crate::{ast::ModuleAst, LibraryNamespace, LibraryPath, Version};
+use super::{
+    combine_blocks, Assembler, AssemblyContext, AssemblyContextType, BTreeMap, CodeBlock, Library,
+    Module, Operation, ProcedureId,
+};
+use crate::{
+    ast::{ModuleAst, ProgramAst},
+    LibraryNamespace, LibraryPath, Version,
+};
 use core::slice::Iter;
 
 // TESTS
@@ -139,3 +145,106 @@ fn nested_blocks() {
 
     assert_eq!(combined.hash(), program.hash());
 }
+
+#[test]
+fn constant_folding() {
+    let source = "begin push.3 add.4 mul.2 push.5 eq.5 push.9 div.3 neq.3 end";
+    let expected = Assembler::default().compile("begin push.14 push.1 push.0 end").unwrap();
+
+    // with optimization enabled, the immediate operations are folded into the pushed constants
+    let optimized = Assembler::default().with_optimization(true).compile(source).unwrap();
+    let unoptimized = Assembler::default().compile(source).unwrap();
+    assert_eq!(expected.hash(), optimized.hash());
+    assert!(num_ops(optimized.root()) < num_ops(unoptimized.root()));
+
+    // with optimization disabled, the program is compiled as written
+    let disabled = Assembler::default().with_optimization(false).compile(source).unwrap();
+    assert_eq!(unoptimized.hash(), disabled.hash());
+    assert_ne!(expected.hash(), disabled.hash());
+
+    // constants are folded in nested blocks, but not across non-immediate operations
+    let source = "begin push.1 if.true push.2 add.3 else push.2 add add.3 end end";
+    let expected = "begin push.1 if.true push.5 else push.2 add add.3 end end";
+    let expected = Assembler::default().compile(expected).unwrap();
+    let optimized = Assembler::default().with_optimization(true).compile(source).unwrap();
+    assert_eq!(expected.hash(), optimized.hash());
+}
+
+#[test]
+fn u32_div_mod_by_power_of_two() {
+    let assembler = Assembler::default();
+    let compile = |source: &str| assembler.compile(source).unwrap().hash();
+
+    // division by a power of two is lowered to a right shift, and modulo to a bit mask
+    for k in [0, 1, 4, 31] {
+        let div = compile(&format!("begin u32checked_div.{} end", 1u64 << k));
+        assert_eq!(compile(&format!("begin u32checked_shr.{k} end")), div);
+
+        let modulo = compile(&format!("begin u32checked_mod.{} end", 1u64 << k));
+        let mask = (1u64 << k) - 1;
+        assert_eq!(compile(&format!("begin push.{mask} u32checked_and end")), modulo);
+    }
+
+    // the rewrite is not applied to divisors which are not powers of two
+    for b in [3, 6, 100] {
+        let div = compile(&format!("begin u32checked_div.{b} end"));
+        assert_eq!(compile(&format!("begin push.{b} u32checked_div end")), div);
+
+        let modulo = compile(&format!("begin u32checked_mod.{b} end"));
+        assert_eq!(compile(&format!("begin push.{b} u32checked_mod end")), modulo);
+    }
+
+    // unchecked modulo is not rewritten since a bitwise AND fails on non-u32 values
+    let modulo = compile("begin u32unchecked_mod.8 end");
+    assert_eq!(compile("begin push.8 u32unchecked_mod end"), modulo);
+}
+
+#[test]
+fn procedure_hashes() {
+    let source = "
+        proc.foo push.1 add end
+        proc.bar push.2 mul end
+        begin exec.foo call.bar end";
+    let edited = "
+        proc.foo push.1 add end
+        proc.bar push.3 mul end
+        begin exec.foo call.bar end";
+
+    let hashes = compile_procedure_hashes(source);
+    let edited_hashes = compile_procedure_hashes(edited);
+
+    let foo = ProcedureId::from_name("foo", &LibraryPath::exec_path());
+    let bar = ProcedureId::from_name("bar", &LibraryPath::exec_path());
+    assert_eq!(2, hashes.len());
+    assert_eq!(2, edited_hashes.len());
+
+    // only the hash of the edited procedure changes
+    assert_eq!(hashes[&foo], edited_hashes[&foo]);
+    assert_ne!(hashes[&bar], edited_hashes[&bar]);
+
+    // compiling the same program again yields the same hashes
+    assert_eq!(hashes, compile_procedure_hashes(source));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the number of operations in the span blocks of the provided code block.
+fn num_ops(block: &CodeBlock) -> usize {
+    match block {
+        CodeBlock::Span(span) => span.op_batches().iter().map(|batch| batch.ops().len()).sum(),
+        CodeBlock::Join(join) => num_ops(join.first()) + num_ops(join.second()),
+        CodeBlock::Split(split) => num_ops(split.on_true()) + num_ops(split.on_false()),
+        CodeBlock::Loop(loop_block) => num_ops(loop_block.body()),
+        CodeBlock::Call(_) | CodeBlock::Proxy(_) => 0,
+    }
+}
+
+/// Compiles the provided program and returns the hashes of its local procedures.
+fn compile_procedure_hashes(source: &str) -> BTreeMap<ProcedureId, [u8; 32]> {
+    let assembler = Assembler::default();
+    let program = ProgramAst::parse(source).unwrap();
+    let mut context = AssemblyContext::new(AssemblyContextType::Program);
+    assembler.compile_in_context(&program, &mut context).unwrap();
+    context.procedure_hashes()
+}
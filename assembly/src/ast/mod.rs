@@ -4,10 +4,10 @@
 //! code into relevant ASTs. This can be done via their `parse()` methods.
 
 use super::{
-    crypto::hash::RpoDigest, BTreeMap, ByteReader, ByteWriter, Deserializable,
-    DeserializationError, Felt, LabelError, LibraryPath, ParsingError, ProcedureId, ProcedureName,
-    Serializable, SliceReader, StarkField, String, ToString, Token, TokenStream, Vec,
-    MAX_LABEL_LEN,
+    crypto::hash::RpoDigest, AstSerdeError, BTreeMap, BTreeSet, ByteReader, ByteWriter,
+    Deserializable, DeserializationError, Felt, LabelError, LibraryPath, ParsingError, ProcedureId,
+    ProcedureName, Serializable, SliceReader, StarkField, String, ToString, Token, TokenStream,
+    Vec, MAX_LABEL_LEN,
 };
 use core::{iter, str::from_utf8};
 use vm_core::utils::bound_into_included_u64;
@@ -15,7 +15,7 @@ use vm_core::utils::bound_into_included_u64;
 pub use super::tokens::SourceLocation;
 
 mod nodes;
-pub use nodes::{AdviceInjectorNode, Instruction, Node};
+pub use nodes::{instruction_opcode, AdviceInjectorNode, Instruction, Node, OpCategory, OpCode};
 
 mod code_body;
 pub use code_body::CodeBody;
@@ -55,6 +55,10 @@ const MAX_IMPORTS: usize = u16::MAX as usize;
 /// Maximum stack index at which a full word can start.
 const MAX_STACK_WORD_OFFSET: u8 = 12;
 
+/// Version of the binary format of serialized programs. This must be incremented whenever the
+/// format changes in a backward-incompatible way (e.g., when opcodes are renumbered).
+pub const PROGRAM_SERDE_VERSION: u8 = 1;
+
 // TYPE ALIASES
 // ================================================================================================
 type LocalProcMap = BTreeMap<String, (u16, ProcedureAst)>;
@@ -137,6 +141,24 @@ impl ProgramAst {
         &self.body
     }
 
+    /// Returns size and complexity metrics of this program.
+    ///
+    /// The metrics are computed by walking the nodes of the program's body and of its local
+    /// procedures. Bodies of imported procedures are not visited, and thus, they do not contribute
+    /// to the number of nodes or to the nesting depth.
+    pub fn metrics(&self) -> ProgramMetrics {
+        let mut metrics = ProgramMetrics::default();
+        let mut imported_procs = BTreeSet::new();
+
+        let bodies = iter::once(&self.body).chain(self.local_procs.iter().map(|proc| &proc.body));
+        for body in bodies {
+            collect_metrics(body.nodes(), 0, &mut metrics, &mut imported_procs);
+        }
+        metrics.num_imported_procs = imported_procs.len();
+
+        metrics
+    }
+
     // PARSER
     // --------------------------------------------------------------------------------------------
     /// Parses the provided source into a [ProgramAst].
@@ -209,10 +231,14 @@ impl ProgramAst {
 
     /// Returns byte representation of this [ProgramAst].
     ///
-    /// The serde options are serialized as header information for the purposes of deserialization.
+    /// The format version and the serde options are serialized as header information for the
+    /// purposes of deserialization.
     pub fn to_bytes(&self, options: AstSerdeOptions) -> Vec<u8> {
         let mut target = Vec::<u8>::default();
 
+        // serialize the format version, so that blobs in an outdated format can be rejected
+        target.write_u8(PROGRAM_SERDE_VERSION);
+
         // serialize the options, so that deserialization knows what to do
         options.write_into(&mut target);
 
@@ -241,11 +267,24 @@ impl ProgramAst {
 
     /// Returns a [ProgramAst] struct deserialized from the provided bytes.
     ///
-    /// This function assumes that the byte array contains a serialized [AstSerdeOptions] struct as
-    /// a header.
-    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+    /// This function assumes that the byte array contains the format version followed by a
+    /// serialized [AstSerdeOptions] struct as a header.
+    ///
+    /// # Errors
+    /// Returns an error if the format version is not [PROGRAM_SERDE_VERSION], or if the bytes do
+    /// not contain a valid serialized program.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AstSerdeError> {
         let mut source = SliceReader::new(bytes);
 
+        // make sure the program was serialized using the current format version
+        let version = source.read_u8()?;
+        if version != PROGRAM_SERDE_VERSION {
+            return Err(AstSerdeError::UnsupportedVersion {
+                found: version,
+                expected: PROGRAM_SERDE_VERSION,
+            });
+        }
+
         // Deserialize the serialization options used when serializing
         let options = AstSerdeOptions::read_from(&mut source)?;
 
@@ -264,7 +303,7 @@ impl ProgramAst {
         let body_len = source.read_u16()? as usize;
         let nodes = Deserializable::read_batch_from(&mut source, body_len)?;
         match Self::new(nodes, local_procs, imports) {
-            Err(err) => Err(DeserializationError::UnknownError(err.message().clone())),
+            Err(err) => Err(DeserializationError::UnknownError(err.message().clone()).into()),
             Ok(res) => Ok(res),
         }
     }
@@ -303,6 +342,24 @@ impl ProgramAst {
     }
 }
 
+// PROGRAM METRICS
+// ================================================================================================
+
+/// Size and complexity metrics of a [ProgramAst].
+///
+/// The metrics are cheap to compute and can be used to flag unexpectedly large programs before
+/// compiling and proving them.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ProgramMetrics {
+    /// Total number of nodes in the body of the program and in its local procedures, including
+    /// nodes nested in control flow blocks.
+    pub num_nodes: usize,
+    /// Maximum nesting depth of control flow blocks (i.e., `if`, `while`, and `repeat`).
+    pub max_depth: usize,
+    /// Number of distinct imported procedures invoked via `exec` or `call` instructions.
+    pub num_imported_procs: usize,
+}
+
 // MODULE AST
 // ================================================================================================
 
@@ -762,6 +819,36 @@ impl Deserializable for ProcReExport {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Adds metrics of the provided nodes, located at the specified nesting depth, to `metrics` and
+/// records IDs of the imported procedures invoked by the nodes in `imported_procs`.
+fn collect_metrics(
+    nodes: &[Node],
+    depth: usize,
+    metrics: &mut ProgramMetrics,
+    imported_procs: &mut BTreeSet<ProcedureId>,
+) {
+    metrics.max_depth = metrics.max_depth.max(depth);
+    for node in nodes {
+        metrics.num_nodes += 1;
+        match node {
+            Node::Instruction(Instruction::ExecImported(id) | Instruction::CallImported(id)) => {
+                imported_procs.insert(*id);
+            }
+            Node::Instruction(_) => (),
+            Node::IfElse {
+                true_case,
+                false_case,
+            } => {
+                collect_metrics(true_case.nodes(), depth + 1, metrics, imported_procs);
+                collect_metrics(false_case.nodes(), depth + 1, metrics, imported_procs);
+            }
+            Node::Repeat { body, .. } | Node::While { body } => {
+                collect_metrics(body.nodes(), depth + 1, metrics, imported_procs);
+            }
+        }
+    }
+}
+
 /// Sort a map of procedures into a vec, respecting the order set in the map
 fn sort_procs_into_vec(proc_map: LocalProcMap) -> Vec<ProcedureAst> {
     let mut procedures: Vec<_> = proc_map.into_values().collect();
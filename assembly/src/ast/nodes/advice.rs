@@ -17,6 +17,7 @@ use vm_core::{AdviceInjector, Felt, ZERO};
 pub enum AdviceInjectorNode {
     PushU64div,
     PushExt2intt,
+    PushC25519Inv,
     PushSmtGet,
     PushMapVal,
     PushMapValImm { offset: u8 },
@@ -34,6 +35,7 @@ impl From<&AdviceInjectorNode> for AdviceInjector {
         match value {
             PushU64div => Self::DivU64,
             PushExt2intt => Self::Ext2Intt,
+            PushC25519Inv => Self::Curve25519Inv,
             PushSmtGet => Self::SmtGet,
             PushMapVal => Self::MapValueToStack {
                 include_len: false,
@@ -67,6 +69,7 @@ impl fmt::Display for AdviceInjectorNode {
         match self {
             PushU64div => write!(f, "push_u64div"),
             PushExt2intt => write!(f, "push_ext2intt"),
+            PushC25519Inv => write!(f, "push_c25519inv"),
             PushSmtGet => write!(f, "push_smtget"),
             PushMapVal => write!(f, "push_mapval"),
             PushMapValImm { offset } => write!(f, "push_mapval.{offset}"),
@@ -94,6 +97,7 @@ const PUSH_MTNODE: u8 = 7;
 const INSERT_MEM: u8 = 8;
 const INSERT_HDWORD: u8 = 9;
 const INSERT_HDWORD_IMM: u8 = 10;
+const PUSH_C25519INV: u8 = 11;
 
 impl Serializable for AdviceInjectorNode {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
@@ -101,6 +105,7 @@ impl Serializable for AdviceInjectorNode {
         match self {
             PushU64div => target.write_u8(PUSH_U64DIV),
             PushExt2intt => target.write_u8(PUSH_EXT2INTT),
+            PushC25519Inv => target.write_u8(PUSH_C25519INV),
             PushSmtGet => target.write_u8(PUSH_SMTGET),
             PushMapVal => target.write_u8(PUSH_MAPVAL),
             PushMapValImm { offset } => {
@@ -128,6 +133,7 @@ impl Deserializable for AdviceInjectorNode {
         match source.read_u8()? {
             PUSH_U64DIV => Ok(AdviceInjectorNode::PushU64div),
             PUSH_EXT2INTT => Ok(AdviceInjectorNode::PushExt2intt),
+            PUSH_C25519INV => Ok(AdviceInjectorNode::PushC25519Inv),
             PUSH_SMTGET => Ok(AdviceInjectorNode::PushSmtGet),
             PUSH_MAPVAL => Ok(AdviceInjectorNode::PushMapVal),
             PUSH_MAPVAL_IMM => {
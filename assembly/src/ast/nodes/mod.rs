@@ -5,6 +5,7 @@ mod advice;
 pub use advice::AdviceInjectorNode;
 
 mod serde;
+pub use self::serde::{instruction_opcode, OpCategory, OpCode};
 
 // NODES
 // ================================================================================================
@@ -34,6 +35,7 @@ pub enum Instruction {
     AssertEq,
     AssertEqw,
     Assertz,
+    AssertzW,
     Add,
     AddImm(Felt),
     Sub,
@@ -45,6 +47,7 @@ pub enum Instruction {
     Neg,
     Inv,
     Incr,
+    Decr,
     Pow2,
     Exp,
     ExpImm(Felt),
@@ -244,6 +247,7 @@ pub enum Instruction {
     PushU32List(Vec<u32>),
     PushFeltList(Vec<Felt>),
     Locaddr(u16),
+    LocaddrRange(u16, u16),
     Sdepth,
     Caller,
     Clk,
@@ -290,6 +294,9 @@ pub enum Instruction {
     CallImported(ProcedureId),
     SysCall(ProcedureId),
 
+    // ----- host events --------------------------------------------------------------------------
+    Emit(u32),
+
     // ----- debug decorators ---------------------------------------------------------------------
     Breakpoint,
 }
@@ -308,6 +315,7 @@ impl fmt::Display for Instruction {
             Self::AssertEq => write!(f, "assert_eq"),
             Self::AssertEqw => write!(f, "assert_eqw"),
             Self::Assertz => write!(f, "assertz"),
+            Self::AssertzW => write!(f, "assertz_w"),
             Self::Add => write!(f, "add"),
             Self::AddImm(value) => write!(f, "add.{value}"),
             Self::Sub => write!(f, "sub"),
@@ -318,7 +326,8 @@ impl fmt::Display for Instruction {
             Self::DivImm(value) => write!(f, "div.{value}"),
             Self::Neg => write!(f, "neg"),
             Self::Inv => write!(f, "inv"),
-            Self::Incr => write!(f, "add.1"),
+            Self::Incr => write!(f, "incr"),
+            Self::Decr => write!(f, "decr"),
             Self::Pow2 => write!(f, "pow2"),
             Self::Exp => write!(f, "exp"),
             Self::ExpImm(value) => write!(f, "exp.{value}"),
@@ -519,6 +528,7 @@ impl fmt::Display for Instruction {
             Self::PushFeltList(values) => display_push_vec(f, values),
 
             Self::Locaddr(value) => write!(f, "locaddr.{value}"),
+            Self::LocaddrRange(base, len) => write!(f, "locaddr.{base}.{len}"),
             Self::Sdepth => write!(f, "sdepth"),
             Self::Caller => write!(f, "caller"),
             Self::Clk => write!(f, "clk"),
@@ -567,6 +577,9 @@ impl fmt::Display for Instruction {
             Self::CallImported(proc_id) => write!(f, "call.{proc_id}"),
             Self::SysCall(proc_id) => write!(f, "syscall.{proc_id}"),
 
+            // ----- host events ------------------------------------------------------------------
+            Self::Emit(event_id) => write!(f, "emit.{event_id}"),
+
             // ----- debug decorators -------------------------------------------------------------
             Self::Breakpoint => write!(f, "breakpoint"),
         }
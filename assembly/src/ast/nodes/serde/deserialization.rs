@@ -1,6 +1,7 @@
 use super::{
-    super::AdviceInjectorNode, ByteReader, CodeBody, Deserializable, DeserializationError, Felt,
-    Instruction, Node, OpCode, ProcedureId, RpoDigest, ToString, MAX_PUSH_INPUTS,
+    super::AdviceInjectorNode, AstSerdeError, ByteReader, CodeBody, Deserializable,
+    DeserializationError, Felt, Instruction, Node, OpCode, ProcedureId, RpoDigest, ToString,
+    MAX_PUSH_INPUTS,
 };
 
 // NODE DESERIALIZATION
@@ -62,6 +63,7 @@ impl Deserializable for Instruction {
             OpCode::AssertEq => Ok(Instruction::AssertEq),
             OpCode::AssertEqw => Ok(Instruction::AssertEqw),
             OpCode::Assertz => Ok(Instruction::Assertz),
+            OpCode::AssertzW => Ok(Instruction::AssertzW),
             OpCode::Add => Ok(Instruction::Add),
             OpCode::AddImm => Ok(Instruction::AddImm(Felt::read_from(source)?)),
             OpCode::Sub => Ok(Instruction::Sub),
@@ -73,6 +75,7 @@ impl Deserializable for Instruction {
             OpCode::Neg => Ok(Instruction::Neg),
             OpCode::Inv => Ok(Instruction::Inv),
             OpCode::Incr => Ok(Instruction::Incr),
+            OpCode::Decr => Ok(Instruction::Decr),
             OpCode::Pow2 => Ok(Instruction::Pow2),
             OpCode::Exp => Ok(Instruction::Exp),
             OpCode::ExpImm => Ok(Instruction::ExpImm(Felt::read_from(source)?)),
@@ -310,6 +313,11 @@ impl Deserializable for Instruction {
             }
 
             OpCode::Locaddr => Ok(Instruction::Locaddr(source.read_u16()?)),
+            OpCode::LocaddrRange => {
+                let base = source.read_u16()?;
+                let len = source.read_u16()?;
+                Ok(Instruction::LocaddrRange(base, len))
+            }
             OpCode::Sdepth => Ok(Instruction::Sdepth),
             OpCode::Caller => Ok(Instruction::Caller),
             OpCode::Clk => Ok(Instruction::Clk),
@@ -355,12 +363,15 @@ impl Deserializable for Instruction {
             OpCode::CallImported => Ok(Instruction::CallImported(ProcedureId::read_from(source)?)),
             OpCode::SysCall => Ok(Instruction::SysCall(ProcedureId::read_from(source)?)),
 
+            // ----- host events ------------------------------------------------------------------
+            OpCode::Emit => Ok(Instruction::Emit(source.read_u32()?)),
+
             // ----- control flow -----------------------------------------------------------------
-            // control flow instructions should be parsed as a part of Node::read_from() and we
-            // should never get here
-            OpCode::IfElse => unreachable!(),
-            OpCode::Repeat => unreachable!(),
-            OpCode::While => unreachable!(),
+            // control flow markers are valid only at node boundaries, where they are handled by
+            // Node::read_from(); thus, we can get here only if the input is malformed
+            OpCode::IfElse | OpCode::Repeat | OpCode::While => {
+                Err(AstSerdeError::UnexpectedControlMarker(opcode as u8).into())
+            }
         }
     }
 }
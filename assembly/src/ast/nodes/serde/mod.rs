@@ -1,5 +1,5 @@
 use super::{CodeBody, Felt, Instruction, Node, ProcedureId, RpoDigest, ToString};
-use crate::MAX_PUSH_INPUTS;
+use crate::{AstSerdeError, MAX_PUSH_INPUTS};
 use num_enum::TryFromPrimitive;
 
 use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
@@ -273,12 +273,133 @@ pub enum OpCode {
     CallImported = 238,
     SysCall = 239,
 
+    // ----- extended io operations ---------------------------------------------------------------
+    LocaddrRange = 240,
+
+    // ----- host events --------------------------------------------------------------------------
+    Emit = 241,
+
+    // ----- extended field operations ------------------------------------------------------------
+    AssertzW = 242,
+    Decr = 243,
+
     // ----- control flow -------------------------------------------------------------------------
     IfElse = 253,
     Repeat = 254,
     While = 255,
 }
 
+impl OpCode {
+    /// Returns the category of instructions to which this opcode belongs.
+    ///
+    /// Categories are assigned according to the opcode ranges of the enum; calls to procedures
+    /// are considered to be control flow.
+    pub fn category(&self) -> OpCategory {
+        match self {
+            Self::LocaddrRange | Self::Emit => OpCategory::Io,
+            Self::AssertzW | Self::Decr => OpCategory::Arithmetic,
+            _ => match *self as u8 {
+                0..=38 => OpCategory::Arithmetic,
+                39..=117 => OpCategory::U32,
+                118..=195 => OpCategory::Stack,
+                196..=225 => OpCategory::Io,
+                226..=233 => OpCategory::Crypto,
+                234..=239 | 253..=255 => OpCategory::ControlFlow,
+                _ => unreachable!("no category is defined for opcode {self:?}"),
+            },
+        }
+    }
+
+    /// Returns true if the serialized instruction carries an immediate value (e.g., a constant,
+    /// an address, or a procedure identifier) after this opcode.
+    ///
+    /// Control flow opcodes are followed by nested code blocks rather than immediate values, and
+    /// thus always return false.
+    pub fn is_immediate(&self) -> bool {
+        matches!(
+            self,
+            Self::AddImm
+                | Self::SubImm
+                | Self::MulImm
+                | Self::DivImm
+                | Self::ExpImm
+                | Self::ExpBitLength
+                | Self::EqImm
+                | Self::NeqImm
+                | Self::U32CheckedAddImm
+                | Self::U32WrappingAddImm
+                | Self::U32OverflowingAddImm
+                | Self::U32CheckedSubImm
+                | Self::U32WrappingSubImm
+                | Self::U32OverflowingSubImm
+                | Self::U32CheckedMulImm
+                | Self::U32WrappingMulImm
+                | Self::U32OverflowingMulImm
+                | Self::U32CheckedDivImm
+                | Self::U32UncheckedDivImm
+                | Self::U32CheckedModImm
+                | Self::U32UncheckedModImm
+                | Self::U32CheckedDivModImm
+                | Self::U32UncheckedDivModImm
+                | Self::U32CheckedShrImm
+                | Self::U32UncheckedShrImm
+                | Self::U32CheckedShlImm
+                | Self::U32UncheckedShlImm
+                | Self::U32CheckedRotrImm
+                | Self::U32UncheckedRotrImm
+                | Self::U32CheckedRotlImm
+                | Self::U32UncheckedRotlImm
+                | Self::U32CheckedEqImm
+                | Self::U32CheckedNeqImm
+                | Self::PushU8
+                | Self::PushU16
+                | Self::PushU32
+                | Self::PushFelt
+                | Self::PushWord
+                | Self::PushU8List
+                | Self::PushU16List
+                | Self::PushU32List
+                | Self::PushFeltList
+                | Self::Locaddr
+                | Self::LocaddrRange
+                | Self::MemLoadImm
+                | Self::MemLoadWImm
+                | Self::LocLoad
+                | Self::LocLoadW
+                | Self::MemStoreImm
+                | Self::LocStore
+                | Self::MemStoreWImm
+                | Self::LocStoreW
+                | Self::AdvPush
+                | Self::AdvInject
+                | Self::ExecLocal
+                | Self::ExecImported
+                | Self::CallLocal
+                | Self::CallMastRoot
+                | Self::CallImported
+                | Self::SysCall
+                | Self::Emit
+        )
+    }
+}
+
+/// Category of instructions identified by an [OpCode].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OpCategory {
+    /// Field and extension field operations, including assertions and comparisons.
+    Arithmetic,
+    /// Operations on u32 values.
+    U32,
+    /// Stack manipulation operations.
+    Stack,
+    /// Input / output operations, including memory and advice provider accesses.
+    Io,
+    /// Hashing, Merkle tree, and STARK proof verification operations.
+    Crypto,
+    /// Control flow and procedure invocations.
+    ControlFlow,
+}
+
 impl Serializable for OpCode {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         target.write_u8(*self as u8);
@@ -293,3 +414,280 @@ impl Deserializable for OpCode {
         })
     }
 }
+
+// INSTRUCTION OPCODES
+// ================================================================================================
+
+/// Returns the [OpCode] under which the specified instruction is serialized, or None if the
+/// instruction is not serialized (e.g., `breakpoint`, which is a transparent instruction).
+///
+/// The match below is exhaustive by design: adding a new [Instruction] variant without assigning
+/// an [OpCode] to it results in a compilation error.
+pub fn instruction_opcode(instruction: &Instruction) -> Option<OpCode> {
+    let opcode = match instruction {
+        Instruction::Assert => OpCode::Assert,
+        Instruction::AssertEq => OpCode::AssertEq,
+        Instruction::AssertEqw => OpCode::AssertEqw,
+        Instruction::Assertz => OpCode::Assertz,
+        Instruction::AssertzW => OpCode::AssertzW,
+        Instruction::Add => OpCode::Add,
+        Instruction::AddImm(..) => OpCode::AddImm,
+        Instruction::Sub => OpCode::Sub,
+        Instruction::SubImm(..) => OpCode::SubImm,
+        Instruction::Mul => OpCode::Mul,
+        Instruction::MulImm(..) => OpCode::MulImm,
+        Instruction::Div => OpCode::Div,
+        Instruction::DivImm(..) => OpCode::DivImm,
+        Instruction::Neg => OpCode::Neg,
+        Instruction::Inv => OpCode::Inv,
+        Instruction::Incr => OpCode::Incr,
+        Instruction::Decr => OpCode::Decr,
+        Instruction::Pow2 => OpCode::Pow2,
+        Instruction::Exp => OpCode::Exp,
+        Instruction::ExpImm(..) => OpCode::ExpImm,
+        Instruction::ExpBitLength(..) => OpCode::ExpBitLength,
+        Instruction::Not => OpCode::Not,
+        Instruction::And => OpCode::And,
+        Instruction::Or => OpCode::Or,
+        Instruction::Xor => OpCode::Xor,
+        Instruction::Eq => OpCode::Eq,
+        Instruction::EqImm(..) => OpCode::EqImm,
+        Instruction::Neq => OpCode::Neq,
+        Instruction::NeqImm(..) => OpCode::NeqImm,
+        Instruction::Eqw => OpCode::Eqw,
+        Instruction::Lt => OpCode::Lt,
+        Instruction::Lte => OpCode::Lte,
+        Instruction::Gt => OpCode::Gt,
+        Instruction::Gte => OpCode::Gte,
+        Instruction::IsOdd => OpCode::IsOdd,
+
+        // ----- ext2 operations -------------------------------------------------------------------
+        Instruction::Ext2Add => OpCode::Ext2Add,
+        Instruction::Ext2Sub => OpCode::Ext2Sub,
+        Instruction::Ext2Mul => OpCode::Ext2Mul,
+        Instruction::Ext2Div => OpCode::Ext2Div,
+        Instruction::Ext2Neg => OpCode::Ext2Neg,
+        Instruction::Ext2Inv => OpCode::Ext2Inv,
+
+        // ----- u32 operations --------------------------------------------------------------------
+        Instruction::U32Test => OpCode::U32Test,
+        Instruction::U32TestW => OpCode::U32TestW,
+        Instruction::U32Assert => OpCode::U32Assert,
+        Instruction::U32Assert2 => OpCode::U32Assert2,
+        Instruction::U32AssertW => OpCode::U32AssertW,
+        Instruction::U32Split => OpCode::U32Split,
+        Instruction::U32Cast => OpCode::U32Cast,
+        Instruction::U32CheckedAdd => OpCode::U32CheckedAdd,
+        Instruction::U32CheckedAddImm(..) => OpCode::U32CheckedAddImm,
+        Instruction::U32WrappingAdd => OpCode::U32WrappingAdd,
+        Instruction::U32WrappingAddImm(..) => OpCode::U32WrappingAddImm,
+        Instruction::U32OverflowingAdd => OpCode::U32OverflowingAdd,
+        Instruction::U32OverflowingAddImm(..) => OpCode::U32OverflowingAddImm,
+        Instruction::U32OverflowingAdd3 => OpCode::U32OverflowingAdd3,
+        Instruction::U32WrappingAdd3 => OpCode::U32WrappingAdd3,
+        Instruction::U32CheckedSub => OpCode::U32CheckedSub,
+        Instruction::U32CheckedSubImm(..) => OpCode::U32CheckedSubImm,
+        Instruction::U32WrappingSub => OpCode::U32WrappingSub,
+        Instruction::U32WrappingSubImm(..) => OpCode::U32WrappingSubImm,
+        Instruction::U32OverflowingSub => OpCode::U32OverflowingSub,
+        Instruction::U32OverflowingSubImm(..) => OpCode::U32OverflowingSubImm,
+        Instruction::U32CheckedMul => OpCode::U32CheckedMul,
+        Instruction::U32CheckedMulImm(..) => OpCode::U32CheckedMulImm,
+        Instruction::U32WrappingMul => OpCode::U32WrappingMul,
+        Instruction::U32WrappingMulImm(..) => OpCode::U32WrappingMulImm,
+        Instruction::U32OverflowingMul => OpCode::U32OverflowingMul,
+        Instruction::U32OverflowingMulImm(..) => OpCode::U32OverflowingMulImm,
+        Instruction::U32OverflowingMadd => OpCode::U32OverflowingMadd,
+        Instruction::U32WrappingMadd => OpCode::U32WrappingMadd,
+        Instruction::U32CheckedDiv => OpCode::U32CheckedDiv,
+        Instruction::U32CheckedDivImm(..) => OpCode::U32CheckedDivImm,
+        Instruction::U32UncheckedDiv => OpCode::U32UncheckedDiv,
+        Instruction::U32UncheckedDivImm(..) => OpCode::U32UncheckedDivImm,
+        Instruction::U32CheckedMod => OpCode::U32CheckedMod,
+        Instruction::U32CheckedModImm(..) => OpCode::U32CheckedModImm,
+        Instruction::U32UncheckedMod => OpCode::U32UncheckedMod,
+        Instruction::U32UncheckedModImm(..) => OpCode::U32UncheckedModImm,
+        Instruction::U32CheckedDivMod => OpCode::U32CheckedDivMod,
+        Instruction::U32CheckedDivModImm(..) => OpCode::U32CheckedDivModImm,
+        Instruction::U32UncheckedDivMod => OpCode::U32UncheckedDivMod,
+        Instruction::U32UncheckedDivModImm(..) => OpCode::U32UncheckedDivModImm,
+        Instruction::U32CheckedAnd => OpCode::U32CheckedAnd,
+        Instruction::U32CheckedOr => OpCode::U32CheckedOr,
+        Instruction::U32CheckedXor => OpCode::U32CheckedXor,
+        Instruction::U32CheckedNot => OpCode::U32CheckedNot,
+        Instruction::U32CheckedShr => OpCode::U32CheckedShr,
+        Instruction::U32CheckedShrImm(..) => OpCode::U32CheckedShrImm,
+        Instruction::U32UncheckedShr => OpCode::U32UncheckedShr,
+        Instruction::U32UncheckedShrImm(..) => OpCode::U32UncheckedShrImm,
+        Instruction::U32CheckedShl => OpCode::U32CheckedShl,
+        Instruction::U32CheckedShlImm(..) => OpCode::U32CheckedShlImm,
+        Instruction::U32UncheckedShl => OpCode::U32UncheckedShl,
+        Instruction::U32UncheckedShlImm(..) => OpCode::U32UncheckedShlImm,
+        Instruction::U32CheckedRotr => OpCode::U32CheckedRotr,
+        Instruction::U32CheckedRotrImm(..) => OpCode::U32CheckedRotrImm,
+        Instruction::U32UncheckedRotr => OpCode::U32UncheckedRotr,
+        Instruction::U32UncheckedRotrImm(..) => OpCode::U32UncheckedRotrImm,
+        Instruction::U32CheckedRotl => OpCode::U32CheckedRotl,
+        Instruction::U32CheckedRotlImm(..) => OpCode::U32CheckedRotlImm,
+        Instruction::U32UncheckedRotl => OpCode::U32UncheckedRotl,
+        Instruction::U32UncheckedRotlImm(..) => OpCode::U32UncheckedRotlImm,
+        Instruction::U32CheckedPopcnt => OpCode::U32CheckedPopcnt,
+        Instruction::U32UncheckedPopcnt => OpCode::U32UncheckedPopcnt,
+        Instruction::U32CheckedEq => OpCode::U32CheckedEq,
+        Instruction::U32CheckedEqImm(..) => OpCode::U32CheckedEqImm,
+        Instruction::U32CheckedNeq => OpCode::U32CheckedNeq,
+        Instruction::U32CheckedNeqImm(..) => OpCode::U32CheckedNeqImm,
+        Instruction::U32CheckedLt => OpCode::U32CheckedLt,
+        Instruction::U32UncheckedLt => OpCode::U32UncheckedLt,
+        Instruction::U32CheckedLte => OpCode::U32CheckedLte,
+        Instruction::U32UncheckedLte => OpCode::U32UncheckedLte,
+        Instruction::U32CheckedGt => OpCode::U32CheckedGt,
+        Instruction::U32UncheckedGt => OpCode::U32UncheckedGt,
+        Instruction::U32CheckedGte => OpCode::U32CheckedGte,
+        Instruction::U32UncheckedGte => OpCode::U32UncheckedGte,
+        Instruction::U32CheckedMin => OpCode::U32CheckedMin,
+        Instruction::U32UncheckedMin => OpCode::U32UncheckedMin,
+        Instruction::U32CheckedMax => OpCode::U32CheckedMax,
+        Instruction::U32UncheckedMax => OpCode::U32UncheckedMax,
+
+        // ----- stack manipulation ----------------------------------------------------------------
+        Instruction::Drop => OpCode::Drop,
+        Instruction::DropW => OpCode::DropW,
+        Instruction::PadW => OpCode::PadW,
+        Instruction::Dup0 => OpCode::Dup0,
+        Instruction::Dup1 => OpCode::Dup1,
+        Instruction::Dup2 => OpCode::Dup2,
+        Instruction::Dup3 => OpCode::Dup3,
+        Instruction::Dup4 => OpCode::Dup4,
+        Instruction::Dup5 => OpCode::Dup5,
+        Instruction::Dup6 => OpCode::Dup6,
+        Instruction::Dup7 => OpCode::Dup7,
+        Instruction::Dup8 => OpCode::Dup8,
+        Instruction::Dup9 => OpCode::Dup9,
+        Instruction::Dup10 => OpCode::Dup10,
+        Instruction::Dup11 => OpCode::Dup11,
+        Instruction::Dup12 => OpCode::Dup12,
+        Instruction::Dup13 => OpCode::Dup13,
+        Instruction::Dup14 => OpCode::Dup14,
+        Instruction::Dup15 => OpCode::Dup15,
+        Instruction::DupW0 => OpCode::DupW0,
+        Instruction::DupW1 => OpCode::DupW1,
+        Instruction::DupW2 => OpCode::DupW2,
+        Instruction::DupW3 => OpCode::DupW3,
+        Instruction::Swap1 => OpCode::Swap1,
+        Instruction::Swap2 => OpCode::Swap2,
+        Instruction::Swap3 => OpCode::Swap3,
+        Instruction::Swap4 => OpCode::Swap4,
+        Instruction::Swap5 => OpCode::Swap5,
+        Instruction::Swap6 => OpCode::Swap6,
+        Instruction::Swap7 => OpCode::Swap7,
+        Instruction::Swap8 => OpCode::Swap8,
+        Instruction::Swap9 => OpCode::Swap9,
+        Instruction::Swap10 => OpCode::Swap10,
+        Instruction::Swap11 => OpCode::Swap11,
+        Instruction::Swap12 => OpCode::Swap12,
+        Instruction::Swap13 => OpCode::Swap13,
+        Instruction::Swap14 => OpCode::Swap14,
+        Instruction::Swap15 => OpCode::Swap15,
+        Instruction::SwapW1 => OpCode::SwapW1,
+        Instruction::SwapW2 => OpCode::SwapW2,
+        Instruction::SwapW3 => OpCode::SwapW3,
+        Instruction::SwapDw => OpCode::SwapDW,
+        Instruction::MovUp2 => OpCode::MovUp2,
+        Instruction::MovUp3 => OpCode::MovUp3,
+        Instruction::MovUp4 => OpCode::MovUp4,
+        Instruction::MovUp5 => OpCode::MovUp5,
+        Instruction::MovUp6 => OpCode::MovUp6,
+        Instruction::MovUp7 => OpCode::MovUp7,
+        Instruction::MovUp8 => OpCode::MovUp8,
+        Instruction::MovUp9 => OpCode::MovUp9,
+        Instruction::MovUp10 => OpCode::MovUp10,
+        Instruction::MovUp11 => OpCode::MovUp11,
+        Instruction::MovUp12 => OpCode::MovUp12,
+        Instruction::MovUp13 => OpCode::MovUp13,
+        Instruction::MovUp14 => OpCode::MovUp14,
+        Instruction::MovUp15 => OpCode::MovUp15,
+        Instruction::MovUpW2 => OpCode::MovUpW2,
+        Instruction::MovUpW3 => OpCode::MovUpW3,
+        Instruction::MovDn2 => OpCode::MovDn2,
+        Instruction::MovDn3 => OpCode::MovDn3,
+        Instruction::MovDn4 => OpCode::MovDn4,
+        Instruction::MovDn5 => OpCode::MovDn5,
+        Instruction::MovDn6 => OpCode::MovDn6,
+        Instruction::MovDn7 => OpCode::MovDn7,
+        Instruction::MovDn8 => OpCode::MovDn8,
+        Instruction::MovDn9 => OpCode::MovDn9,
+        Instruction::MovDn10 => OpCode::MovDn10,
+        Instruction::MovDn11 => OpCode::MovDn11,
+        Instruction::MovDn12 => OpCode::MovDn12,
+        Instruction::MovDn13 => OpCode::MovDn13,
+        Instruction::MovDn14 => OpCode::MovDn14,
+        Instruction::MovDn15 => OpCode::MovDn15,
+        Instruction::MovDnW2 => OpCode::MovDnW2,
+        Instruction::MovDnW3 => OpCode::MovDnW3,
+        Instruction::CSwap => OpCode::CSwap,
+        Instruction::CSwapW => OpCode::CSwapW,
+        Instruction::CDrop => OpCode::CDrop,
+        Instruction::CDropW => OpCode::CDropW,
+
+        // ----- input / output operations ---------------------------------------------------------
+        Instruction::PushU8(..) => OpCode::PushU8,
+        Instruction::PushU16(..) => OpCode::PushU16,
+        Instruction::PushU32(..) => OpCode::PushU32,
+        Instruction::PushFelt(..) => OpCode::PushFelt,
+        Instruction::PushWord(..) => OpCode::PushWord,
+        Instruction::PushU8List(..) => OpCode::PushU8List,
+        Instruction::PushU16List(..) => OpCode::PushU16List,
+        Instruction::PushU32List(..) => OpCode::PushU32List,
+        Instruction::PushFeltList(..) => OpCode::PushFeltList,
+        Instruction::Locaddr(..) => OpCode::Locaddr,
+        Instruction::LocaddrRange(..) => OpCode::LocaddrRange,
+        Instruction::Sdepth => OpCode::Sdepth,
+        Instruction::Caller => OpCode::Caller,
+        Instruction::Clk => OpCode::Clk,
+        Instruction::MemLoad => OpCode::MemLoad,
+        Instruction::MemLoadImm(..) => OpCode::MemLoadImm,
+        Instruction::MemLoadW => OpCode::MemLoadW,
+        Instruction::MemLoadWImm(..) => OpCode::MemLoadWImm,
+        Instruction::LocLoad(..) => OpCode::LocLoad,
+        Instruction::LocLoadW(..) => OpCode::LocLoadW,
+        Instruction::MemStore => OpCode::MemStore,
+        Instruction::MemStoreImm(..) => OpCode::MemStoreImm,
+        Instruction::LocStore(..) => OpCode::LocStore,
+        Instruction::MemStoreW => OpCode::MemStoreW,
+        Instruction::MemStoreWImm(..) => OpCode::MemStoreWImm,
+        Instruction::LocStoreW(..) => OpCode::LocStoreW,
+        Instruction::MemStream => OpCode::MemStream,
+        Instruction::AdvPipe => OpCode::AdvPipe,
+        Instruction::AdvPush(..) => OpCode::AdvPush,
+        Instruction::AdvLoadW => OpCode::AdvLoadW,
+        Instruction::AdvInject(..) => OpCode::AdvInject,
+
+        // ----- cryptographic operations ----------------------------------------------------------
+        Instruction::Hash => OpCode::Hash,
+        Instruction::HMerge => OpCode::HMerge,
+        Instruction::HPerm => OpCode::HPerm,
+        Instruction::MTreeGet => OpCode::MTreeGet,
+        Instruction::MTreeSet => OpCode::MTreeSet,
+        Instruction::MTreeMerge => OpCode::MTreeMerge,
+        Instruction::MTreeVerify => OpCode::MTreeVerify,
+
+        // ----- STARK proof verification ----------------------------------------------------------
+        Instruction::FriExt2Fold4 => OpCode::FriExt2Fold4,
+
+        // ----- exec / call -----------------------------------------------------------------------
+        Instruction::ExecLocal(..) => OpCode::ExecLocal,
+        Instruction::ExecImported(..) => OpCode::ExecImported,
+        Instruction::CallLocal(..) => OpCode::CallLocal,
+        Instruction::CallMastRoot(..) => OpCode::CallMastRoot,
+        Instruction::CallImported(..) => OpCode::CallImported,
+        Instruction::SysCall(..) => OpCode::SysCall,
+
+        // ----- host events -----------------------------------------------------------------------
+        Instruction::Emit(..) => OpCode::Emit,
+
+        // ----- debug decorators ------------------------------------------------------------------
+        Instruction::Breakpoint => return None,
+    };
+    Some(opcode)
+}
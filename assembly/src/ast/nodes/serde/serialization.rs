@@ -56,6 +56,7 @@ impl Serializable for Instruction {
             Self::AssertEq => OpCode::AssertEq.write_into(target),
             Self::AssertEqw => OpCode::AssertEqw.write_into(target),
             Self::Assertz => OpCode::Assertz.write_into(target),
+            Self::AssertzW => OpCode::AssertzW.write_into(target),
             Self::Add => OpCode::Add.write_into(target),
             Self::AddImm(v) => {
                 OpCode::AddImm.write_into(target);
@@ -79,6 +80,7 @@ impl Serializable for Instruction {
             Self::Neg => OpCode::Neg.write_into(target),
             Self::Inv => OpCode::Inv.write_into(target),
             Self::Incr => OpCode::Incr.write_into(target),
+            Self::Decr => OpCode::Decr.write_into(target),
             Self::Pow2 => OpCode::Pow2.write_into(target),
             Self::Exp => OpCode::Exp.write_into(target),
             Self::ExpImm(v) => {
@@ -317,6 +319,8 @@ impl Serializable for Instruction {
             Self::SwapW2 => OpCode::SwapW2.write_into(target),
             Self::SwapW3 => OpCode::SwapW3.write_into(target),
             Self::SwapDw => OpCode::SwapDW.write_into(target),
+            Self::SwapNw3 => OpCode::SwapNW3.write_into(target),
+            Self::SwapNw4 => OpCode::SwapNW4.write_into(target),
             Self::MovUp2 => OpCode::MovUp2.write_into(target),
             Self::MovUp3 => OpCode::MovUp3.write_into(target),
             Self::MovUp4 => OpCode::MovUp4.write_into(target),
@@ -399,6 +403,11 @@ impl Serializable for Instruction {
                 OpCode::Locaddr.write_into(target);
                 target.write_u16(*v);
             }
+            Self::LocaddrRange(base, len) => {
+                OpCode::LocaddrRange.write_into(target);
+                target.write_u16(*base);
+                target.write_u16(*len);
+            }
             Self::Sdepth => OpCode::Sdepth.write_into(target),
             Self::Caller => OpCode::Caller.write_into(target),
             Self::Clk => OpCode::Clk.write_into(target),
@@ -492,6 +501,12 @@ impl Serializable for Instruction {
                 imported.write_into(target)
             }
 
+            // ----- host events ------------------------------------------------------------------
+            Self::Emit(event_id) => {
+                OpCode::Emit.write_into(target);
+                target.write_u32(*event_id);
+            }
+
             // ----- debug decorators -------------------------------------------------------------
             Self::Breakpoint => {
                 // this is a transparent instruction and will not be encoded into the library
@@ -29,6 +29,10 @@ pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
             2 => AdvInject(PushExt2intt),
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "push_c25519inv" => match op.num_parts() {
+            2 => AdvInject(PushC25519Inv),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         "push_smtget" => match op.num_parts() {
             2 => AdvInject(PushSmtGet),
             _ => return Err(ParsingError::extra_param(op)),
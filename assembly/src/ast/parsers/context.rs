@@ -1,7 +1,7 @@
 use super::{
     super::ProcReExport, adv_ops, field_ops, io_ops, stack_ops, u32_ops, CodeBody, Instruction,
     InvocationTarget, LibraryPath, LocalConstMap, LocalProcMap, Node, ParsingError, ProcedureAst,
-    ProcedureId, ReExportedProcMap, Token, TokenStream, MAX_BODY_LEN, MAX_DOCS_LEN,
+    ProcedureId, ReExportedProcMap, SourceLocation, Token, TokenStream, MAX_BODY_LEN, MAX_DOCS_LEN,
 };
 use vm_core::utils::{
     collections::{BTreeMap, Vec},
@@ -24,7 +24,15 @@ impl ParserContext<'_> {
     // --------------------------------------------------------------------------------------------
 
     /// Parses an if-else statement from the provided token stream into an AST node.
-    fn parse_if(&self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
+    ///
+    /// If `in_loop` is true, the branches of the statement may exit the enclosing loop via `break`.
+    /// In such a case, both branches are terminated with a flag indicating whether the loop should
+    /// continue, and the returned boolean is set to true.
+    fn parse_if(
+        &self,
+        tokens: &mut TokenStream,
+        in_loop: bool,
+    ) -> Result<(Node, bool), ParsingError> {
         // record start of the if-else block and consume the 'if' token
         let if_start = tokens.pos();
         let if_token = tokens.read().expect("no if token");
@@ -33,11 +41,11 @@ impl ParserContext<'_> {
         tokens.advance();
 
         // read the `if` clause
-        let mut true_case = self.parse_body(tokens, true)?;
+        let (mut true_case, true_breaks) = self.parse_block(tokens, true, in_loop)?;
 
         // build the `else` clause; if the else clause is specified, then parse it;
         // otherwise, set the `else` to an empty vector
-        let false_case = match tokens.read() {
+        let (false_case, false_breaks) = match tokens.read() {
             Some(token) => match token.parts()[0] {
                 Token::ELSE => {
                     // record start of the `else` block and consume the `else` token
@@ -46,7 +54,8 @@ impl ParserContext<'_> {
                     tokens.advance();
 
                     // parse the `false` branch
-                    let false_case = self.parse_body(tokens, false)?;
+                    let (mut false_case, false_breaks) =
+                        self.parse_block(tokens, false, in_loop)?;
 
                     // consume the `end` token
                     match tokens.read() {
@@ -56,6 +65,16 @@ impl ParserContext<'_> {
                         }
                         Some(token) => match token.parts()[0] {
                             Token::END => {
+                                // if any of the branches exits the loop, make sure the other one
+                                // signals that the loop should continue
+                                let location = *token.location();
+                                if false_breaks && !true_breaks {
+                                    true_case = append_node(true_case, loop_flag(true), location);
+                                }
+                                if true_breaks && !false_breaks {
+                                    false_case = append_node(false_case, loop_flag(true), location);
+                                }
+
                                 // the end token is duplicated for the if body so consistency is
                                 // maintained over the assumption that a body is always terminated
                                 // with an `end` location - in this case, both `if.true` and `else`
@@ -73,13 +92,18 @@ impl ParserContext<'_> {
                     tokens.advance();
 
                     // return the `false` branch
-                    false_case
+                    (false_case, false_breaks)
                 }
                 Token::END => {
-                    // consume the `end` token and return an empty vector
+                    // consume the `end` token and return an empty vector; if the `true` branch
+                    // exits the loop, the empty `false` branch signals that the loop should continue
                     token.validate_end()?;
                     tokens.advance();
-                    CodeBody::default()
+                    if true_breaks {
+                        (CodeBody::new([loop_flag(true)]), false)
+                    } else {
+                        (CodeBody::default(), false)
+                    }
                 }
                 _ => {
                     let token = tokens.read_at(if_start).expect("no if token");
@@ -92,10 +116,11 @@ impl ParserContext<'_> {
             }
         };
 
-        Ok(Node::IfElse {
+        let node = Node::IfElse {
             true_case,
             false_case,
-        })
+        };
+        Ok((node, true_breaks || false_breaks))
     }
 
     /// Parses a while statement from the provided token stream into an AST node.
@@ -107,16 +132,16 @@ impl ParserContext<'_> {
         tokens.advance();
 
         // read the loop body
-        let body = self.parse_body(tokens, false)?;
+        let (body, may_break) = self.parse_block(tokens, false, true)?;
 
         // consume the `end` token
-        match tokens.read() {
+        let end_location = match tokens.read() {
             None => {
                 let token = tokens.read_at(while_start).expect("no while token");
                 Err(ParsingError::unmatched_while(token))
             }
             Some(token) => match token.parts()[0] {
-                Token::END => token.validate_end(),
+                Token::END => token.validate_end().map(|_| *token.location()),
                 Token::ELSE => Err(ParsingError::dangling_else(token)),
                 _ => {
                     let token = tokens.read_at(while_start).expect("no while token");
@@ -126,28 +151,44 @@ impl ParserContext<'_> {
         }?;
         tokens.advance();
 
+        // if the body may exit the loop, it leaves a flag on top of the loop condition; the loop
+        // condition is kept if the flag is set, and is replaced with `0` otherwise.
+        let body = if may_break {
+            let exit = Node::IfElse {
+                true_case: CodeBody::default(),
+                false_case: CodeBody::new([loop_flag(false)]),
+            };
+            append_node(body, exit, end_location)
+        } else {
+            body
+        };
+
         Ok(Node::While { body })
     }
 
-    /// Parses a repeat statement from the provided token stream into an AST node.
-    fn parse_repeat(&self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
+    /// Parses a repeat statement from the provided token stream into AST nodes.
+    ///
+    /// A repeat statement is parsed into a single node, unless its body may exit the loop, in
+    /// which case it is lowered into a sequence of nodes.
+    fn parse_repeat(&self, tokens: &mut TokenStream) -> Result<Vec<Node>, ParsingError> {
         // record start of the repeat block and consume the 'repeat' token
         let repeat_start = tokens.pos();
         let repeat_token = tokens.read().expect("no repeat token");
+        let repeat_location = *repeat_token.location();
         let times = repeat_token.parse_repeat()?;
         tokens.advance();
 
         // read the loop body
-        let body = self.parse_body(tokens, false)?;
+        let (body, may_break) = self.parse_block(tokens, false, true)?;
 
         // consume the `end` token
-        match tokens.read() {
+        let end_location = match tokens.read() {
             None => {
                 let token = tokens.read_at(repeat_start).expect("no repeat token");
                 Err(ParsingError::unmatched_repeat(token))
             }
             Some(token) => match token.parts()[0] {
-                Token::END => token.validate_end(),
+                Token::END => token.validate_end().map(|_| *token.location()),
                 Token::ELSE => Err(ParsingError::dangling_else(token)),
                 _ => {
                     let token = tokens.read_at(repeat_start).expect("no repeat token");
@@ -157,7 +198,26 @@ impl ParserContext<'_> {
         }?;
         tokens.advance();
 
-        Ok(Node::Repeat { times, body })
+        if !may_break {
+            return Ok(Vec::from([Node::Repeat { times, body }]));
+        }
+
+        // if the body may exit the loop, every iteration is executed only if the flag left on the
+        // stack by the previous iteration is set; once the loop is exited, the remaining
+        // iterations keep the flag unset. The flag is initially set, and the flag left by the last
+        // iteration is dropped. This keeps the nesting depth of the loop independent of `times`.
+        let guarded_body = Node::IfElse {
+            true_case: body,
+            false_case: CodeBody::new([loop_flag(false)]),
+        };
+        let body =
+            CodeBody::new([guarded_body]).with_source_locations([repeat_location, end_location]);
+
+        Ok(Vec::from([
+            loop_flag(true),
+            Node::Repeat { times, body },
+            Node::Instruction(Instruction::Drop),
+        ]))
     }
 
     // CALL PARSERS
@@ -361,16 +421,77 @@ impl ParserContext<'_> {
         tokens: &mut TokenStream,
         break_on_else: bool,
     ) -> Result<CodeBody, ParsingError> {
+        self.parse_block(tokens, break_on_else, false).map(|(body, _)| body)
+    }
+
+    /// Parses AST tokens from the token stream into a code body, the same way as
+    /// [ParserContext::parse_body] does.
+    ///
+    /// If `in_loop` is true, the body may contain `break` instructions exiting the innermost
+    /// enclosing loop. These are lowered as follows:
+    /// - `break` pushes `0` onto the stack, and must be the last instruction of the body.
+    /// - If an if-else statement may exit the loop, the remainder of the body is executed only if
+    ///   the flag left on the stack by the statement is set, and pushes `1` at its end.
+    ///
+    /// Thus, when the returned boolean is true, the body leaves a flag on top of the stack which is
+    /// set to `1` if the loop should continue and to `0` if it should be exited.
+    fn parse_block(
+        &self,
+        tokens: &mut TokenStream,
+        break_on_else: bool,
+        in_loop: bool,
+    ) -> Result<(CodeBody, bool), ParsingError> {
         let start_pos = tokens.pos();
         let mut nodes = Vec::new();
         let mut locations = Vec::new();
+        let mut may_break = false;
 
         while let Some(token) = tokens.read() {
+            // once the loop may be exited, the remainder of the body has been consumed already;
+            // thus, anything else than the end of the body is unreachable
+            if may_break
+                && !matches!(
+                    token.parts()[0],
+                    Token::ELSE | Token::END | Token::EXPORT | Token::PROC | Token::BEGIN
+                )
+            {
+                return Err(ParsingError::dangling_ops_after_break(token));
+            }
+
             match token.parts()[0] {
                 Token::IF => {
                     locations.push(*token.location());
-                    let body = self.parse_if(tokens)?;
+                    let (body, if_may_break) = self.parse_if(tokens, in_loop)?;
                     nodes.push(body);
+
+                    if if_may_break {
+                        // execute the remainder of the body only if the loop was not exited
+                        let location = next_location(tokens);
+                        let (rest, rest_may_break) =
+                            self.parse_block(tokens, break_on_else, in_loop)?;
+                        let rest = if rest_may_break {
+                            rest
+                        } else {
+                            append_node(rest, loop_flag(true), next_location(tokens))
+                        };
+
+                        locations.push(location);
+                        nodes.push(Node::IfElse {
+                            true_case: rest,
+                            false_case: CodeBody::new([loop_flag(false)]),
+                        });
+                        may_break = true;
+                    }
+                }
+                Token::BREAK => {
+                    if !in_loop {
+                        return Err(ParsingError::break_outside_loop(token));
+                    }
+                    token.validate_break()?;
+                    locations.push(*token.location());
+                    nodes.push(loop_flag(false));
+                    may_break = true;
+                    tokens.advance();
                 }
                 Token::ELSE => {
                     token.validate_else()?;
@@ -385,9 +506,10 @@ impl ParserContext<'_> {
                     nodes.push(body);
                 }
                 Token::REPEAT => {
-                    locations.push(*token.location());
-                    let body = self.parse_repeat(tokens)?;
-                    nodes.push(body);
+                    let location = *token.location();
+                    let repeat_nodes = self.parse_repeat(tokens)?;
+                    locations.resize(locations.len() + repeat_nodes.len(), location);
+                    nodes.extend(repeat_nodes);
                 }
                 Token::END => {
                     locations.push(*token.location());
@@ -403,8 +525,9 @@ impl ParserContext<'_> {
                     break;
                 }
                 _ => {
-                    locations.push(*token.location());
-                    nodes.push(self.parse_op_token(token)?);
+                    let op_nodes = self.parse_op_token(token)?;
+                    locations.resize(locations.len() + op_nodes.len(), *token.location());
+                    nodes.extend(op_nodes);
                     tokens.advance();
                 }
             }
@@ -415,21 +538,36 @@ impl ParserContext<'_> {
             return Err(ParsingError::body_too_long(token, nodes.len(), MAX_BODY_LEN));
         }
 
-        Ok(CodeBody::new(nodes).with_source_locations(locations))
+        Ok((CodeBody::new(nodes).with_source_locations(locations), may_break))
     }
 
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
-    /// Parses a token into an instruction node.
-    fn parse_op_token(&self, op: &Token) -> Result<Node, ParsingError> {
+    /// Parses a token into instruction nodes.
+    ///
+    /// Most instructions are parsed into a single node; instructions which cannot be represented
+    /// by a single node (e.g., `movup.dyn`) are lowered into a sequence of nodes, and instructions
+    /// which do nothing (e.g., `push.range.2.2`) are parsed into no nodes.
+    fn parse_op_token(&self, op: &Token) -> Result<Vec<Node>, ParsingError> {
         use Instruction::*;
 
+        // instructions with a stack index provided at runtime are lowered into several nodes
+        if let ["dup" | "movup" | "movdn", "dyn"] = op.parts() {
+            return Ok(stack_ops::parse_dynamic_move(op));
+        }
+
+        // an empty range of values does not push anything, and thus, is parsed into no nodes
+        if let ["push", "range", ..] = op.parts() {
+            return io_ops::parse_push_range(op, &self.local_constants);
+        }
+
         // based on the instruction, invoke the correct parser for the operation
-        match op.parts()[0] {
+        let node = match op.parts()[0] {
             // ----- field operations -------------------------------------------------------------
             "assert" => simple_instruction(op, Assert),
             "assertz" => simple_instruction(op, Assertz),
+            "assertz_w" => simple_instruction(op, AssertzW),
             "assert_eq" => simple_instruction(op, AssertEq),
             "assert_eqw" => simple_instruction(op, AssertEqw),
 
@@ -439,6 +577,8 @@ impl ParserContext<'_> {
             "div" => field_ops::parse_div(op),
             "neg" => simple_instruction(op, Neg),
             "inv" => simple_instruction(op, Inv),
+            "incr" => simple_instruction(op, Incr),
+            "decr" => simple_instruction(op, Decr),
 
             "pow2" => simple_instruction(op, Pow2),
             "exp" => field_ops::parse_exp(op),
@@ -550,6 +690,7 @@ impl ParserContext<'_> {
             "swap" => stack_ops::parse_swap(op),
             "swapw" => stack_ops::parse_swapw(op),
             "swapdw" => simple_instruction(op, SwapDw),
+            "swapnw" => stack_ops::parse_swapnw(op),
             "movup" => stack_ops::parse_movup(op),
             "movupw" => stack_ops::parse_movupw(op),
             "movdn" => stack_ops::parse_movdn(op),
@@ -605,6 +746,9 @@ impl ParserContext<'_> {
             "call" => self.parse_call(op),
             "syscall" => self.parse_syscall(op),
 
+            // ----- host events ------------------------------------------------------------------
+            "emit" => io_ops::parse_emit(op, &self.local_constants),
+
             // ----- constant statements ----------------------------------------------------------
             "const" => Err(ParsingError::const_invalid_scope(op)),
 
@@ -613,7 +757,9 @@ impl ParserContext<'_> {
 
             // ----- catch all --------------------------------------------------------------------
             _ => Err(ParsingError::invalid_op(op)),
-        }
+        }?;
+
+        Ok(Vec::from([node]))
     }
 
     /// Returns an index of a local procedure for the specified procedure name.
@@ -669,3 +815,24 @@ fn simple_instruction(op: &Token, instruction: Instruction) -> Result<Node, Pars
         _ => Err(ParsingError::extra_param(op)),
     }
 }
+
+/// Returns a node pushing the flag which indicates whether the enclosing loop should continue
+/// (`1`) or be exited (`0`).
+fn loop_flag(should_continue: bool) -> Node {
+    Node::Instruction(Instruction::PushU8(should_continue as u8))
+}
+
+/// Appends the provided node to the end of the body, keeping the location of the `end` token (if
+/// present) last.
+fn append_node(body: CodeBody, node: Node, location: SourceLocation) -> CodeBody {
+    let (mut nodes, mut locations) = body.into_parts();
+    locations.insert(nodes.len().min(locations.len()), location);
+    nodes.push(node);
+    CodeBody::new(nodes).with_source_locations(locations)
+}
+
+/// Returns the location of the next token in the stream, or the end of file location if there
+/// are no more tokens.
+fn next_location(tokens: &TokenStream) -> SourceLocation {
+    tokens.read().map_or(*tokens.eof_location(), |token| *token.location())
+}
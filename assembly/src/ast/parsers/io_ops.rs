@@ -25,6 +25,10 @@ const MAX_PUSH_PARTS: usize = MAX_PUSH_INPUTS + 1;
 /// values.
 pub fn parse_push(op: &Token, constants: &LocalConstMap) -> Result<Node, ParsingError> {
     debug_assert_eq!(op.parts()[0], "push");
+    match op.parts().get(1) {
+        Some(&"u8") | Some(&"u16") | Some(&"u32") => return parse_push_list(op),
+        _ => (),
+    }
     match op.num_parts() {
         0 => unreachable!("missing token"),
         1 => Err(ParsingError::missing_param(op)),
@@ -56,11 +60,107 @@ pub fn parse_push(op: &Token, constants: &LocalConstMap) -> Result<Node, Parsing
     }
 }
 
-/// Returns `Locaddr` instruction node.
+/// Returns the `Push` instruction nodes for the `push.range.start.end` instruction, which pushes
+/// the ascending sequence of values `[start, start + 1, ..., end - 1]` onto the stack.
+///
+/// An empty range (i.e., `start == end`) does not push anything and results in no nodes.
 ///
 /// # Errors
-/// Returns an error if the instruction token contains a wrong number of parameters, or if
-/// the provided parameter is not a u16 value.
+/// Returns an error if the instruction token does not have exactly two range bounds, if either
+/// of the bounds is not a valid field element, if `start` is greater than `end`, or if the range
+/// contains more than `MAX_PUSH_INPUTS` values.
+pub fn parse_push_range(op: &Token, constants: &LocalConstMap) -> Result<Vec<Node>, ParsingError> {
+    debug_assert_eq!(op.parts()[1], "range");
+    match op.num_parts() {
+        0..=1 => unreachable!(),
+        2..=3 => Err(ParsingError::missing_param(op)),
+        4 => {
+            let start =
+                parse_non_hex_param_with_constants_lookup(op, constants, 2, 0..Felt::MODULUS)?;
+            let end =
+                parse_non_hex_param_with_constants_lookup(op, constants, 3, 0..Felt::MODULUS)?;
+            if start > end {
+                return Err(ParsingError::invalid_param_with_reason(
+                    op,
+                    3,
+                    "range end must not be smaller than range start",
+                ));
+            }
+            if end - start > MAX_PUSH_INPUTS as u64 {
+                return Err(ParsingError::invalid_param_with_reason(
+                    op,
+                    3,
+                    &format!("range must not contain more than {MAX_PUSH_INPUTS} values"),
+                ));
+            }
+            let node = match end - start {
+                0 => return Ok(Vec::new()),
+                1 => build_push_one_instruction(start),
+                _ => {
+                    let values = (start..end).collect::<Vec<_>>();
+                    build_push_many_instruction(values.into_iter().map(Ok))
+                }
+            }?;
+            Ok(Vec::from([node]))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns one of the `PushU8List`, `PushU16List`, or `PushU32List` instruction nodes for the
+/// `push.u8.[a,b,c]`, `push.u16.[a,b,c]`, and `push.u32.[a,b,c]` instructions respectively. The
+/// values are pushed onto the stack in the order in which they are listed.
+///
+/// # Errors
+/// Returns an error if the instruction token does not have exactly one list parameter, if the list
+/// is not enclosed in square brackets, if the list is empty or contains more than
+/// `MAX_PUSH_INPUTS` values, or if any of the values does not fit into the stated width.
+fn parse_push_list(op: &Token) -> Result<Node, ParsingError> {
+    match op.num_parts() {
+        0..=1 => unreachable!(),
+        2 => return Err(ParsingError::missing_param(op)),
+        3 => (),
+        _ => return Err(ParsingError::extra_param(op)),
+    }
+
+    let list = op.parts()[2]
+        .strip_prefix('[')
+        .and_then(|list| list.strip_suffix(']'))
+        .ok_or_else(|| {
+            ParsingError::invalid_param_with_reason(
+                op,
+                2,
+                "list of values must be enclosed in square brackets",
+            )
+        })?;
+    if list.is_empty() {
+        return Err(ParsingError::missing_param(op));
+    }
+
+    let values = list.split(',').collect::<Vec<_>>();
+    if values.len() > MAX_PUSH_INPUTS {
+        return Err(ParsingError::invalid_param_with_reason(
+            op,
+            2,
+            &format!("list must not contain more than {MAX_PUSH_INPUTS} values"),
+        ));
+    }
+
+    match op.parts()[1] {
+        "u8" => Ok(Instruction(PushU8List(parse_list_values(op, &values)?))),
+        "u16" => Ok(Instruction(PushU16List(parse_list_values(op, &values)?))),
+        "u32" => Ok(Instruction(PushU32List(parse_list_values(op, &values)?))),
+        _ => unreachable!(),
+    }
+}
+
+/// Returns `Locaddr` instruction node if a single parameter is provided, or `LocaddrRange`
+/// instruction node if both the base index and the length of a local array are provided.
+///
+/// # Errors
+/// Returns an error if the instruction token contains a wrong number of parameters, if any of
+/// the provided parameters is not a u16 value, if the array length is zero, or if the array
+/// extends beyond the u16 index range.
 pub fn parse_locaddr(op: &Token, constants: &LocalConstMap) -> Result<Node, ParsingError> {
     debug_assert_eq!(op.parts()[0], "locaddr");
     match op.num_parts() {
@@ -70,6 +170,43 @@ pub fn parse_locaddr(op: &Token, constants: &LocalConstMap) -> Result<Node, Pars
             let index = parse_param_with_constant_lookup::<u16>(op, 1, constants)?;
             Ok(Instruction(Locaddr(index)))
         }
+        3 => {
+            let base = parse_param_with_constant_lookup::<u16>(op, 1, constants)?;
+            let len = parse_param_with_constant_lookup::<u16>(op, 2, constants)?;
+            if len == 0 {
+                return Err(ParsingError::invalid_param_with_reason(
+                    op,
+                    2,
+                    "local array length must be greater than zero",
+                ));
+            }
+            if base.checked_add(len).is_none() {
+                return Err(ParsingError::invalid_param_with_reason(
+                    op,
+                    2,
+                    "local array extends beyond the maximum local index",
+                ));
+            }
+            Ok(Instruction(LocaddrRange(base, len)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `Emit` instruction node.
+///
+/// # Errors
+/// Returns an error if the instruction token does not have exactly one parameter, or if the
+/// parameter is not a u32 value.
+pub fn parse_emit(op: &Token, constants: &LocalConstMap) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "emit");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Err(ParsingError::missing_param(op)),
+        2 => {
+            let event_id = parse_param_with_constant_lookup::<u32>(op, 1, constants)?;
+            Ok(Instruction(Emit(event_id)))
+        }
         _ => Err(ParsingError::extra_param(op)),
     }
 }
@@ -311,6 +448,28 @@ fn parse_long_hex_param(op: &Token, param_str: &str) -> Result<Node, ParsingErro
     build_push_many_instruction(values)
 }
 
+/// Parses the values of a `push.<type>.[...]` list into values of the specified type.
+///
+/// # Errors
+/// Returns an error if any of the values is not a valid decimal integer which fits into type `T`.
+fn parse_list_values<T>(op: &Token, values: &[&str]) -> Result<Vec<T>, ParsingError>
+where
+    T: core::str::FromStr,
+{
+    values
+        .iter()
+        .map(|value| {
+            value.parse::<T>().map_err(|_| {
+                ParsingError::invalid_param_with_reason(
+                    op,
+                    2,
+                    &format!("value '{value}' does not fit into {}", op.parts()[1]),
+                )
+            })
+        })
+        .collect()
+}
+
 /// Parses a hexadecimal parameter value into a u64.
 ///
 /// # Errors
@@ -1,9 +1,9 @@
 use super::{
     bound_into_included_u64, AdviceInjectorNode, BTreeMap, CodeBody, Deserializable, Felt,
     Instruction, InvocationTarget, LabelError, LibraryPath, LocalConstMap, LocalProcMap, Node,
-    ParsingError, ProcedureAst, ProcedureId, ReExportedProcMap, RpoDigest, SliceReader, StarkField,
-    String, ToString, Token, TokenStream, Vec, MAX_BODY_LEN, MAX_DOCS_LEN, MAX_IMPORTS,
-    MAX_LABEL_LEN, MAX_STACK_WORD_OFFSET,
+    ParsingError, ProcedureAst, ProcedureId, ReExportedProcMap, RpoDigest, SliceReader,
+    SourceLocation, StarkField, String, ToString, Token, TokenStream, Vec, MAX_BODY_LEN,
+    MAX_DOCS_LEN, MAX_IMPORTS, MAX_LABEL_LEN, MAX_STACK_WORD_OFFSET,
 };
 use core::{fmt::Display, ops::RangeBounds};
 
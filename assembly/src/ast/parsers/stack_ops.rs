@@ -1,9 +1,16 @@
 use super::{
-    Instruction::*,
+    CodeBody,
+    Instruction::{self as Inst, *},
     Node::{self, Instruction},
-    ParsingError, Token,
+    ParsingError, Token, Vec,
 };
 
+// CONSTANTS
+// ================================================================================================
+
+/// Number of stack items addressable by `dup.dyn`, `movup.dyn`, and `movdn.dyn` instructions.
+const DYNAMIC_MOVE_RANGE: u8 = 16;
+
 // INSTRUCTION PARSERS
 // ================================================================================================
 
@@ -117,6 +124,30 @@ pub fn parse_swapw(op: &Token) -> Result<Node, ParsingError> {
     }
 }
 
+/// Returns `SwapW1` or `SwapDw` instruction node according to the immediate value of the
+/// `swapnw.k` instruction, which swaps the top `k` words of the stack with the next `k` words.
+///
+/// `swapnw.k` touches the top `2k` words (`8k` elements) of the stack. Only the top 16 elements of
+/// the stack can be rearranged by stack manipulation operations, and thus, `k` is limited to 1
+/// (equivalent to `swapw`) and 2 (equivalent to `swapdw`).
+///
+/// # Errors
+/// Returns an error if the instruction token contains wrong number of parameters, or if the
+/// provided parameter is smaller than 1 or greater than 2.
+pub fn parse_swapnw(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "swapnw");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Err(ParsingError::missing_param(op)),
+        2 => match op.parts()[1] {
+            "1" => Ok(Instruction(SwapW1)),
+            "2" => Ok(Instruction(SwapDw)),
+            _ => Err(ParsingError::invalid_param(op, 1)),
+        },
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 /// Returns one of the `MovUp2` — `MovUp15` instruction nodes according to the immediate value.
 ///
 /// # Errors
@@ -216,3 +247,141 @@ pub fn parse_movdnw(op: &Token) -> Result<Node, ParsingError> {
         _ => Err(ParsingError::extra_param(op)),
     }
 }
+
+/// Returns the nodes for `dup.dyn`, `movup.dyn`, and `movdn.dyn` instructions, which duplicate or
+/// move a stack item at an index read from the top of the stack at runtime.
+///
+/// See [build_dynamic_move] for details on how these instructions are lowered.
+pub fn parse_dynamic_move(op: &Token) -> Vec<Node> {
+    debug_assert_eq!(op.parts()[1], "dyn");
+    match op.parts()[0] {
+        "dup" => build_dynamic_move(|idx| Some(dup_instruction(idx))),
+        "movup" => build_dynamic_move(movup_instruction),
+        "movdn" => build_dynamic_move(movdn_instruction),
+        _ => unreachable!("invalid dynamic move instruction {}", op.parts()[0]),
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns nodes which read an index `i` from the top of the stack and perform a move (or a
+/// duplication) with the stack item at index `i` (counting from the item directly below `i`) using
+/// the provided fixed-index instruction.
+///
+/// Since an index known only at runtime cannot be encoded into a single operation, the move is
+/// lowered into a binary tree of `if.true` blocks of depth 4: at every level, the index is
+/// compared against the middle of the remaining range of candidate indexes, and the matching half
+/// of the range is selected. Each of the 16 leaves of the tree drops the index and executes the
+/// fixed-index move for its position. The tree is preceded by a check that `i` is smaller than 16.
+///
+/// Including the overhead of the control flow blocks, executing the resulting code takes between
+/// 63 and 69 VM cycles depending on the value of `i`.
+fn build_dynamic_move(move_instruction: fn(u8) -> Option<Inst>) -> Vec<Node> {
+    [
+        Instruction(Dup0),
+        Instruction(PushU8(DYNAMIC_MOVE_RANGE)),
+        Instruction(U32CheckedLt),
+        Instruction(Assert),
+    ]
+    .into_iter()
+    .chain(build_dynamic_move_tree(0, DYNAMIC_MOVE_RANGE, move_instruction))
+    .collect()
+}
+
+/// Returns nodes selecting the move for the index on top of the stack out of the indexes in the
+/// range [start, end).
+fn build_dynamic_move_tree(
+    start: u8,
+    end: u8,
+    move_instruction: fn(u8) -> Option<Inst>,
+) -> Vec<Node> {
+    if end - start == 1 {
+        return [Instruction(Drop)]
+            .into_iter()
+            .chain(move_instruction(start).map(Instruction))
+            .collect();
+    }
+
+    let mid = start + (end - start) / 2;
+    Vec::from([
+        Instruction(Dup0),
+        Instruction(PushU8(mid)),
+        Instruction(U32UncheckedLt),
+        Node::IfElse {
+            true_case: CodeBody::new(build_dynamic_move_tree(start, mid, move_instruction)),
+            false_case: CodeBody::new(build_dynamic_move_tree(mid, end, move_instruction)),
+        },
+    ])
+}
+
+/// Returns an instruction copying the stack item at the specified index to the top of the stack.
+fn dup_instruction(idx: u8) -> Inst {
+    match idx {
+        0 => Dup0,
+        1 => Dup1,
+        2 => Dup2,
+        3 => Dup3,
+        4 => Dup4,
+        5 => Dup5,
+        6 => Dup6,
+        7 => Dup7,
+        8 => Dup8,
+        9 => Dup9,
+        10 => Dup10,
+        11 => Dup11,
+        12 => Dup12,
+        13 => Dup13,
+        14 => Dup14,
+        15 => Dup15,
+        _ => unreachable!("invalid stack index {idx}"),
+    }
+}
+
+/// Returns an instruction moving the stack item at the specified index to the top of the stack,
+/// or None if no instruction is needed.
+fn movup_instruction(idx: u8) -> Option<Inst> {
+    match idx {
+        0 => None,
+        1 => Some(Swap1),
+        2 => Some(MovUp2),
+        3 => Some(MovUp3),
+        4 => Some(MovUp4),
+        5 => Some(MovUp5),
+        6 => Some(MovUp6),
+        7 => Some(MovUp7),
+        8 => Some(MovUp8),
+        9 => Some(MovUp9),
+        10 => Some(MovUp10),
+        11 => Some(MovUp11),
+        12 => Some(MovUp12),
+        13 => Some(MovUp13),
+        14 => Some(MovUp14),
+        15 => Some(MovUp15),
+        _ => unreachable!("invalid stack index {idx}"),
+    }
+}
+
+/// Returns an instruction moving the top stack item to the specified index, or None if no
+/// instruction is needed.
+fn movdn_instruction(idx: u8) -> Option<Inst> {
+    match idx {
+        0 => None,
+        1 => Some(Swap1),
+        2 => Some(MovDn2),
+        3 => Some(MovDn3),
+        4 => Some(MovDn4),
+        5 => Some(MovDn5),
+        6 => Some(MovDn6),
+        7 => Some(MovDn7),
+        8 => Some(MovDn8),
+        9 => Some(MovDn9),
+        10 => Some(MovDn10),
+        11 => Some(MovDn11),
+        12 => Some(MovDn12),
+        13 => Some(MovDn13),
+        14 => Some(MovDn14),
+        15 => Some(MovDn15),
+        _ => unreachable!("invalid stack index {idx}"),
+    }
+}
@@ -1,8 +1,10 @@
 use super::{
-    AstSerdeOptions, BTreeMap, CodeBody, Felt, Instruction, LocalProcMap, ModuleAst, Node,
-    ParsingError, ProcedureAst, ProcedureId, ProgramAst, SourceLocation, Token,
+    instruction_opcode, AdviceInjectorNode, AstSerdeError, AstSerdeOptions, BTreeMap, CodeBody,
+    Deserializable, Felt, Instruction, LocalProcMap, ModuleAst, Node, OpCategory, OpCode,
+    ParsingError, ProcedureAst, ProcedureId, ProgramAst, RpoDigest, Serializable, SourceLocation,
+    Token, PROGRAM_SERDE_VERSION,
 };
-use vm_core::utils::SliceReader;
+use vm_core::utils::{DeserializationError, SliceReader};
 
 // UNIT TESTS
 // ================================================================================================
@@ -20,6 +22,30 @@ fn test_ast_parsing_program_simple() {
     assert_program_output(source, BTreeMap::new(), nodes);
 }
 
+#[test]
+fn test_ast_parsing_program_assertz_w() {
+    let source = "begin push.0.0.0.0 assertz_w end";
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Instruction::PushU8List(vec![0, 0, 0, 0])),
+        Node::Instruction(Instruction::AssertzW),
+    ];
+
+    assert_program_output(source, BTreeMap::new(), nodes);
+}
+
+#[test]
+fn test_ast_parsing_program_incr_decr() {
+    let source = "begin incr decr add.1 sub.1 end";
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Instruction::Incr),
+        Node::Instruction(Instruction::Decr),
+        Node::Instruction(Instruction::Incr),
+        Node::Instruction(Instruction::SubImm(Felt::new(1))),
+    ];
+
+    assert_program_output(source, BTreeMap::new(), nodes);
+}
+
 #[test]
 fn test_ast_parsing_program_push() {
     let source = "begin push.10 push.500 push.70000 push.5000000000 push.5000000000.7000000000.9000000000.11000000000 push.5.7 push.500.700 push.70000.90000 push.5000000000.7000000000 end";
@@ -50,6 +76,31 @@ fn test_ast_parsing_program_push() {
     assert_program_output(source, BTreeMap::new(), nodes);
 }
 
+#[test]
+fn test_ast_parsing_program_push_list() {
+    let source = "begin push.u8.[1,2,3] push.u16.[1,65535] push.u32.[7,4294967295] push.u8.[5] end";
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Instruction::PushU8List(vec![1, 2, 3])),
+        Node::Instruction(Instruction::PushU16List(vec![1, 65535])),
+        Node::Instruction(Instruction::PushU32List(vec![7, 4294967295])),
+        Node::Instruction(Instruction::PushU8List(vec![5])),
+    ];
+
+    assert_program_output(source, BTreeMap::new(), nodes);
+}
+
+#[test]
+fn test_ast_parsing_program_push_range() {
+    let source = "begin push.range.3.7 push.range.65534.65538 push.range.5.6 push.range.2.2 end";
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Instruction::PushU8List(vec![3, 4, 5, 6])),
+        Node::Instruction(Instruction::PushU32List(vec![65534, 65535, 65536, 65537])),
+        Node::Instruction(Instruction::PushU8(5)),
+    ];
+
+    assert_program_output(source, BTreeMap::new(), nodes);
+}
+
 #[test]
 fn test_ast_parsing_program_u32() {
     let source = "\
@@ -141,6 +192,111 @@ fn test_ast_parsing_program_proc() {
     assert_program_output(source, procedures, nodes);
 }
 
+#[test]
+fn test_ast_source_location_of_instruction() {
+    let source = "\
+begin
+    push.1 push.2
+    add
+    if.true
+        mul
+    end
+end";
+    let program = ProgramAst::parse(source).unwrap();
+
+    // the locations are kept alongside the nodes of the body
+    let (node, location) = program.body().into_iter().nth(2).unwrap();
+    assert_eq!(&Node::Instruction(Instruction::Add), node);
+    assert_eq!(3, location.line());
+    assert_eq!(5, location.column());
+
+    let (node, location) = program.body().into_iter().nth(1).unwrap();
+    assert_eq!(&Node::Instruction(Instruction::PushU8(2)), node);
+    assert_eq!(SourceLocation::new(2, 12), *location);
+
+    // nested blocks keep the locations of their own nodes
+    let Node::IfElse { true_case, .. } = &program.body().nodes()[3] else {
+        panic!("expected an if.true block");
+    };
+    let (node, location) = true_case.into_iter().next().unwrap();
+    assert_eq!(&Node::Instruction(Instruction::Mul), node);
+    assert_eq!(SourceLocation::new(5, 9), *location);
+
+    // locations do not affect node equality
+    let clear_program = clear_procs_loc_program(program.clone());
+    assert_eq!(program.body().nodes(), clear_program.body().nodes());
+    assert_eq!(program, clear_program);
+}
+
+#[test]
+fn test_ast_parsing_program_dynamic_move() {
+    let source = "\
+begin
+    push.3
+    movup.dyn
+end";
+    let program = ProgramAst::parse(source).unwrap();
+
+    // the instruction is lowered directly into the body: the index check, followed by the
+    // comparison against the middle of the range and the selection of one of its halves
+    let nodes = program.body().nodes();
+    assert_eq!(9, nodes.len());
+    assert_eq!(
+        [
+            Node::Instruction(Instruction::Dup0),
+            Node::Instruction(Instruction::PushU8(16)),
+            Node::Instruction(Instruction::U32CheckedLt),
+            Node::Instruction(Instruction::Assert),
+            Node::Instruction(Instruction::Dup0),
+            Node::Instruction(Instruction::PushU8(8)),
+            Node::Instruction(Instruction::U32UncheckedLt),
+        ],
+        nodes[1..8]
+    );
+    assert!(matches!(nodes[8], Node::IfElse { .. }));
+
+    // all of the nodes share the location of the instruction
+    for (_, location) in program.body().into_iter().skip(1) {
+        assert_eq!(SourceLocation::new(3, 5), *location);
+    }
+}
+
+#[test]
+fn test_ast_parsing_program_repeat_break() {
+    let source = "\
+begin
+    repeat.5000
+        dup eq.0
+        if.true
+            break
+        end
+        sub.1
+    end
+end";
+    let program = ProgramAst::parse(source).unwrap();
+
+    // the loop is not unrolled: every iteration is guarded by the flag left by the previous one
+    let nodes = program.body().nodes();
+    assert_eq!(3, nodes.len());
+    assert_eq!(Node::Instruction(Instruction::PushU8(1)), nodes[0]);
+    assert_eq!(Node::Instruction(Instruction::Drop), nodes[2]);
+    match &nodes[1] {
+        Node::Repeat { times, body } => {
+            assert_eq!(5000, *times);
+            assert_eq!(1, body.nodes().len());
+            match &body.nodes()[0] {
+                Node::IfElse { false_case, .. } => {
+                    assert_eq!(&[Node::Instruction(Instruction::PushU8(0))], false_case.nodes());
+                }
+                node => panic!("expected an if-else node, found {node:?}"),
+            }
+        }
+        node => panic!("expected a repeat node, found {node:?}"),
+    }
+
+    assert_correct_program_serialization(source, false);
+}
+
 #[test]
 fn test_ast_parsing_module() {
     let source = "\
@@ -196,12 +352,14 @@ fn test_ast_parsing_adv_injection() {
     use super::AdviceInjectorNode::*;
     use Instruction::AdvInject;
 
-    let source = "begin adv.push_u64div adv.push_mapval adv.push_smtget adv.insert_mem end";
+    let source = "begin adv.push_u64div adv.push_mapval adv.push_smtget adv.insert_mem \
+        adv.push_c25519inv end";
     let nodes: Vec<Node> = vec![
         Node::Instruction(AdvInject(PushU64div)),
         Node::Instruction(AdvInject(PushMapVal)),
         Node::Instruction(AdvInject(PushSmtGet)),
         Node::Instruction(AdvInject(InsertMem)),
+        Node::Instruction(AdvInject(PushC25519Inv)),
     ];
 
     assert_program_output(source, BTreeMap::new(), nodes);
@@ -496,6 +654,36 @@ fn test_unterminated_if() {
     }
 }
 
+#[test]
+fn test_break_outside_loop() {
+    let source = "begin push.1 if.true break end end";
+
+    let result = ProgramAst::parse(source);
+    match result {
+        Ok(_) => assert!(false),
+        Err(err) => assert!(err.to_string().contains("break outside of a while or repeat loop")),
+    }
+
+    let source = "proc.foo push.1 break end";
+
+    let result = ModuleAst::parse(source);
+    match result {
+        Ok(_) => assert!(false),
+        Err(err) => assert!(err.to_string().contains("break outside of a while or repeat loop")),
+    }
+}
+
+#[test]
+fn test_dangling_ops_after_break() {
+    let source = "begin push.1 while.true break push.1 end end";
+
+    let result = ProgramAst::parse(source);
+    match result {
+        Ok(_) => assert!(false),
+        Err(err) => assert!(err.to_string().contains("unreachable instructions after break")),
+    }
+}
+
 // DOCUMENTATION PARSING TESTS
 // ================================================================================================
 
@@ -730,6 +918,12 @@ fn test_ast_program_serde_simple() {
     assert_correct_program_serialization(source, false);
 }
 
+#[test]
+fn test_ast_program_serde_assertz_w() {
+    let source = "begin push.0.0.0.0 assertz_w end";
+    assert_correct_program_serialization(source, false);
+}
+
 #[test]
 fn test_ast_program_serde_local_procs() {
     let source = "\
@@ -790,6 +984,48 @@ fn test_ast_program_serde_control_flow() {
     assert_correct_program_serialization(source, false);
 }
 
+#[test]
+fn test_ast_program_serde_version() {
+    let program = ProgramAst::parse("begin push.1 push.2 add end").unwrap();
+    let mut program_serialized = program.to_bytes(AstSerdeOptions::new(false));
+    assert_eq!(PROGRAM_SERDE_VERSION, program_serialized[0]);
+    let program_deserialized = ProgramAst::from_bytes(&program_serialized).unwrap();
+    assert_eq!(clear_procs_loc_program(program), program_deserialized);
+
+    // a program serialized with a different version of the format is rejected
+    program_serialized[0] = PROGRAM_SERDE_VERSION + 1;
+    let err = ProgramAst::from_bytes(&program_serialized).unwrap_err();
+    let expected = AstSerdeError::UnsupportedVersion {
+        found: PROGRAM_SERDE_VERSION + 1,
+        expected: PROGRAM_SERDE_VERSION,
+    };
+    assert_eq!(expected, err);
+}
+
+#[test]
+fn test_ast_program_serde_loop_break() {
+    let source = "\
+    begin
+        push.1
+        while.true
+            dup eq.7
+            if.true
+                break
+            else
+                repeat.3
+                    dup eq.0
+                    if.true
+                        break
+                    end
+                    sub.1
+                end
+            end
+            dup neq.0
+        end
+    end";
+    assert_correct_program_serialization(source, false);
+}
+
 #[test]
 fn assert_parsing_line_unmatched_begin() {
     let source = format!("\n\nbegin\npush.1.2\n\nadd mul");
@@ -806,6 +1042,80 @@ fn assert_parsing_line_extra_param() {
     assert_eq!(err, ParsingError::extra_param(&Token::new("add.1.2", location)));
 }
 
+#[test]
+fn assert_parsing_push_range_invalid() {
+    let source = "begin push.range.5.3 end";
+    let err = ProgramAst::parse(source).err().unwrap();
+    let location = SourceLocation::new(1, 7);
+    assert_eq!(
+        err,
+        ParsingError::invalid_param_with_reason(
+            &Token::new("push.range.5.3", location),
+            3,
+            "range end must not be smaller than range start",
+        )
+    );
+
+    let source = "begin push.range.0.17 end";
+    let err = ProgramAst::parse(source).err().unwrap();
+    assert_eq!(
+        err,
+        ParsingError::invalid_param_with_reason(
+            &Token::new("push.range.0.17", location),
+            3,
+            "range must not contain more than 16 values",
+        )
+    );
+}
+
+#[test]
+fn assert_parsing_push_list_invalid() {
+    let location = SourceLocation::new(1, 7);
+
+    // values must fit into the stated width
+    for (token, value, width) in [
+        ("push.u8.[1,256]", "256", "u8"),
+        ("push.u16.[65536,1]", "65536", "u16"),
+        ("push.u32.[4294967296]", "4294967296", "u32"),
+    ] {
+        let source = format!("begin {token} end");
+        let err = ProgramAst::parse(&source).err().unwrap();
+        assert_eq!(
+            err,
+            ParsingError::invalid_param_with_reason(
+                &Token::new(token, location),
+                2,
+                &format!("value '{value}' does not fit into {width}"),
+            )
+        );
+    }
+
+    // lists must not contain more than 16 values
+    let token = "push.u8.[0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16]";
+    let source = format!("begin {token} end");
+    let err = ProgramAst::parse(&source).err().unwrap();
+    assert_eq!(
+        err,
+        ParsingError::invalid_param_with_reason(
+            &Token::new(token, location),
+            2,
+            "list must not contain more than 16 values",
+        )
+    );
+
+    // lists must be enclosed in square brackets
+    let source = "begin push.u8.[1,2 end";
+    let err = ProgramAst::parse(source).err().unwrap();
+    assert_eq!(
+        err,
+        ParsingError::invalid_param_with_reason(
+            &Token::new("push.u8.[1,2", location),
+            2,
+            "list of values must be enclosed in square brackets",
+        )
+    );
+}
+
 #[test]
 fn assert_parsing_line_invalid_op() {
     let source = "\
@@ -932,6 +1242,143 @@ fn assert_program_output(source: &str, procedures: LocalProcMap, body: Vec<Node>
     }
 }
 
+#[test]
+fn test_ast_program_metrics() {
+    let source = "\
+    use.std::math::u64
+    use.std::sys
+    proc.foo
+        repeat.2
+            exec.u64::wrapping_add
+        end
+    end
+    begin
+        push.1
+        while.true
+            push.0
+            if.true
+                exec.u64::wrapping_add
+            else
+                call.sys::truncate_stack
+            end
+            exec.foo
+        end
+        exec.u64::wrapping_sub
+    end";
+    let metrics = ProgramAst::parse(source).unwrap().metrics();
+
+    assert_eq!(10, metrics.num_nodes);
+    assert_eq!(2, metrics.max_depth);
+    assert_eq!(3, metrics.num_imported_procs);
+
+    // a program without control flow or imports
+    let metrics = ProgramAst::parse("begin push.1 push.2 add end").unwrap().metrics();
+    assert_eq!(3, metrics.num_nodes);
+    assert_eq!(0, metrics.max_depth);
+    assert_eq!(0, metrics.num_imported_procs);
+}
+
+#[test]
+fn test_instruction_opcodes() {
+    let instructions = all_instructions();
+
+    // every instruction must map to a distinct opcode
+    let mut opcodes = BTreeMap::new();
+    for instruction in instructions.iter() {
+        let opcode = instruction_opcode(instruction).expect("instruction has no opcode");
+        if let Some(other) = opcodes.insert(opcode as u8, instruction) {
+            panic!("{instruction:?} and {other:?} map to the same opcode {opcode:?}");
+        }
+    }
+
+    // every opcode, except for the ones for control flow nodes, must be covered by an instruction
+    for value in 0..=u8::MAX {
+        match OpCode::try_from(value) {
+            Ok(OpCode::IfElse | OpCode::Repeat | OpCode::While) => (),
+            Ok(opcode) => assert!(opcodes.contains_key(&value), "{opcode:?} has no instruction"),
+            Err(_) => (),
+        }
+    }
+
+    // serialization must use the opcode of the instruction and round-trip
+    for instruction in instructions {
+        let bytes = instruction.to_bytes();
+        assert_eq!(instruction_opcode(&instruction).map(|opcode| opcode as u8), Some(bytes[0]));
+        assert_eq!(instruction, Instruction::read_from_bytes(&bytes).unwrap());
+    }
+
+    // breakpoints are not serialized
+    assert_eq!(None, instruction_opcode(&Instruction::Breakpoint));
+    assert!(Instruction::Breakpoint.to_bytes().is_empty());
+}
+
+#[test]
+fn test_instruction_deserialization_control_marker() {
+    // control markers are rejected where an instruction is expected
+    for opcode in [OpCode::IfElse, OpCode::Repeat, OpCode::While] {
+        let err = Instruction::read_from_bytes(&[opcode as u8]).unwrap_err();
+        let expected = AstSerdeError::UnexpectedControlMarker(opcode as u8);
+        assert_eq!(DeserializationError::from(expected), err);
+    }
+
+    // at a node boundary, a control marker starts a control flow node
+    let body = CodeBody::new(vec![Node::Instruction(Instruction::Add)]);
+    let node = Node::While { body };
+    let bytes = node.to_bytes();
+    assert_eq!(OpCode::While as u8, bytes[0]);
+    assert_eq!(node, Node::read_from_bytes(&bytes).unwrap());
+}
+
+#[test]
+fn test_opcode_categories() {
+    // a representative opcode from each range
+    assert_eq!(OpCategory::Arithmetic, OpCode::Add.category());
+    assert_eq!(OpCategory::Arithmetic, OpCode::Ext2Inv.category());
+    assert_eq!(OpCategory::Arithmetic, OpCode::AssertzW.category());
+    assert_eq!(OpCategory::Arithmetic, OpCode::Decr.category());
+    assert_eq!(OpCategory::U32, OpCode::U32CheckedAdd.category());
+    assert_eq!(OpCategory::U32, OpCode::U32UncheckedMax.category());
+    assert_eq!(OpCategory::Stack, OpCode::Drop.category());
+    assert_eq!(OpCategory::Stack, OpCode::CDropW.category());
+    assert_eq!(OpCategory::Stack, OpCode::SwapDW.category());
+    assert_eq!(OpCategory::Io, OpCode::PushU8.category());
+    assert_eq!(OpCategory::Io, OpCode::AdvInject.category());
+    assert_eq!(OpCategory::Io, OpCode::LocaddrRange.category());
+    assert_eq!(OpCategory::Io, OpCode::Emit.category());
+    assert_eq!(OpCategory::Crypto, OpCode::Hash.category());
+    assert_eq!(OpCategory::Crypto, OpCode::FriExt2Fold4.category());
+    assert_eq!(OpCategory::ControlFlow, OpCode::ExecLocal.category());
+    assert_eq!(OpCategory::ControlFlow, OpCode::SysCall.category());
+    assert_eq!(OpCategory::ControlFlow, OpCode::While.category());
+
+    // every opcode belongs to some category, and every category is used by some opcode
+    let categories = (0..=u8::MAX)
+        .filter_map(|value| OpCode::try_from(value).ok())
+        .map(|opcode| opcode.category())
+        .collect::<Vec<_>>();
+    for category in [
+        OpCategory::Arithmetic,
+        OpCategory::U32,
+        OpCategory::Stack,
+        OpCategory::Io,
+        OpCategory::Crypto,
+        OpCategory::ControlFlow,
+    ] {
+        assert!(categories.contains(&category), "{category:?} has no opcodes");
+    }
+
+    // control flow nodes are followed by code blocks rather than immediate values
+    for opcode in [OpCode::IfElse, OpCode::Repeat, OpCode::While] {
+        assert!(!opcode.is_immediate());
+    }
+
+    // an opcode carries an immediate value iff its serialized instruction has more than 1 byte
+    for instruction in all_instructions() {
+        let opcode = instruction_opcode(&instruction).unwrap();
+        assert_eq!(opcode.is_immediate(), instruction.to_bytes().len() > 1, "{opcode:?}");
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -1040,3 +1487,253 @@ fn assert_correct_module_serialization(source: &str, serialize_imports: bool) {
     }
     assert_eq!(module, module_deserialized);
 }
+
+/// Returns an instance of every serializable [Instruction] variant.
+fn all_instructions() -> Vec<Instruction> {
+    vec![
+        Instruction::Assert,
+        Instruction::AssertEq,
+        Instruction::AssertEqw,
+        Instruction::Assertz,
+        Instruction::AssertzW,
+        Instruction::Add,
+        Instruction::AddImm(Felt::new(7)),
+        Instruction::Sub,
+        Instruction::SubImm(Felt::new(7)),
+        Instruction::Mul,
+        Instruction::MulImm(Felt::new(7)),
+        Instruction::Div,
+        Instruction::DivImm(Felt::new(7)),
+        Instruction::Neg,
+        Instruction::Inv,
+        Instruction::Incr,
+        Instruction::Decr,
+        Instruction::Pow2,
+        Instruction::Exp,
+        Instruction::ExpImm(Felt::new(7)),
+        Instruction::ExpBitLength(3),
+        Instruction::Not,
+        Instruction::And,
+        Instruction::Or,
+        Instruction::Xor,
+        Instruction::Eq,
+        Instruction::EqImm(Felt::new(7)),
+        Instruction::Neq,
+        Instruction::NeqImm(Felt::new(7)),
+        Instruction::Eqw,
+        Instruction::Lt,
+        Instruction::Lte,
+        Instruction::Gt,
+        Instruction::Gte,
+        Instruction::IsOdd,
+        Instruction::Ext2Add,
+        Instruction::Ext2Sub,
+        Instruction::Ext2Mul,
+        Instruction::Ext2Div,
+        Instruction::Ext2Neg,
+        Instruction::Ext2Inv,
+        Instruction::U32Test,
+        Instruction::U32TestW,
+        Instruction::U32Assert,
+        Instruction::U32Assert2,
+        Instruction::U32AssertW,
+        Instruction::U32Split,
+        Instruction::U32Cast,
+        Instruction::U32CheckedAdd,
+        Instruction::U32CheckedAddImm(11),
+        Instruction::U32WrappingAdd,
+        Instruction::U32WrappingAddImm(11),
+        Instruction::U32OverflowingAdd,
+        Instruction::U32OverflowingAddImm(11),
+        Instruction::U32OverflowingAdd3,
+        Instruction::U32WrappingAdd3,
+        Instruction::U32CheckedSub,
+        Instruction::U32CheckedSubImm(11),
+        Instruction::U32WrappingSub,
+        Instruction::U32WrappingSubImm(11),
+        Instruction::U32OverflowingSub,
+        Instruction::U32OverflowingSubImm(11),
+        Instruction::U32CheckedMul,
+        Instruction::U32CheckedMulImm(11),
+        Instruction::U32WrappingMul,
+        Instruction::U32WrappingMulImm(11),
+        Instruction::U32OverflowingMul,
+        Instruction::U32OverflowingMulImm(11),
+        Instruction::U32OverflowingMadd,
+        Instruction::U32WrappingMadd,
+        Instruction::U32CheckedDiv,
+        Instruction::U32CheckedDivImm(11),
+        Instruction::U32UncheckedDiv,
+        Instruction::U32UncheckedDivImm(11),
+        Instruction::U32CheckedMod,
+        Instruction::U32CheckedModImm(11),
+        Instruction::U32UncheckedMod,
+        Instruction::U32UncheckedModImm(11),
+        Instruction::U32CheckedDivMod,
+        Instruction::U32CheckedDivModImm(11),
+        Instruction::U32UncheckedDivMod,
+        Instruction::U32UncheckedDivModImm(11),
+        Instruction::U32CheckedAnd,
+        Instruction::U32CheckedOr,
+        Instruction::U32CheckedXor,
+        Instruction::U32CheckedNot,
+        Instruction::U32CheckedShr,
+        Instruction::U32CheckedShrImm(3),
+        Instruction::U32UncheckedShr,
+        Instruction::U32UncheckedShrImm(3),
+        Instruction::U32CheckedShl,
+        Instruction::U32CheckedShlImm(3),
+        Instruction::U32UncheckedShl,
+        Instruction::U32UncheckedShlImm(3),
+        Instruction::U32CheckedRotr,
+        Instruction::U32CheckedRotrImm(3),
+        Instruction::U32UncheckedRotr,
+        Instruction::U32UncheckedRotrImm(3),
+        Instruction::U32CheckedRotl,
+        Instruction::U32CheckedRotlImm(3),
+        Instruction::U32UncheckedRotl,
+        Instruction::U32UncheckedRotlImm(3),
+        Instruction::U32CheckedPopcnt,
+        Instruction::U32UncheckedPopcnt,
+        Instruction::U32CheckedEq,
+        Instruction::U32CheckedEqImm(11),
+        Instruction::U32CheckedNeq,
+        Instruction::U32CheckedNeqImm(11),
+        Instruction::U32CheckedLt,
+        Instruction::U32UncheckedLt,
+        Instruction::U32CheckedLte,
+        Instruction::U32UncheckedLte,
+        Instruction::U32CheckedGt,
+        Instruction::U32UncheckedGt,
+        Instruction::U32CheckedGte,
+        Instruction::U32UncheckedGte,
+        Instruction::U32CheckedMin,
+        Instruction::U32UncheckedMin,
+        Instruction::U32CheckedMax,
+        Instruction::U32UncheckedMax,
+        Instruction::Drop,
+        Instruction::DropW,
+        Instruction::PadW,
+        Instruction::Dup0,
+        Instruction::Dup1,
+        Instruction::Dup2,
+        Instruction::Dup3,
+        Instruction::Dup4,
+        Instruction::Dup5,
+        Instruction::Dup6,
+        Instruction::Dup7,
+        Instruction::Dup8,
+        Instruction::Dup9,
+        Instruction::Dup10,
+        Instruction::Dup11,
+        Instruction::Dup12,
+        Instruction::Dup13,
+        Instruction::Dup14,
+        Instruction::Dup15,
+        Instruction::DupW0,
+        Instruction::DupW1,
+        Instruction::DupW2,
+        Instruction::DupW3,
+        Instruction::Swap1,
+        Instruction::Swap2,
+        Instruction::Swap3,
+        Instruction::Swap4,
+        Instruction::Swap5,
+        Instruction::Swap6,
+        Instruction::Swap7,
+        Instruction::Swap8,
+        Instruction::Swap9,
+        Instruction::Swap10,
+        Instruction::Swap11,
+        Instruction::Swap12,
+        Instruction::Swap13,
+        Instruction::Swap14,
+        Instruction::Swap15,
+        Instruction::SwapW1,
+        Instruction::SwapW2,
+        Instruction::SwapW3,
+        Instruction::SwapDw,
+        Instruction::MovUp2,
+        Instruction::MovUp3,
+        Instruction::MovUp4,
+        Instruction::MovUp5,
+        Instruction::MovUp6,
+        Instruction::MovUp7,
+        Instruction::MovUp8,
+        Instruction::MovUp9,
+        Instruction::MovUp10,
+        Instruction::MovUp11,
+        Instruction::MovUp12,
+        Instruction::MovUp13,
+        Instruction::MovUp14,
+        Instruction::MovUp15,
+        Instruction::MovUpW2,
+        Instruction::MovUpW3,
+        Instruction::MovDn2,
+        Instruction::MovDn3,
+        Instruction::MovDn4,
+        Instruction::MovDn5,
+        Instruction::MovDn6,
+        Instruction::MovDn7,
+        Instruction::MovDn8,
+        Instruction::MovDn9,
+        Instruction::MovDn10,
+        Instruction::MovDn11,
+        Instruction::MovDn12,
+        Instruction::MovDn13,
+        Instruction::MovDn14,
+        Instruction::MovDn15,
+        Instruction::MovDnW2,
+        Instruction::MovDnW3,
+        Instruction::CSwap,
+        Instruction::CSwapW,
+        Instruction::CDrop,
+        Instruction::CDropW,
+        Instruction::PushU8(3),
+        Instruction::PushU16(5),
+        Instruction::PushU32(11),
+        Instruction::PushFelt(Felt::new(7)),
+        Instruction::PushWord([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+        Instruction::PushU8List(vec![1, 2]),
+        Instruction::PushU16List(vec![1000, 2000]),
+        Instruction::PushU32List(vec![100000, 200000]),
+        Instruction::PushFeltList(vec![Felt::new(1), Felt::new(u64::MAX - 1000)]),
+        Instruction::Locaddr(5),
+        Instruction::LocaddrRange(2, 3),
+        Instruction::Sdepth,
+        Instruction::Caller,
+        Instruction::Clk,
+        Instruction::MemLoad,
+        Instruction::MemLoadImm(11),
+        Instruction::MemLoadW,
+        Instruction::MemLoadWImm(11),
+        Instruction::LocLoad(5),
+        Instruction::LocLoadW(5),
+        Instruction::MemStore,
+        Instruction::MemStoreImm(11),
+        Instruction::LocStore(5),
+        Instruction::MemStoreW,
+        Instruction::MemStoreWImm(11),
+        Instruction::LocStoreW(5),
+        Instruction::MemStream,
+        Instruction::AdvPipe,
+        Instruction::AdvPush(3),
+        Instruction::AdvLoadW,
+        Instruction::AdvInject(AdviceInjectorNode::PushU64div),
+        Instruction::Hash,
+        Instruction::HMerge,
+        Instruction::HPerm,
+        Instruction::MTreeGet,
+        Instruction::MTreeSet,
+        Instruction::MTreeMerge,
+        Instruction::MTreeVerify,
+        Instruction::FriExt2Fold4,
+        Instruction::ExecLocal(5),
+        Instruction::ExecImported(ProcedureId::new("std::math::u64::add")),
+        Instruction::CallLocal(5),
+        Instruction::CallMastRoot(RpoDigest::default()),
+        Instruction::CallImported(ProcedureId::new("std::math::u64::add")),
+        Instruction::SysCall(ProcedureId::new("std::math::u64::add")),
+        Instruction::Emit(11),
+    ]
+}
@@ -1,6 +1,6 @@
 use super::{
-    crypto::hash::RpoDigest, tokens::SourceLocation, LibraryNamespace, ProcedureId, String,
-    ToString, Token, Vec,
+    crypto::hash::RpoDigest, tokens::SourceLocation, DeserializationError, LibraryNamespace,
+    ProcedureId, String, ToString, Token, Vec,
 };
 use core::fmt;
 use vm_core::utils::write_hex_bytes;
@@ -350,6 +350,22 @@ impl ParsingError {
         }
     }
 
+    pub fn break_outside_loop(token: &Token) -> Self {
+        ParsingError {
+            message: "break outside of a while or repeat loop".to_string(),
+            location: *token.location(),
+            op: token.to_string(),
+        }
+    }
+
+    pub fn dangling_ops_after_break(token: &Token) -> Self {
+        ParsingError {
+            message: "unreachable instructions after break".to_string(),
+            location: *token.location(),
+            op: token.to_string(),
+        }
+    }
+
     pub fn dangling_ops_after_program(token: &Token) -> Self {
         ParsingError {
             message: "dangling instructions after program end".to_string(),
@@ -979,3 +995,46 @@ impl fmt::Display for PathError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for PathError {}
+
+// AST SERDE ERROR
+// ================================================================================================
+
+/// An error which can be generated while deserializing an AST from bytes.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum AstSerdeError {
+    DeserializationError(DeserializationError),
+    UnexpectedControlMarker(u8),
+    UnsupportedVersion { found: u8, expected: u8 },
+}
+
+impl From<DeserializationError> for AstSerdeError {
+    fn from(err: DeserializationError) -> Self {
+        Self::DeserializationError(err)
+    }
+}
+
+impl From<AstSerdeError> for DeserializationError {
+    fn from(err: AstSerdeError) -> Self {
+        match err {
+            AstSerdeError::DeserializationError(err) => err,
+            err => Self::InvalidValue(err.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for AstSerdeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DeserializationError(err) => write!(f, "{err}"),
+            Self::UnexpectedControlMarker(marker) => {
+                write!(f, "unexpected control marker {marker:#04x} in place of an instruction")
+            }
+            Self::UnsupportedVersion { found, expected } => {
+                write!(f, "unsupported serialization version {found}, expected {expected}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AstSerdeError {}
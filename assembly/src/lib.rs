@@ -29,7 +29,7 @@ mod tokens;
 use tokens::{Token, TokenStream};
 
 mod errors;
-pub use errors::{AssemblyError, LabelError, LibraryError, ParsingError, PathError};
+pub use errors::{AssemblyError, AstSerdeError, LabelError, LibraryError, ParsingError, PathError};
 
 mod assembler;
 pub use assembler::{Assembler, AssemblyContext, AssemblyContextType};
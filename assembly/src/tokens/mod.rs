@@ -42,6 +42,7 @@ impl<'a> Token<'a> {
 
     // CONTROL FLOW TOKENS
     // --------------------------------------------------------------------------------------------
+    pub const BREAK: &'static str = "break";
     pub const CALL: &'static str = "call";
     pub const ELSE: &'static str = "else";
     pub const EXEC: &'static str = "exec";
@@ -194,6 +195,15 @@ impl<'a> Token<'a> {
         }
     }
 
+    pub fn validate_break(&self) -> Result<(), ParsingError> {
+        assert_eq!(Self::BREAK, self.parts[0], "not a break");
+        if self.num_parts() > 1 {
+            Err(ParsingError::extra_param(self))
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn validate_else(&self) -> Result<(), ParsingError> {
         assert_eq!(Self::ELSE, self.parts[0], "not an else");
         if self.num_parts() > 1 {
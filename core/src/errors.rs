@@ -7,6 +7,8 @@ use core::fmt;
 pub enum InputError {
     NotFieldElement(u64, &'static str),
     DuplicateAdviceRoot([u8; 32]),
+    InputLengthExceeded(usize, usize),
+    InvalidAdviceMapEntry([u8; 32]),
 }
 
 impl fmt::Display for InputError {
@@ -19,6 +21,12 @@ impl fmt::Display for InputError {
             DuplicateAdviceRoot(key) => {
                 write!(f, "{key:02x?} is a duplicate of the current merkle set")
             }
+            InputLengthExceeded(limit, provided) => {
+                write!(f, "number of input values can not exceed {limit}, but {provided} was provided")
+            }
+            InvalidAdviceMapEntry(key) => {
+                write!(f, "advice map entry {key:02x?} is out of order or its values are out of bounds")
+            }
         }
     }
 }
@@ -31,7 +31,7 @@ pub mod crypto {
 }
 
 pub use math::{
-    fields::{f64::BaseElement as Felt, QuadExtension},
+    fields::{f64::BaseElement as Felt, CubeExtension, QuadExtension},
     polynom, ExtensionOf, FieldElement, StarkField, ToElements,
 };
 
@@ -40,7 +40,7 @@ pub use program::{blocks as code_blocks, CodeBlockTable, Kernel, Program, Progra
 
 mod operations;
 pub use operations::{
-    AdviceInjector, AssemblyOp, Decorator, DecoratorIterator, DecoratorList, Operation,
+    AdviceInjector, AssemblyOp, Decorator, DecoratorIterator, DecoratorList, OpCode, Operation,
 };
 
 pub mod stack;
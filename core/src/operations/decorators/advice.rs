@@ -128,6 +128,22 @@ pub enum AdviceInjector {
     ///   degree coefficients are located at the top of the advice stack.
     Ext2Intt,
 
+    /// Given an element of the prime field of order 2^255 - 19 on the top of the stack, computes
+    /// its multiplicative inverse and pushes the result onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [b7, b6, b5, b4, b3, b2, b1, b0, ...]
+    ///
+    /// Where (a0, ..., a7) and (b0, ..., b7) are the 32-bit limbs of the input element and of its
+    /// inverse respectively, with a0 and b0 representing the 32 least significant bits. The input
+    /// element does not need to be fully reduced, i.e., it can be any value smaller than 2^256.
+    Curve25519Inv,
+
     /// Pushes values onto the advice stack which are required for successful retrieval of a
     /// value from a Sparse Merkle Tree data structure.
     ///
@@ -201,6 +217,7 @@ impl fmt::Display for AdviceInjector {
             Self::DivU64 => write!(f, "div_u64"),
             Self::Ext2Inv => write!(f, "ext2_inv"),
             Self::Ext2Intt => write!(f, "ext2_intt"),
+            Self::Curve25519Inv => write!(f, "curve25519_inv"),
             Self::SmtGet => write!(f, "smt_get"),
             Self::MemToMap => write!(f, "mem_to_map"),
             Self::HdwordToMap { domain } => write!(f, "hdword_to_map.{domain}"),
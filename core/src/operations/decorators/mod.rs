@@ -17,6 +17,10 @@ pub enum Decorator {
     /// Adds information about the assembly instruction at a particular index
     /// (only applicable in debug mode)
     AsmOp(AssemblyOp),
+    /// Records an event with the specified id so that it can be observed by the host after the
+    /// execution. This has no effect on the state of the VM and does not advance the VM clock;
+    /// the `emit` instruction pairs it with a NOOP operation, and thus, takes one cycle.
+    Event(u32),
 }
 
 impl fmt::Display for Decorator {
@@ -26,6 +30,7 @@ impl fmt::Display for Decorator {
             Self::AsmOp(assembly_op) => {
                 write!(f, "asmOp({}, {})", assembly_op.op(), assembly_op.num_cycles())
             }
+            Self::Event(event_id) => write!(f, "event({event_id})"),
         }
     }
 }
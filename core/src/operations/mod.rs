@@ -1,5 +1,5 @@
-use super::Felt;
-use core::fmt;
+use super::{Felt, ZERO};
+use core::{cmp::Ordering, fmt};
 mod decorators;
 pub use decorators::{AdviceInjector, AssemblyOp, Decorator, DecoratorIterator, DecoratorList};
 
@@ -685,3 +685,60 @@ impl fmt::Display for Operation {
         }
     }
 }
+
+// OPERATION CODES
+// ================================================================================================
+
+/// The opcode of a native VM operation, with any immediate value stripped.
+///
+/// Opcodes are ordered and compared by their binary encoding (see [Operation::op_code()]), which
+/// makes them usable as keys in ordered collections such as per-operation cycle counts.
+#[derive(Copy, Clone, Debug)]
+pub struct OpCode(Operation);
+
+impl OpCode {
+    /// Returns the binary encoding of this opcode.
+    pub const fn as_u8(&self) -> u8 {
+        self.0.op_code()
+    }
+}
+
+impl From<Operation> for OpCode {
+    fn from(op: Operation) -> Self {
+        match op {
+            Operation::Push(_) => Self(Operation::Push(ZERO)),
+            op => Self(op),
+        }
+    }
+}
+
+impl PartialEq for OpCode {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_u8() == other.as_u8()
+    }
+}
+
+impl Eq for OpCode {}
+
+impl PartialOrd for OpCode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OpCode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_u8().cmp(&other.as_u8())
+    }
+}
+
+impl fmt::Display for OpCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Operation::Push(_) => write!(f, "push"),
+            Operation::Call => write!(f, "call"),
+            Operation::SysCall => write!(f, "syscall"),
+            op => write!(f, "{op}"),
+        }
+    }
+}
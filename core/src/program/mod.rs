@@ -71,6 +71,101 @@ impl Program {
     pub fn cb_table(&self) -> &CodeBlockTable {
         &self.cb_table
     }
+
+    // EXTERNAL COMMITMENT
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a commitment to this program computed using the provided hash function.
+    ///
+    /// The program is serialized canonically as its [ProgramInfo] (i.e., the MAST root of the
+    /// program followed by the roots of the kernel procedures), and the resulting bytes are hashed
+    /// with `hasher`. Since the MAST root commits to the entire program, two programs compiled
+    /// from identical sources have the same external hash.
+    ///
+    /// This is useful when a program needs to be identified by a hash function other than the one
+    /// used by the VM (e.g., to match a commitment stored in an external registry).
+    pub fn external_hash(&self, hasher: &dyn Fn(&[u8]) -> [u8; 32]) -> [u8; 32] {
+        let program_info = ProgramInfo::new(self.hash(), self.kernel.clone());
+        hasher(&program_info.to_bytes())
+    }
+
+    // SEMANTIC EQUALITY
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if this program is semantically equal to the other program.
+    ///
+    /// Unlike comparing program hashes, this ignores `NOOP` operations which may be inserted into
+    /// span blocks to satisfy operation alignment rules (e.g., to make sure that a `PUSH`
+    /// operation is not the last operation in an operation group). Decorators are ignored as
+    /// well since they do not affect program execution.
+    ///
+    /// Two programs are considered semantically equal if they have the same kernel, and their
+    /// MASTs have the same structure with span blocks containing the same non-`NOOP` operations.
+    /// Code blocks referenced by `CALL`/`SYSCALL` blocks and proxies are resolved via code block
+    /// tables of the respective programs; if a referenced block cannot be resolved, the
+    /// references are compared by hash.
+    pub fn semantic_eq(&self, other: &Program) -> bool {
+        self.kernel == other.kernel && self.block_semantic_eq(&self.root, other, &other.root)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if `block` from this program is semantically equal to `other_block` from the
+    /// `other` program.
+    fn block_semantic_eq(
+        &self,
+        block: &CodeBlock,
+        other: &Program,
+        other_block: &CodeBlock,
+    ) -> bool {
+        match (block, other_block) {
+            (CodeBlock::Span(lhs), CodeBlock::Span(rhs)) => {
+                let lhs_ops = lhs.op_batches().iter().flat_map(|batch| batch.ops());
+                let rhs_ops = rhs.op_batches().iter().flat_map(|batch| batch.ops());
+                lhs_ops
+                    .filter(|&&op| op != Operation::Noop)
+                    .eq(rhs_ops.filter(|&&op| op != Operation::Noop))
+            }
+            (CodeBlock::Join(lhs), CodeBlock::Join(rhs)) => {
+                self.block_semantic_eq(lhs.first(), other, rhs.first())
+                    && self.block_semantic_eq(lhs.second(), other, rhs.second())
+            }
+            (CodeBlock::Split(lhs), CodeBlock::Split(rhs)) => {
+                self.block_semantic_eq(lhs.on_true(), other, rhs.on_true())
+                    && self.block_semantic_eq(lhs.on_false(), other, rhs.on_false())
+            }
+            (CodeBlock::Loop(lhs), CodeBlock::Loop(rhs)) => {
+                self.block_semantic_eq(lhs.body(), other, rhs.body())
+            }
+            (CodeBlock::Call(lhs), CodeBlock::Call(rhs)) => {
+                lhs.is_syscall() == rhs.is_syscall()
+                    && self.referenced_block_semantic_eq(lhs.fn_hash(), other, rhs.fn_hash())
+            }
+            (CodeBlock::Proxy(lhs), CodeBlock::Proxy(rhs)) => {
+                self.referenced_block_semantic_eq(lhs.hash(), other, rhs.hash())
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns true if a code block with the specified hash referenced from this program is
+    /// semantically equal to a code block with the specified hash referenced from the `other`
+    /// program.
+    fn referenced_block_semantic_eq(
+        &self,
+        hash: Digest,
+        other: &Program,
+        other_hash: Digest,
+    ) -> bool {
+        if hash == other_hash {
+            return true;
+        }
+        match (self.cb_table.get(hash), other.cb_table.get(other_hash)) {
+            (Some(lhs), Some(rhs)) => self.block_semantic_eq(lhs, other, rhs),
+            _ => false,
+        }
+    }
 }
 
 impl fmt::Display for Program {
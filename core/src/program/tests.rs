@@ -1,4 +1,7 @@
-use super::{Deserializable, Digest, Felt, Kernel, ProgramInfo, Serializable};
+use super::{
+    blocks::CodeBlock, CodeBlockTable, Deserializable, Digest, Felt, Kernel, Operation, Program,
+    ProgramInfo, Serializable,
+};
 use crate::Word;
 use proptest::prelude::*;
 use rand_utils::prng_array;
@@ -24,9 +27,87 @@ proptest! {
     }
 }
 
+// SEMANTIC EQUALITY
+// --------------------------------------------------------------------------------------------
+
+#[test]
+fn semantic_eq_ignores_push_padding() {
+    // a PUSH operation cannot be the last operation in a group; padding the first group with a
+    // NOOP moves the PUSH into the second group, which changes the hash of the span block
+    let ops = vec![Operation::Pad; 6];
+    let program1 = Program::new(span_with_push(ops.clone()));
+    let mut padded_ops = ops;
+    padded_ops.push(Operation::Noop);
+    let program2 = Program::new(span_with_push(padded_ops));
+
+    assert_ne!(program1.hash(), program2.hash());
+    assert!(program1.semantic_eq(&program2));
+    assert!(program2.semantic_eq(&program1));
+
+    // padding inside nested blocks is ignored as well
+    let join1 =
+        CodeBlock::new_join([program1.root().clone(), CodeBlock::new_span(vec![Operation::Add])]);
+    let join2 = CodeBlock::new_join([
+        program2.root().clone(),
+        CodeBlock::new_span(vec![Operation::Noop, Operation::Add]),
+    ]);
+    let program1 = Program::new(CodeBlock::new_loop(join1));
+    let program2 = Program::new(CodeBlock::new_loop(join2));
+
+    assert_ne!(program1.hash(), program2.hash());
+    assert!(program1.semantic_eq(&program2));
+}
+
+#[test]
+fn semantic_eq_resolves_called_blocks() {
+    let callee1 = CodeBlock::new_span(vec![Operation::Push(Felt::new(3)), Operation::Mul]);
+    let callee2 =
+        CodeBlock::new_span(vec![Operation::Noop, Operation::Push(Felt::new(3)), Operation::Mul]);
+
+    let mut cb_table1 = CodeBlockTable::default();
+    cb_table1.insert(callee1.clone());
+    let mut cb_table2 = CodeBlockTable::default();
+    cb_table2.insert(callee2.clone());
+
+    let root1 = CodeBlock::new_call(callee1.hash());
+    let root2 = CodeBlock::new_call(callee2.hash());
+    let program1 = Program::with_kernel(root1.clone(), Kernel::default(), cb_table1);
+    let program2 = Program::with_kernel(root2.clone(), Kernel::default(), cb_table2);
+
+    assert_ne!(program1.hash(), program2.hash());
+    assert!(program1.semantic_eq(&program2));
+
+    // without the code block table the callees cannot be resolved
+    assert!(!Program::new(root1).semantic_eq(&Program::new(root2)));
+}
+
+#[test]
+fn semantic_eq_detects_differences() {
+    let program1 = Program::new(CodeBlock::new_span(vec![Operation::Push(Felt::new(1))]));
+    let program2 = Program::new(CodeBlock::new_span(vec![Operation::Push(Felt::new(2))]));
+    assert!(!program1.semantic_eq(&program2));
+
+    let span = CodeBlock::new_span(vec![Operation::Add]);
+    let program1 = Program::new(CodeBlock::new_loop(span.clone()));
+    let program2 = Program::new(span.clone());
+    assert!(!program1.semantic_eq(&program2));
+
+    // programs with different kernels are not equal
+    let kernel = Kernel::new(&[span.hash()]);
+    let program1 = Program::new(span.clone());
+    let program2 = Program::with_kernel(span, kernel, CodeBlockTable::default());
+    assert!(!program1.semantic_eq(&program2));
+}
+
 // HELPER FUNCTIONS
 // --------------------------------------------------------------------------------------------
 
+fn span_with_push(mut ops: Vec<Operation>) -> CodeBlock {
+    ops.push(Operation::Push(Felt::new(7)));
+    ops.push(Operation::Drop);
+    CodeBlock::new_span(ops)
+}
+
 fn digest_from_seed(seed: [u8; 32]) -> Digest {
     let mut digest = Word::default();
     digest.iter_mut().enumerate().for_each(|(i, d)| {
@@ -7,6 +7,9 @@ use math::StarkField;
 
 pub use winter_crypto::{DefaultRandomCoin as WinterRandomCoin, RandomCoin, RandomCoinError};
 
+#[cfg(feature = "std")]
+use std::cell::RefCell;
+
 // CONSTANTS
 // ================================================================================================
 
@@ -159,3 +162,165 @@ impl RandomCoin for RpoRandomCoin {
         Ok(values)
     }
 }
+
+// TRANSCRIPT RANDOM COIN
+// ================================================================================================
+
+/// A challenge drawn from a public coin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Challenge {
+    /// A field element, represented by its base field elements.
+    Element(Vec<Felt>),
+    /// A set of integers drawn from a domain (e.g., query positions).
+    Integers(Vec<usize>),
+}
+
+/// A wrapper around a [RandomCoin] which records all challenges drawn from the wrapped coin, in
+/// the order in which they were drawn.
+pub struct TranscriptRandomCoin<R> {
+    coin: R,
+    challenges: Vec<Challenge>,
+}
+
+impl<R> TranscriptRandomCoin<R> {
+    /// Returns the challenges drawn from this coin so far.
+    pub fn challenges(&self) -> &[Challenge] {
+        &self.challenges
+    }
+
+    /// Consumes this coin and returns the challenges drawn from it.
+    pub fn into_challenges(self) -> Vec<Challenge> {
+        self.challenges
+    }
+}
+
+impl<R> RandomCoin for TranscriptRandomCoin<R>
+where
+    R: RandomCoin<BaseField = Felt>,
+{
+    type BaseField = Felt;
+    type Hasher = R::Hasher;
+
+    fn new(seed: &[Self::BaseField]) -> Self {
+        Self {
+            coin: R::new(seed),
+            challenges: Vec::new(),
+        }
+    }
+
+    fn reseed(&mut self, data: <Self::Hasher as winter_crypto::Hasher>::Digest) {
+        self.coin.reseed(data)
+    }
+
+    fn reseed_with_int(&mut self, value: u64) {
+        self.coin.reseed_with_int(value)
+    }
+
+    fn leading_zeros(&self) -> u32 {
+        self.coin.leading_zeros()
+    }
+
+    fn check_leading_zeros(&self, value: u64) -> u32 {
+        self.coin.check_leading_zeros(value)
+    }
+
+    fn draw<E: FieldElement<BaseField = Felt>>(&mut self) -> Result<E, RandomCoinError> {
+        let value = self.coin.draw::<E>()?;
+        let challenge = Challenge::Element(E::slice_as_base_elements(&[value]).to_vec());
+        self.challenges.push(challenge);
+        Ok(value)
+    }
+
+    fn draw_integers(
+        &mut self,
+        num_values: usize,
+        domain_size: usize,
+    ) -> Result<Vec<usize>, RandomCoinError> {
+        let values = self.coin.draw_integers(num_values, domain_size)?;
+        self.challenges.push(Challenge::Integers(values.clone()));
+        Ok(values)
+    }
+}
+
+// RECORDING RANDOM COIN
+// ================================================================================================
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static RECORDED_CHALLENGES: RefCell<Option<Vec<Challenge>>> = RefCell::new(None);
+}
+
+/// A wrapper around a [RandomCoin] which records all challenges drawn from the wrapped coin while
+/// [record_challenges()] is running on the current thread.
+///
+/// Unlike [TranscriptRandomCoin], this coin can be used by the STARK prover, which instantiates its
+/// public coin internally and does not expose it once the proof has been generated.
+#[cfg(feature = "std")]
+pub struct RecordingRandomCoin<R>(R);
+
+#[cfg(feature = "std")]
+impl<R> RecordingRandomCoin<R> {
+    fn record(challenge: Challenge) {
+        RECORDED_CHALLENGES.with(|challenges| {
+            if let Some(challenges) = challenges.borrow_mut().as_mut() {
+                challenges.push(challenge);
+            }
+        });
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R> RandomCoin for RecordingRandomCoin<R>
+where
+    R: RandomCoin<BaseField = Felt>,
+{
+    type BaseField = Felt;
+    type Hasher = R::Hasher;
+
+    fn new(seed: &[Self::BaseField]) -> Self {
+        Self(R::new(seed))
+    }
+
+    fn reseed(&mut self, data: <Self::Hasher as winter_crypto::Hasher>::Digest) {
+        self.0.reseed(data)
+    }
+
+    fn reseed_with_int(&mut self, value: u64) {
+        self.0.reseed_with_int(value)
+    }
+
+    fn leading_zeros(&self) -> u32 {
+        self.0.leading_zeros()
+    }
+
+    fn check_leading_zeros(&self, value: u64) -> u32 {
+        self.0.check_leading_zeros(value)
+    }
+
+    fn draw<E: FieldElement<BaseField = Felt>>(&mut self) -> Result<E, RandomCoinError> {
+        let value = self.0.draw::<E>()?;
+        Self::record(Challenge::Element(E::slice_as_base_elements(&[value]).to_vec()));
+        Ok(value)
+    }
+
+    fn draw_integers(
+        &mut self,
+        num_values: usize,
+        domain_size: usize,
+    ) -> Result<Vec<usize>, RandomCoinError> {
+        let values = self.0.draw_integers(num_values, domain_size)?;
+        Self::record(Challenge::Integers(values.clone()));
+        Ok(values)
+    }
+}
+
+/// Executes the provided function and returns its result together with the challenges drawn from
+/// all [RecordingRandomCoin]s on the current thread during its execution, in the order in which
+/// they were drawn.
+#[cfg(feature = "std")]
+pub fn record_challenges<T>(f: impl FnOnce() -> T) -> (T, Vec<Challenge>) {
+    let outer = RECORDED_CHALLENGES.with(|challenges| challenges.replace(Some(Vec::new())));
+    let result = f();
+    let challenges = RECORDED_CHALLENGES.with(|challenges| challenges.replace(outer));
+    (result, challenges.unwrap_or_default())
+}
@@ -1,4 +1,6 @@
-use super::{vec, ByteWriter, Felt, InputError, Serializable, ToElements, Vec};
+use super::{
+    vec, ByteWriter, Felt, InputError, Serializable, StarkField, ToElements, Vec, MAX_STACK_INPUTS,
+};
 use core::slice;
 
 // STACK INPUTS
@@ -18,25 +20,83 @@ impl StackInputs {
     // --------------------------------------------------------------------------------------------
 
     /// Returns `[StackInputs]` from a list of values, reversing them into a stack.
-    pub fn new(mut values: Vec<Felt>) -> Self {
+    ///
+    /// # Errors
+    /// Returns an error if the number of provided values is greater than [MAX_STACK_INPUTS].
+    pub fn new(mut values: Vec<Felt>) -> Result<Self, InputError> {
+        if values.len() > MAX_STACK_INPUTS {
+            return Err(InputError::InputLengthExceeded(MAX_STACK_INPUTS, values.len()));
+        }
+
         values.reverse();
-        Self { values }
+        Ok(Self { values })
     }
 
     /// Attempts to create stack inputs from an iterator of numbers, failing if they do not
-    /// represent a valid field element.
+    /// represent a valid field element or if there are more than [MAX_STACK_INPUTS] of them.
     pub fn try_from_values<I>(iter: I) -> Result<Self, InputError>
     where
         I: IntoIterator<Item = u64>,
     {
-        iter.into_iter()
-            .map(|v| {
-                Felt::try_from(v).map_err(|_| {
-                    InputError::NotFieldElement(v, "the provided value isn't a valid field element")
-                })
+        let values = iter.into_iter().collect::<Vec<_>>();
+        if let Some(&idx) = Self::non_canonical_values(values.iter().copied()).first() {
+            return Err(InputError::NotFieldElement(
+                values[idx],
+                "the provided value isn't a valid field element",
+            ));
+        }
+        Self::new(values.into_iter().map(Felt::new).collect())
+    }
+
+    /// Attempts to create stack inputs from values listed starting with the value which should be
+    /// on top of the stack.
+    ///
+    /// That is, `values[0]` ends up on top of the stack, `values[1]` right below it, and so on.
+    ///
+    /// # Errors
+    /// Returns an error if any of the values is not a valid field element, or if there are more
+    /// than [MAX_STACK_INPUTS] values.
+    pub fn with_stack_top_first(mut values: Vec<u64>) -> Result<Self, InputError> {
+        values.reverse();
+        Self::with_stack_bottom_first(values)
+    }
+
+    /// Attempts to create stack inputs from values listed starting with the value which should be
+    /// deepest in the stack.
+    ///
+    /// That is, the last value ends up on top of the stack, as if the values were pushed onto the
+    /// stack one by one. This is the ordering expected by [StackInputs::try_from_values()].
+    ///
+    /// # Errors
+    /// Returns an error if any of the values is not a valid field element, or if there are more
+    /// than [MAX_STACK_INPUTS] values.
+    pub fn with_stack_bottom_first(values: Vec<u64>) -> Result<Self, InputError> {
+        Self::try_from_values(values)
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns indexes of the provided raw values which are not canonical field elements, i.e.,
+    /// values which are greater than or equal to the field modulus.
+    ///
+    /// Converting such values into field elements (e.g., via `Felt::new()` or `Felt::from()`)
+    /// silently reduces them modulo the field modulus. This can be used to detect such reductions
+    /// before the values are used to instantiate stack inputs. Indexes refer to positions in the
+    /// provided list (i.e., prior to reversing the values into a stack).
+    pub fn non_canonical_values<I, T>(values: I) -> Vec<usize>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<u128>,
+    {
+        values
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, value)| {
+                let value: u128 = value.into();
+                (value >= Felt::MODULUS as u128).then_some(idx)
             })
-            .collect::<Result<Vec<_>, _>>()
-            .map(Self::new)
+            .collect()
     }
 
     // PUBLIC ACCESSORS
@@ -83,3 +143,63 @@ impl ToElements<Felt> for StackInputs {
         self.values.to_vec()
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Felt, InputError, StackInputs, StarkField, Vec, MAX_STACK_INPUTS};
+
+    #[test]
+    fn stack_inputs_within_limits() {
+        let inputs = StackInputs::try_from_values([1, 2, 3]).unwrap();
+        assert_eq!(&[Felt::new(3), Felt::new(2), Felt::new(1)], inputs.values());
+
+        let values = (0..MAX_STACK_INPUTS as u64).collect::<Vec<_>>();
+        let inputs = StackInputs::try_from_values(values).unwrap();
+        assert_eq!(MAX_STACK_INPUTS, inputs.values().len());
+    }
+
+    #[test]
+    fn stack_inputs_too_long() {
+        let values = vec![Felt::new(1); MAX_STACK_INPUTS + 1];
+        let err = StackInputs::new(values).unwrap_err();
+        assert!(matches!(
+            err,
+            InputError::InputLengthExceeded(MAX_STACK_INPUTS, len) if len == MAX_STACK_INPUTS + 1
+        ));
+
+        let values = (0..=MAX_STACK_INPUTS as u64).collect::<Vec<_>>();
+        assert!(StackInputs::try_from_values(values).is_err());
+    }
+
+    #[test]
+    fn stack_inputs_explicit_ordering() {
+        let top_first = StackInputs::with_stack_top_first(vec![1, 2, 3]).unwrap();
+        assert_eq!(&[Felt::new(1), Felt::new(2), Felt::new(3)], top_first.values());
+
+        let bottom_first = StackInputs::with_stack_bottom_first(vec![3, 2, 1]).unwrap();
+        assert_eq!(top_first.values(), bottom_first.values());
+
+        assert!(StackInputs::with_stack_top_first(vec![Felt::MODULUS]).is_err());
+        assert!(StackInputs::with_stack_bottom_first(vec![1, u64::MAX]).is_err());
+
+        let err = StackInputs::try_from_values([1, u64::MAX, Felt::MODULUS]).unwrap_err();
+        assert!(matches!(err, InputError::NotFieldElement(value, _) if value == u64::MAX));
+    }
+
+    #[test]
+    fn stack_inputs_non_canonical_values() {
+        let values = [0, 1, Felt::MODULUS - 1, u32::MAX as u64];
+        assert!(StackInputs::non_canonical_values(values).is_empty());
+
+        let values: [u128; 4] =
+            [1, Felt::MODULUS as u128, 2, u64::MAX as u128 + Felt::MODULUS as u128];
+        assert_eq!(vec![1, 3], StackInputs::non_canonical_values(values));
+
+        // non-canonical values are silently reduced when converted into field elements
+        assert_eq!(vec![0], StackInputs::non_canonical_values([u64::MAX]));
+        assert_eq!(Felt::new(u64::MAX), Felt::new(u64::MAX - Felt::MODULUS));
+    }
+}
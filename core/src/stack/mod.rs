@@ -16,3 +16,16 @@ pub use outputs::StackOutputs;
 /// The number of stack registers which can be accessed by the VM directly. This is also the
 /// minimum stack depth enforced by the VM.
 pub const STACK_TOP_SIZE: usize = 16;
+
+/// The maximum number of elements which can be placed onto the stack at the start of execution.
+///
+/// Stack inputs are a part of the public inputs of a program, and thus, this also bounds the number
+/// of public inputs which a verifier would need to process.
+pub const MAX_STACK_INPUTS: usize = 1 << 16;
+
+/// The maximum number of elements which can be on the stack at the end of execution for the stack
+/// outputs to be accepted by the verifier.
+///
+/// Similarly to stack inputs, stack outputs are a part of the public inputs of a program, and thus,
+/// this bounds the number of public inputs which a verifier would need to process.
+pub const MAX_STACK_OUTPUTS: usize = 1 << 16;
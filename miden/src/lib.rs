@@ -6,12 +6,22 @@
 
 pub use assembly::{Assembler, AssemblyError, ParsingError};
 pub use processor::{
-    crypto, execute, execute_iter, utils, AdviceInputs, AdviceProvider, AsmOpInfo, ExecutionError,
-    ExecutionTrace, Kernel, MemAdviceProvider, Operation, ProgramInfo, StackInputs, VmState,
-    VmStateIterator,
+    crypto, execute, execute_iter, execute_with_min_trace_len, utils, AdviceDiff, AdviceInputs,
+    AdviceProvider, AsmOpInfo, ExecutionError, ExecutionTrace, Kernel, MemAdviceProvider,
+    Operation, ProgramInfo, StackInputs, VmState, VmStateIterator,
 };
 pub use prover::{
-    math, prove, Digest, ExecutionProof, FieldExtension, HashFunction, InputError, Program,
-    ProofOptions, StackOutputs, StarkProof, Word,
+    execute_with_hashed_inputs, execute_with_mem_commitment, execute_with_mem_commitments,
+    execute_with_min_trace_length, execute_with_progress, math, prove, Digest, ExecutionPhase,
+    ExecutionProof, FieldExtension, HashFunction, InputError, Program, ProofOptions, Prover,
+    StackOutputs, StarkProof, Word,
 };
-pub use verifier::{verify, VerificationError};
+pub use verifier::{
+    air_info, proof_trace_length, verify, verify_hashed, verify_mixed, verify_prefix, AirInfo,
+    VerificationError, VerificationFailure,
+};
+
+#[cfg(feature = "std")]
+pub use prover::execute_with_transcript;
+#[cfg(feature = "std")]
+pub use verifier::verify_with_transcript;
@@ -1,3 +1,4 @@
+use processor::MAX_CONTEXT_DEPTH;
 use test_utils::{build_test, AdviceInputs, StackInputs, Test, TestError};
 
 // SIMPLE FLOW CONTROL TESTS
@@ -118,6 +119,85 @@ fn if_in_loop_in_if() {
     test.expect_stack(&[121]);
 }
 
+#[test]
+fn loop_with_break() {
+    // --- breaking out of a while loop -----------------------------------------------------------
+    // counts down from the value at the top of the stack until either 7 or 0 is reached
+    let source = "
+        begin
+            push.1
+            while.true
+                dup eq.7
+                if.true
+                    break
+                end
+                sub.1 dup neq.0
+            end
+        end";
+
+    let test = build_test!(source, &[10]);
+    test.expect_stack(&[7]);
+
+    let test = build_test!(source, &[5]);
+    test.expect_stack(&[0]);
+
+    // --- breaking out of a repeat loop ----------------------------------------------------------
+    let source = "
+        begin
+            push.0
+            repeat.10
+                add.1
+                dup eq.4
+                if.true
+                    break
+                end
+            end
+        end";
+
+    let test = build_test!(source);
+    test.expect_stack(&[4]);
+
+    // --- breaking out of a long repeat loop -----------------------------------------------------
+    let source = "
+        begin
+            push.0
+            repeat.5000
+                add.1
+                dup eq.4000
+                if.true
+                    break
+                end
+            end
+        end";
+
+    let test = build_test!(source);
+    test.expect_stack(&[4000]);
+}
+
+#[test]
+fn nested_loops_with_break() {
+    // the inner loop increments the value until it is divisible by 5, while the outer loop adds
+    // 100 after each execution of the inner loop; only the inner loop is exited by the break
+    let source = "
+        begin
+            repeat.3
+                push.1
+                while.true
+                    add.1
+                    dup u32checked_mod.5 eq.0
+                    if.true
+                        break
+                    end
+                    push.1
+                end
+                add.100
+            end
+        end";
+
+    let test = build_test!(source, &[0]);
+    test.expect_stack(&[315]);
+}
+
 // FUNCTION CALLS
 // ================================================================================================
 
@@ -183,6 +263,17 @@ fn local_fn_call_with_mem_access() {
     test.prove_and_verify(vec![3, 7], false);
 }
 
+#[test]
+fn nested_fn_calls() {
+    // a chain of calls which is exactly as deep as the maximum context depth succeeds
+    let test = build_test!(&build_nested_calls_source(MAX_CONTEXT_DEPTH), &[1, 2]);
+    test.expect_stack(&[3]);
+
+    // a chain of calls which is one level deeper than the maximum context depth fails
+    let test = build_test!(&build_nested_calls_source(MAX_CONTEXT_DEPTH + 1), &[1, 2]);
+    test.expect_error(TestError::ExecutionError("CallDepthExceeded"));
+}
+
 #[test]
 fn simple_syscall() {
     let kernel_source = "
@@ -209,3 +300,17 @@ fn simple_syscall() {
 
     test.prove_and_verify(vec![1, 2], false);
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the source of a program which adds the two values at the top of the stack in a
+/// procedure reached through `depth` nested calls.
+fn build_nested_calls_source(depth: usize) -> String {
+    let mut source = String::from("proc.f0 add end");
+    for i in 1..depth {
+        source.push_str(&format!(" proc.f{i} call.f{} end", i - 1));
+    }
+    source.push_str(&format!(" begin call.f{} end", depth - 1));
+    source
+}
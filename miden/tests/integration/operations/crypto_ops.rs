@@ -258,3 +258,43 @@ fn mtree_update() {
     let test = build_op_test!(asm_op, &stack_inputs, &[], store.clone());
     test.expect_stack(&final_stack);
 }
+
+#[test]
+fn mtree_set_returns_old_value() {
+    let index = 5usize;
+    let (leaves, store) = init_merkle_store(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let tree = MerkleTree::new(leaves.clone()).unwrap();
+    let new_node = init_merkle_leaf(9);
+
+    let stack_inputs = [
+        new_node[0].as_int(),
+        new_node[1].as_int(),
+        new_node[2].as_int(),
+        new_node[3].as_int(),
+        tree.root()[0].as_int(),
+        tree.root()[1].as_int(),
+        tree.root()[2].as_int(),
+        tree.root()[3].as_int(),
+        index as u64,
+        tree.depth() as u64,
+    ];
+
+    // the old value left on the stack by the first update is written back by the second update
+    // without fetching it via mtree_get; this restores the original tree
+    let asm_op = format!("mtree_set swapw push.{index}.{} mtree_set", tree.depth());
+
+    // the second update returns the value written by the first one
+    let final_stack = [
+        new_node[3].as_int(),
+        new_node[2].as_int(),
+        new_node[1].as_int(),
+        new_node[0].as_int(),
+        tree.root()[3].as_int(),
+        tree.root()[2].as_int(),
+        tree.root()[1].as_int(),
+        tree.root()[0].as_int(),
+    ];
+
+    let test = build_op_test!(&asm_op, &stack_inputs, &[], store);
+    test.expect_stack(&final_stack);
+}
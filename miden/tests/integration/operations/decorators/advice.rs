@@ -1,4 +1,4 @@
-use test_utils::{build_test, crypto::MerkleStore, rand::rand_value, Felt};
+use test_utils::{build_test, crypto::MerkleStore, rand::rand_value, AdviceInputs, Felt};
 
 // ADVICE INJECTION
 // ================================================================================================
@@ -273,6 +273,42 @@ fn advice_push_mapval() {
     test.expect_stack(&[15, 14, 13, 12, 11, 5]);
 }
 
+#[test]
+fn advice_push_mapval_typed_keys() {
+    let values = [Felt::new(8), Felt::new(7), Felt::new(6), Felt::new(5)];
+
+    // --- test a value inserted under a word key -------------------------------------------
+    let source: &str = "begin
+    push.1.2.3.4
+    adv.push_mapval
+    dropw
+    adv_push.4
+    end";
+
+    let mut advice_inputs = AdviceInputs::default();
+    let key = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    advice_inputs.insert_advice_word_key(key, values);
+
+    let mut test = build_test!(source);
+    test.advice_inputs = advice_inputs;
+    test.expect_stack(&[5, 6, 7, 8]);
+
+    // --- test a value inserted under an element key ---------------------------------------
+    let source: &str = "begin
+    push.9.0.0.0
+    adv.push_mapval
+    dropw
+    adv_push.4
+    end";
+
+    let mut advice_inputs = AdviceInputs::default();
+    advice_inputs.insert_advice_felt_key(Felt::new(9), values);
+
+    let mut test = build_test!(source);
+    test.advice_inputs = advice_inputs;
+    test.expect_stack(&[5, 6, 7, 8]);
+}
+
 #[test]
 fn advice_insert_hdword() {
     // --- test hashing without domain ----------------------------------------
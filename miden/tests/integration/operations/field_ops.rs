@@ -44,6 +44,31 @@ fn assert_eq_fail() {
     test.expect_error(TestError::ExecutionError("FailedAssertion"));
 }
 
+#[test]
+fn assertz_w() {
+    let asm_op = "assertz_w";
+
+    let test = build_op_test!(asm_op, &[0, 0, 0, 0]);
+    test.expect_stack(&[]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let test = build_op_test!(asm_op, &[7, 0, 0, 0, 0]);
+    test.expect_stack(&[7]);
+}
+
+#[test]
+fn assertz_w_fail() {
+    let asm_op = "assertz_w";
+
+    // a single non-zero element in any position fails the assertion
+    for i in 0..4 {
+        let mut inputs = [0; 4];
+        inputs[i] = 1;
+        let test = build_op_test!(asm_op, &inputs);
+        test.expect_error(TestError::ExecutionError("FailedAssertion"));
+    }
+}
+
 // FIELD OPS ARITHMETIC - MANUAL TESTS
 // ================================================================================================
 
@@ -137,6 +162,53 @@ fn sub_b() {
     test.expect_stack(&[0, c]);
 }
 
+#[test]
+fn incr() {
+    let asm_op = "incr";
+
+    // --- simple case ----------------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[5]);
+    test.expect_stack(&[6]);
+
+    // --- test overflow --------------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[Felt::MODULUS - 1]);
+    test.expect_stack(&[0]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let c = rand_value::<u64>();
+    let test = build_op_test!(asm_op, &[c, 2]);
+    test.expect_stack(&[3, c]);
+}
+
+#[test]
+fn decr() {
+    let asm_op = "decr";
+
+    // --- simple case ----------------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[5]);
+    test.expect_stack(&[4]);
+
+    // --- test underflow -------------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[0]);
+    test.expect_stack(&[Felt::MODULUS - 1]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let c = rand_value::<u64>();
+    let test = build_op_test!(asm_op, &[c, 2]);
+    test.expect_stack(&[1, c]);
+}
+
+#[test]
+fn incr_decr() {
+    // --- values at both ends of the field are preserved in either order ------------------------
+    for asm_op in ["incr decr", "decr incr"] {
+        for a in [0, 1, Felt::MODULUS - 1, rand_value::<u64>() % Felt::MODULUS] {
+            let test = build_op_test!(asm_op, &[a]);
+            test.expect_stack(&[a]);
+        }
+    }
+}
+
 #[test]
 fn mul() {
     let asm_op = "mul";
@@ -493,6 +565,22 @@ fn xor_fail() {
     test.expect_error(TestError::ExecutionError("NotBinaryValue"));
 }
 
+#[test]
+fn boolean_ops_reject_u32_operands() {
+    // boolean ops fail on non-binary operands which their u32 bitwise counterparts accept
+    for (asm_op, u32_asm_op, expected) in [
+        ("and", "u32checked_and", 2),
+        ("or", "u32checked_or", 3),
+        ("xor", "u32checked_xor", 1),
+    ] {
+        let test = build_op_test!(asm_op, &[2, 3]);
+        test.expect_error(TestError::ExecutionError("NotBinaryValue"));
+
+        let test = build_op_test!(u32_asm_op, &[2, 3]);
+        test.expect_stack(&[expected]);
+    }
+}
+
 // FIELD OPS COMPARISON - MANUAL TESTS
 // ================================================================================================
 
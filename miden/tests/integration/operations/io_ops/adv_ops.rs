@@ -1,4 +1,9 @@
 use super::{build_op_test, build_test, TestError};
+use processor::{execute, ExecutionError, StackInputs};
+use test_utils::{
+    crypto::{init_merkle_store, MerkleTree},
+    AdviceRead, ScriptedAdviceProvider,
+};
 use vm_core::{chiplets::hasher::apply_permutation, utils::ToElements, Felt, StarkField};
 
 // PUSHING VALUES ONTO THE STACK (PUSH)
@@ -112,3 +117,50 @@ fn adv_pipe_with_hperm() {
     let test = build_test!(source, &[], &advice_stack);
     test.expect_stack(&final_stack);
 }
+
+// SCRIPTED ADVICE
+// ================================================================================================
+
+#[test]
+fn adv_reads_are_logged() {
+    let source = "begin adv_push.1 adv_loadw adv_push.2 end";
+    let program = build_test!(source).compile();
+
+    let script = (1..=7).map(Felt::new).collect::<Vec<_>>();
+    let mut provider = ScriptedAdviceProvider::new(script.clone());
+    execute(&program, StackInputs::default(), &mut provider).unwrap();
+
+    let expected = [
+        AdviceRead::Element(script[0]),
+        AdviceRead::Word([script[1], script[2], script[3], script[4]]),
+        AdviceRead::Element(script[5]),
+        AdviceRead::Element(script[6]),
+    ];
+    assert_eq!(&expected, provider.read_log());
+    assert_eq!(0, provider.remaining_script().count());
+}
+
+#[test]
+fn scripted_mtree_set_fails() {
+    let (leaves, store) = init_merkle_store(&[1, 2, 3, 4]);
+    let tree = MerkleTree::new(leaves).unwrap();
+    let program = build_test!("begin mtree_set end").compile();
+
+    // updating a node of a tree known to the provider is rejected instead of panicking
+    let stack_inputs = [
+        9,
+        0,
+        0,
+        0,
+        tree.root()[0].as_int(),
+        tree.root()[1].as_int(),
+        tree.root()[2].as_int(),
+        tree.root()[3].as_int(),
+        1,
+        tree.depth() as u64,
+    ];
+    let stack_inputs = StackInputs::try_from_values(stack_inputs).unwrap();
+    let mut provider = ScriptedAdviceProvider::default().with_merkle_store(store);
+    let result = execute(&program, stack_inputs, &mut provider);
+    assert!(matches!(result, Err(ExecutionError::AdviceProviderReadOnly(_))));
+}
@@ -1,6 +1,7 @@
 use processor::FMP_MIN;
 use test_utils::{
-    build_op_test, build_test, AdviceInputs, StackInputs, StarkField, Test, Word, STACK_TOP_SIZE,
+    build_op_test, build_test, AdviceInputs, StackInputs, StarkField, Test, TestError, Word,
+    STACK_TOP_SIZE,
 };
 use vm_core::{code_blocks::CodeBlock, Operation};
 
@@ -122,6 +123,39 @@ fn locaddr() {
     test.expect_stack(&[7, 6, 5, 4, 3, 2, 1, 10]);
 }
 
+#[test]
+fn locaddr_range() {
+    // --- locaddr.b.n returns the address of the first local in the array ------------------------
+    let source = "
+        proc.foo.4
+            locaddr.1.3
+            locaddr.0.4
+            locaddr.3.1
+        end
+        begin
+            exec.foo
+        end";
+
+    let test = build_test!(source, &[10]);
+    test.expect_stack(&[FMP_MIN + 4, FMP_MIN + 1, FMP_MIN + 2, 10]);
+
+    // --- array extending beyond the declared locals fails to assemble ---------------------------
+    let source = "
+        proc.foo.4
+            locaddr.2.3
+        end
+        begin
+            exec.foo
+        end";
+
+    let test = build_test!(source, &[10]);
+    test.expect_error(TestError::AssemblyError("ParamOutOfBounds"));
+
+    // --- zero-length array is rejected ----------------------------------------------------------
+    let test = build_test!("proc.foo.4 locaddr.1.0 end begin exec.foo end", &[10]);
+    test.expect_error(TestError::AssemblyError("greater than zero"));
+}
+
 // CALLER INSTRUCTION
 // ================================================================================================
 
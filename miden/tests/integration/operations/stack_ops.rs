@@ -1,4 +1,6 @@
-use test_utils::{build_op_test, proptest::prelude::*, TestError, STACK_TOP_SIZE, WORD_SIZE};
+use test_utils::{
+    build_op_test, build_test, proptest::prelude::*, TestError, STACK_TOP_SIZE, WORD_SIZE,
+};
 
 // STACK OPERATIONS TESTS
 // ================================================================================================
@@ -57,6 +59,46 @@ fn dupn_fail() {
     test.expect_error(TestError::AssemblyError("parameter"));
 }
 
+#[test]
+fn dup_dyn() {
+    let stack = [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1];
+
+    // --- index 0 --------------------------------------------------------------------------------
+    let test = build_op_test!("push.0 dup.dyn", &stack);
+    test.expect_stack(&[1, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+    // --- index in the middle of the stack -------------------------------------------------------
+    let test = build_op_test!("push.8 dup.dyn", &stack);
+    test.expect_stack(&[9, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+    // --- deepest index --------------------------------------------------------------------------
+    let test = build_op_test!("push.15 dup.dyn", &stack);
+    test.expect_stack(&[16, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+    // --- the original items stay in place -------------------------------------------------------
+    for idx in [0, 8, 15] {
+        let test = build_op_test!(format!("push.{idx} dup.dyn drop"), &stack);
+        test.expect_stack(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    }
+}
+
+#[test]
+fn dup_dyn_matches_dup() {
+    for idx in 0..16 {
+        let stack = [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1];
+        let expected = build_op_test!(format!("dup.{idx}"), &stack).get_last_stack_state();
+        let test = build_op_test!(format!("push.{idx} dup.dyn"), &stack);
+        assert_eq!(expected, test.get_last_stack_state());
+    }
+}
+
+#[test]
+fn dup_dyn_fail() {
+    let asm_op = "push.16 dup.dyn";
+    let test = build_op_test!(asm_op, &[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
 #[test]
 fn dupw() {
     let asm_op = "dupw";
@@ -147,6 +189,46 @@ fn swapdw() {
     test.expect_stack(&[9, 10, 11, 12, 13, 14, 15, 16, 1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
+#[test]
+fn swapnw() {
+    // --- swapnw.1 is equivalent to swapw --------------------------------------------------------
+    let asm_op = "swapnw.1";
+    let test = build_op_test!(asm_op, &[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    test.expect_stack(&[5, 6, 7, 8, 1, 2, 3, 4, 9, 10, 11, 12, 13, 14, 15, 16]);
+
+    // --- swapnw.2 is equivalent to swapdw -------------------------------------------------------
+    let asm_op = "swapnw.2";
+    let test = build_op_test!(asm_op, &[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    test.expect_stack(&[9, 10, 11, 12, 13, 14, 15, 16, 1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn swapnw_fail() {
+    let asm_op = "swapnw.0";
+    let test = build_op_test!(asm_op, &[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    test.expect_error(TestError::AssemblyError("parameter"));
+
+    // words beyond the top 16 elements of the stack cannot be swapped
+    let asm_op = "swapnw.3";
+    let test = build_op_test!(asm_op, &[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    test.expect_error(TestError::AssemblyError("parameter"));
+
+    let asm_op = "swapnw.5";
+    let test = build_op_test!(asm_op, &[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    test.expect_error(TestError::AssemblyError("parameter"));
+
+    let asm_op = "swapnw";
+    let test = build_op_test!(asm_op, &[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    test.expect_error(TestError::AssemblyError("missing"));
+}
+
+#[test]
+fn swapdw_fail() {
+    let asm_op = "swapdw.1";
+    let test = build_op_test!(asm_op, &[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    test.expect_error(TestError::AssemblyError("parameter"));
+}
+
 #[test]
 fn movup() {
     let asm_op = "movup.2";
@@ -170,6 +252,41 @@ fn movup_fail() {
     test.expect_error(TestError::AssemblyError("parameter"));
 }
 
+#[test]
+fn movup_dyn() {
+    // --- index 0 leaves the stack unchanged -----------------------------------------------------
+    let asm_op = "push.0 movup.dyn";
+    let test = build_op_test!(asm_op, &[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    test.expect_stack(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+
+    // --- index in the middle of the stack -------------------------------------------------------
+    let asm_op = "push.7 movup.dyn";
+    let test = build_op_test!(asm_op, &[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    test.expect_stack(&[8, 1, 2, 3, 4, 5, 6, 7, 9, 10, 11, 12, 13, 14, 15, 16]);
+
+    // --- deepest index --------------------------------------------------------------------------
+    let asm_op = "push.15 movup.dyn";
+    let test = build_op_test!(asm_op, &[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    test.expect_stack(&[16, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+}
+
+#[test]
+fn movup_dyn_matches_movup() {
+    for idx in 2..16 {
+        let stack = [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1];
+        let expected = build_op_test!(format!("movup.{idx}"), &stack).get_last_stack_state();
+        let test = build_op_test!(format!("push.{idx} movup.dyn"), &stack);
+        assert_eq!(expected, test.get_last_stack_state());
+    }
+}
+
+#[test]
+fn movup_dyn_fail() {
+    let asm_op = "push.16 movup.dyn";
+    let test = build_op_test!(asm_op, &[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
 #[test]
 fn movupw() {
     let asm_op = "movupw.2";
@@ -216,6 +333,21 @@ fn movdn_fail() {
     test.expect_error(TestError::AssemblyError("parameter"));
 }
 
+#[test]
+fn movdn_dyn() {
+    let asm_op = "push.0 movdn.dyn";
+    let test = build_op_test!(asm_op, &[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    test.expect_stack(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+
+    let asm_op = "push.7 movdn.dyn";
+    let test = build_op_test!(asm_op, &[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    test.expect_stack(&[2, 3, 4, 5, 6, 7, 8, 1, 9, 10, 11, 12, 13, 14, 15, 16]);
+
+    let asm_op = "push.15 movdn.dyn";
+    let test = build_op_test!(asm_op, &[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    test.expect_stack(&[2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 1]);
+}
+
 #[test]
 fn movdnw() {
     let asm_op = "movdnw.2";
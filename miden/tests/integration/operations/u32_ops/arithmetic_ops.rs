@@ -888,6 +888,28 @@ fn u32unchecked_mod_fail() {
     test.expect_error(TestError::ExecutionError("DivideByZero"));
 }
 
+#[test]
+fn u32checked_div_mod_b_power_of_two() {
+    let a = rand_value::<u32>();
+
+    for k in [0, 1, 7, 16, 31] {
+        let b = 1u32 << k;
+
+        let test = build_op_test!(format!("u32checked_div.{b}").as_str(), &[a as u64]);
+        test.expect_stack(&[(a / b) as u64]);
+
+        let test = build_op_test!(format!("u32checked_mod.{b}").as_str(), &[a as u64]);
+        test.expect_stack(&[(a % b) as u64]);
+
+        // values which are not u32 are still rejected
+        let test = build_op_test!(format!("u32checked_div.{b}").as_str(), &[U32_BOUND]);
+        test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+        let test = build_op_test!(format!("u32checked_mod.{b}").as_str(), &[U32_BOUND]);
+        test.expect_error(TestError::ExecutionError("NotU32Value"));
+    }
+}
+
 #[test]
 fn u32checked_divmod() {
     let asm_op = "u32checked_divmod";
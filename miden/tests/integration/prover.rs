@@ -0,0 +1,309 @@
+use miden::{
+    execute, execute_with_hashed_inputs, execute_with_mem_commitment, execute_with_mem_commitments,
+    execute_with_min_trace_len, execute_with_min_trace_length, execute_with_progress,
+    execute_with_transcript, math::Felt, proof_trace_length, prove, verify, verify_hashed,
+    verify_with_transcript, Assembler, ExecutionPhase, MemAdviceProvider, ProgramInfo,
+    ProofOptions, Prover, StackInputs,
+};
+use test_utils::{build_expected_hash, build_test, hash_elements, StarkField};
+
+// PROVER TESTS
+// ================================================================================================
+
+#[test]
+fn prover_and_verifier_transcripts_match() {
+    let program = build_test!("begin push.1 push.2 add repeat.8 dup mul end end").compile();
+    let stack_inputs = StackInputs::try_from_values([5, 6]).unwrap();
+    let (stack_outputs, proof, prover_challenges) = execute_with_transcript(
+        &program,
+        stack_inputs.clone(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+    assert!(!prover_challenges.is_empty());
+
+    let program_info = ProgramInfo::from(program);
+    let (_, verifier_challenges) =
+        verify_with_transcript(program_info, stack_inputs, stack_outputs, proof).unwrap();
+    assert_eq!(prover_challenges, verifier_challenges);
+}
+
+#[test]
+fn prove_with_mem_commitment() {
+    let program = build_test!(
+        "begin
+            push.1.2.3.4 push.100 mem_storew dropw
+            push.5.6.7.8 push.101 mem_storew dropw
+            push.9 push.102 mem_store
+            add
+        end",
+        &[2, 3]
+    )
+    .compile();
+
+    // the region contains an odd and an even number of words
+    for (commit_len, region) in
+        [(3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0]), (2, vec![1, 2, 3, 4, 5, 6, 7, 8])]
+    {
+        let stack_inputs = StackInputs::try_from_values([2, 3]).unwrap();
+        let (program_info, stack_outputs, proof) = execute_with_mem_commitment(
+            &program,
+            stack_inputs.clone(),
+            MemAdviceProvider::default(),
+            100,
+            commit_len,
+            ProofOptions::default(),
+        )
+        .unwrap();
+
+        // the commitment is at the top of the stack, followed by the outputs of the program
+        let expected_hash = build_expected_hash(&region).map(|e| e.as_int());
+        assert_eq!(expected_hash, stack_outputs.stack()[..4]);
+        assert_eq!(5, stack_outputs.stack()[4]);
+
+        assert!(verify(program_info, stack_inputs, stack_outputs, proof).is_ok());
+    }
+}
+
+#[test]
+fn optimized_program() {
+    let source = "begin push.3 add.4 mul.2 push.5 eq.5 push.9 div.3 neq.3 add mul end";
+    let optimized = Assembler::default().with_optimization(true).compile(source).unwrap();
+    let unoptimized = Assembler::default().compile(source).unwrap();
+    assert_ne!(optimized.hash(), unoptimized.hash());
+
+    let stack_inputs = StackInputs::try_from_values([5]).unwrap();
+    let expected =
+        execute(&unoptimized, stack_inputs.clone(), MemAdviceProvider::default()).unwrap();
+    let result = execute(&optimized, stack_inputs, MemAdviceProvider::default()).unwrap();
+    assert_eq!(&[14, 5, 0, 0], &expected.stack_outputs().stack()[..4]);
+    assert_eq!(expected.stack_outputs(), result.stack_outputs());
+}
+
+#[test]
+fn prove_with_progress() {
+    let program = build_test!("begin push.1 push.2 add repeat.8 dup mul end end").compile();
+    let stack_inputs = StackInputs::try_from_values([5, 6]).unwrap();
+
+    let mut phases = Vec::new();
+    let (stack_outputs, proof) = execute_with_progress(
+        &program,
+        stack_inputs.clone(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+        |phase| phases.push(phase),
+    )
+    .unwrap();
+
+    let trace_len = execute(&program, stack_inputs.clone(), MemAdviceProvider::default())
+        .unwrap()
+        .get_trace_len();
+    assert_eq!(
+        vec![
+            ExecutionPhase::TraceGenerationStarted,
+            ExecutionPhase::TraceGenerated { trace_len },
+            ExecutionPhase::ProofGenerationStarted,
+            ExecutionPhase::ProofGenerated,
+        ],
+        phases
+    );
+
+    let program_info = ProgramInfo::from(program);
+    assert!(verify(program_info, stack_inputs, stack_outputs, proof).is_ok());
+}
+
+#[test]
+fn prove_with_min_trace_length() {
+    let program = build_test!("begin push.1 push.2 add repeat.8 dup mul end end").compile();
+    let stack_inputs = StackInputs::try_from_values([5, 6]).unwrap();
+    let trace_len = execute(&program, stack_inputs.clone(), MemAdviceProvider::default())
+        .unwrap()
+        .get_trace_len();
+
+    // the minimum length is rounded up to the next power of two
+    let min_trace_len = trace_len * 4 + 1;
+    let trace = execute_with_min_trace_len(
+        &program,
+        stack_inputs.clone(),
+        MemAdviceProvider::default(),
+        min_trace_len,
+    )
+    .unwrap();
+    assert!(trace.get_trace_len() >= min_trace_len);
+    assert_eq!(trace_len * 8, trace.get_trace_len());
+
+    // a minimum length smaller than the natural trace length has no effect
+    let trace =
+        execute_with_min_trace_len(&program, stack_inputs.clone(), MemAdviceProvider::default(), 1)
+            .unwrap();
+    assert_eq!(trace_len, trace.get_trace_len());
+
+    let (stack_outputs, proof) = execute_with_min_trace_length(
+        &program,
+        stack_inputs.clone(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+        min_trace_len,
+    )
+    .unwrap();
+
+    let program_info = ProgramInfo::from(program);
+    assert!(verify(program_info, stack_inputs, stack_outputs, proof).is_ok());
+}
+
+#[test]
+fn prove_with_hashed_inputs() {
+    // the program sums the top 25 inputs, leaving exactly 16 elements on the stack
+    let program = build_test!("begin repeat.24 add end end").compile();
+    let inputs = (1..=40).collect::<Vec<u64>>();
+    let stack_inputs = StackInputs::try_from_values(inputs.iter().copied()).unwrap();
+
+    let (program_info, inputs_hash, stack_outputs, proof) = execute_with_hashed_inputs(
+        &program,
+        stack_inputs.clone(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+
+    // the hash commits to the inputs in the order in which they were provided
+    let inputs = inputs.into_iter().map(Felt::new).collect::<Vec<_>>();
+    assert_eq!(hash_elements(&inputs), inputs_hash);
+
+    // the program observes the same stack as when executed with the inputs directly
+    let trace = execute(&program, stack_inputs, MemAdviceProvider::default()).unwrap();
+    assert_eq!(trace.stack_outputs(), &stack_outputs);
+    assert_eq!(&[700, 15, 14], &stack_outputs.stack()[..3]);
+
+    // the proof verifies against the hash only
+    let result =
+        verify_hashed(program_info.clone(), inputs_hash, stack_outputs.clone(), proof.clone());
+    assert!(result.is_ok());
+
+    let other_hash = hash_elements(&inputs[1..]);
+    assert!(verify_hashed(program_info, other_hash, stack_outputs, proof).is_err());
+}
+
+#[test]
+fn prove_with_reusable_prover() {
+    let prover = Prover::new(ProofOptions::default());
+
+    let sources = [
+        "begin push.1 push.2 add end",
+        "begin mul repeat.4 dup add end end",
+        "begin push.3 repeat.8 dup mul end swap drop end",
+    ];
+    for source in sources {
+        let program = build_test!(source).compile();
+        let stack_inputs = StackInputs::try_from_values([5, 6]).unwrap();
+
+        let (stack_outputs, proof) = prover
+            .prove(&program, stack_inputs.clone(), MemAdviceProvider::default())
+            .unwrap();
+
+        // outputs must be the same as the ones of a standalone execution
+        let trace = execute(&program, stack_inputs.clone(), MemAdviceProvider::default()).unwrap();
+        assert_eq!(trace.stack_outputs(), &stack_outputs);
+
+        let program_info = ProgramInfo::from(program);
+        assert!(verify(program_info, stack_inputs, stack_outputs, proof).is_ok());
+    }
+}
+
+#[test]
+fn proof_trace_length_matches_execution() {
+    for num_iterations in [1, 100, 1000] {
+        let source = format!("begin push.1 repeat.{num_iterations} dup mul end end");
+        let program = build_test!(&source).compile();
+        let trace_len = execute(&program, StackInputs::default(), MemAdviceProvider::default())
+            .unwrap()
+            .get_trace_len();
+
+        let (_, proof) = prove(
+            &program,
+            StackInputs::default(),
+            MemAdviceProvider::default(),
+            ProofOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(trace_len, proof_trace_length(proof.stark_proof()));
+    }
+}
+
+#[test]
+fn prove_with_mem_commitments() {
+    let program = build_test!(
+        "begin
+            push.1.2.3.4 push.100 mem_storew dropw
+            push.5.6.7.8 push.101 mem_storew dropw
+            push.9 push.200 mem_store
+            push.10 push.0 mem_store
+            add
+        end",
+        &[2, 3]
+    )
+    .compile();
+
+    // the regions are disjoint and contain an even and an odd number of words; the digests of
+    // the regions are combined without using memory, and thus, a region may start at address 0
+    let region_100 = (100, 2, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    let region_200 = (200, 1, vec![9, 0, 0, 0]);
+    let region_0 = (0, 1, vec![10, 0, 0, 0]);
+    for regions in [
+        vec![region_100.clone(), region_200.clone()],
+        vec![region_100, region_200, region_0],
+    ] {
+        let addresses =
+            regions.iter().map(|&(addr, len, _)| (addr, len)).collect::<Vec<(u64, usize)>>();
+        let stack_inputs = StackInputs::try_from_values([2, 3]).unwrap();
+        let (program_info, stack_outputs, proof) = execute_with_mem_commitments(
+            &program,
+            stack_inputs.clone(),
+            MemAdviceProvider::default(),
+            &addresses,
+            ProofOptions::default(),
+        )
+        .unwrap();
+
+        // the commitment is the hash of the digests of the regions taken in order
+        let digests = regions
+            .iter()
+            .flat_map(|(_, _, values)| {
+                hash_elements(&values.iter().map(|&v| Felt::new(v)).collect::<Vec<_>>())
+                    .as_elements()
+                    .to_vec()
+            })
+            .map(|e| e.as_int())
+            .collect::<Vec<_>>();
+        let expected_hash = build_expected_hash(&digests).map(|e| e.as_int());
+        assert_eq!(expected_hash, stack_outputs.stack()[..4]);
+        assert_eq!(5, stack_outputs.stack()[4]);
+
+        assert!(verify(program_info, stack_inputs, stack_outputs, proof).is_ok());
+    }
+}
+
+#[test]
+fn prove_with_explicit_stack_input_ordering() {
+    // the program leaves the top two stack elements unchanged
+    let program = build_test!("begin dup add push.2 div end").compile();
+
+    let top_first = StackInputs::with_stack_top_first(vec![3, 2, 1]).unwrap();
+    let bottom_first = StackInputs::with_stack_bottom_first(vec![1, 2, 3]).unwrap();
+    assert_eq!(top_first.values(), bottom_first.values());
+
+    for stack_inputs in [top_first, bottom_first] {
+        let (stack_outputs, proof) = prove(
+            &program,
+            stack_inputs.clone(),
+            MemAdviceProvider::default(),
+            ProofOptions::default(),
+        )
+        .unwrap();
+        assert_eq!([3, 2, 1], stack_outputs.stack()[..3]);
+
+        let program_info = ProgramInfo::from(program.clone());
+        assert!(verify(program_info, stack_inputs, stack_outputs, proof).is_ok());
+    }
+}
@@ -0,0 +1,189 @@
+use miden::{
+    prove,
+    utils::{Deserializable, Serializable},
+    verify, verify_mixed, verify_prefix, Assembler, MemAdviceProvider, ProgramInfo, ProofOptions,
+    StackInputs, StackOutputs, VerificationError, VerificationFailure,
+};
+use sha3::{Digest as _, Keccak256};
+use test_utils::build_test;
+use vm_core::stack::MAX_STACK_OUTPUTS;
+
+// VERIFIER TESTS
+// ================================================================================================
+
+#[test]
+fn verify_mixed_programs() {
+    let programs = [
+        build_test!("begin push.1 push.2 add end").compile(),
+        build_test!("begin mul movup.2 drop end").compile(),
+    ];
+
+    // interleave proofs of the two programs: 0, 1, 0, 1
+    let mut instances = Vec::new();
+    let inputs = [vec![1, 2], vec![1, 2, 3], vec![3, 4], vec![4, 5, 6]];
+    for (i, inputs) in inputs.into_iter().enumerate() {
+        let program = &programs[i % 2];
+        let stack_inputs = StackInputs::try_from_values(inputs).unwrap();
+        let (stack_outputs, proof) = prove(
+            program,
+            stack_inputs.clone(),
+            MemAdviceProvider::default(),
+            ProofOptions::default(),
+        )
+        .unwrap();
+        instances.push((ProgramInfo::from(program.clone()), stack_inputs, stack_outputs, proof));
+    }
+    assert!(verify_mixed(instances.clone()).is_ok());
+
+    // tamper with the outputs of the second instance of the second program
+    instances[3].2.stack_mut()[0] += 1;
+    let (idx, _) = verify_mixed(instances).unwrap_err();
+    assert_eq!(3, idx);
+}
+
+#[test]
+fn verification_failure_category() {
+    let program = build_test!("begin push.1 push.2 add end").compile();
+    let stack_inputs = StackInputs::default();
+    let (stack_outputs, proof) = prove(
+        &program,
+        stack_inputs.clone(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+    let program_info = ProgramInfo::from(program);
+
+    // tamper with the least significant byte of the last out-of-domain constraint evaluation
+    let mut ood_proof = proof.clone();
+    let mut bytes = ood_proof.proof.ood_frame.to_bytes();
+    let idx = bytes.len() - 8;
+    bytes[idx] ^= 1;
+    ood_proof.proof.ood_frame = Deserializable::read_from_bytes(&bytes).unwrap();
+
+    let err = verify(program_info.clone(), stack_inputs.clone(), stack_outputs.clone(), ood_proof)
+        .unwrap_err();
+    assert_eq!(Some(VerificationFailure::OodConsistency), err.failure());
+
+    // tamper with the first queried trace value so that its Merkle path no longer authenticates
+    // it; the queried values are serialized after a 4-byte length prefix
+    let mut query_proof = proof;
+    let mut bytes = query_proof.proof.trace_queries[0].to_bytes();
+    bytes[4] ^= 1;
+    query_proof.proof.trace_queries[0] = Deserializable::read_from_bytes(&bytes).unwrap();
+
+    let err = verify(program_info, stack_inputs, stack_outputs, query_proof).unwrap_err();
+    assert_eq!(Some(VerificationFailure::MerkleAuthentication), err.failure());
+}
+
+#[test]
+fn verify_output_prefix() {
+    let program = build_test!("begin push.1 push.2 add end").compile();
+    let program_info = ProgramInfo::from(program.clone());
+    let stack_inputs = StackInputs::try_from_values([5, 6]).unwrap();
+    let (stack_outputs, proof) = prove(
+        &program,
+        stack_inputs.clone(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(&[3, 6, 5], &stack_outputs.stack()[..3]);
+
+    // a matching prefix is accepted
+    let result = verify_prefix(
+        program_info.clone(),
+        stack_inputs.clone(),
+        &[3, 6],
+        stack_outputs.clone(),
+        proof.clone(),
+    );
+    assert!(result.is_ok());
+
+    // a mismatching prefix is rejected even though the proof is valid
+    let result = verify_prefix(
+        program_info.clone(),
+        stack_inputs.clone(),
+        &[3, 5],
+        stack_outputs.clone(),
+        proof.clone(),
+    );
+    assert_eq!(Err(VerificationError::OutputPrefixMismatch(1)), result);
+
+    // a prefix longer than the outputs is rejected
+    let mut prefix = stack_outputs.stack().to_vec();
+    prefix.push(0);
+    let result = verify_prefix(program_info, stack_inputs, &prefix, stack_outputs.clone(), proof);
+    assert_eq!(
+        Err(VerificationError::OutputPrefixMismatch(stack_outputs.stack().len())),
+        result
+    );
+}
+
+#[test]
+fn verify_output_length() {
+    let program = build_test!("begin push.1 push.2 add end").compile();
+    let program_info = ProgramInfo::from(program.clone());
+    let stack_inputs = StackInputs::try_from_values([5, 6]).unwrap();
+    let (_, proof) = prove(
+        &program,
+        stack_inputs.clone(),
+        MemAdviceProvider::default(),
+        ProofOptions::default(),
+    )
+    .unwrap();
+
+    // exactly MAX_STACK_OUTPUTS outputs pass the bounds check; the proof is then rejected because
+    // the outputs do not match the execution
+    let stack_outputs = build_stack_outputs(MAX_STACK_OUTPUTS);
+    let result = verify(program_info.clone(), stack_inputs.clone(), stack_outputs, proof.clone());
+    assert!(matches!(result, Err(VerificationError::VerifierError(_))));
+
+    // one more output is rejected before the proof is verified
+    let stack_outputs = build_stack_outputs(MAX_STACK_OUTPUTS + 1);
+    let result = verify(program_info, stack_inputs, stack_outputs, proof);
+    assert_eq!(
+        Err(VerificationError::OutputLengthExceeded(
+            MAX_STACK_OUTPUTS,
+            MAX_STACK_OUTPUTS + 1
+        )),
+        result
+    );
+}
+
+#[test]
+fn program_external_hash() {
+    let keccak = |bytes: &[u8]| -> [u8; 32] { Keccak256::digest(bytes).into() };
+    let source = "
+        proc.foo
+            push.3 mul
+        end
+        begin
+            push.1 push.2 add exec.foo
+        end";
+
+    // identical sources compiled separately produce the same external hash
+    let program = Assembler::default().compile(source).unwrap();
+    let recompiled = Assembler::default().compile(source).unwrap();
+    let hash = program.external_hash(&keccak);
+    assert_eq!(hash, recompiled.external_hash(&keccak));
+
+    // the hash is computed over the canonical serialization of the program info
+    let program_info = ProgramInfo::from(program);
+    assert_eq!(keccak(&program_info.to_bytes()), hash);
+
+    // a different program produces a different external hash
+    let other = Assembler::default().compile("begin push.1 push.2 mul end").unwrap();
+    assert_ne!(hash, other.external_hash(&keccak));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Builds stack outputs with the specified number of elements, and with a consistent set of
+/// overflow table addresses for the elements beyond the top 16.
+fn build_stack_outputs(num_outputs: usize) -> StackOutputs {
+    let stack = (0..num_outputs as u64).collect::<Vec<_>>();
+    let overflow_addrs = (0..(num_outputs - 15) as u64).collect::<Vec<_>>();
+    StackOutputs::new(stack, overflow_addrs)
+}
@@ -1,4 +1,13 @@
-use super::{BTreeMap, Felt, InnerNodeInfo, InputError, MerkleStore, Vec};
+use super::{
+    BTreeMap, Felt, InnerNodeInfo, InputError, IntoBytes, MerkleStore, RpoDigest, Vec, Word,
+};
+use vm_core::{
+    utils::{
+        collections::BTreeSet, string::String, to_hex, ByteReader, ByteWriter, Deserializable,
+        DeserializationError, Serializable,
+    },
+    ZERO,
+};
 
 // ADVICE INPUTS
 // ================================================================================================
@@ -14,11 +23,18 @@ use super::{BTreeMap, Felt, InnerNodeInfo, InputError, MerkleStore, Vec};
 /// 2. Key-mapped element lists which can be pushed onto the advice stack.
 /// 3. Merkle store, which is used to provide nondeterministic inputs for instructions that
 ///    operates with Merkle trees.
+///
+/// Optionally, the inputs can carry a capacity hint for the advice stack (see
+/// [AdviceInputs::with_stack_capacity]) which is used to pre-size the advice stack of advice
+/// providers built from these inputs. The advice map and the Merkle store take no capacity hints:
+/// advice providers back both with B-tree maps (via `KvMap`), which allocate nodes on demand and
+/// cannot be pre-sized.
 #[derive(Clone, Debug, Default)]
 pub struct AdviceInputs {
     stack: Vec<Felt>,
     map: BTreeMap<[u8; 32], Vec<Felt>>,
     store: MerkleStore,
+    stack_capacity: usize,
 }
 
 impl AdviceInputs {
@@ -67,6 +83,18 @@ impl AdviceInputs {
         self
     }
 
+    /// Sets the expected maximum number of elements in the advice stack during program execution.
+    ///
+    /// When an advice provider is built from these inputs, the advice stack reserves capacity for
+    /// this number of elements up front. The hint is not serialized.
+    ///
+    /// There are no equivalent hints for the advice map and the Merkle store, since their B-tree
+    /// backed `KvMap` implementations do not support reserving capacity.
+    pub fn with_stack_capacity(mut self, capacity: usize) -> Self {
+        self.stack_capacity = capacity;
+        self
+    }
+
     // PUBLIC MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -86,6 +114,31 @@ impl AdviceInputs {
         self.map.extend(iter);
     }
 
+    /// Inserts the given values into the map under the key derived from the provided element,
+    /// replacing previously inserted values.
+    ///
+    /// The element is expanded into the word `[key, ZERO, ZERO, ZERO]` before being converted into
+    /// bytes. Thus, the values can be read by a program via `push.{key}.0.0.0 adv.push_mapval`.
+    pub fn insert_advice_felt_key<I>(&mut self, key: Felt, values: I)
+    where
+        I: IntoIterator<Item = Felt>,
+    {
+        self.insert_advice_word_key([key, ZERO, ZERO, ZERO], values);
+    }
+
+    /// Inserts the given values into the map under the key derived from the provided word,
+    /// replacing previously inserted values.
+    ///
+    /// The key is converted into bytes in the same way as the keys read from the stack by the
+    /// `adv.push_mapval` family of instructions. Thus, the values can be read by a program via
+    /// `push.{key[0]}.{key[1]}.{key[2]}.{key[3]} adv.push_mapval`.
+    pub fn insert_advice_word_key<I>(&mut self, key: Word, values: I)
+    where
+        I: IntoIterator<Item = Felt>,
+    {
+        self.map.insert(key.into_bytes(), values.into_iter().collect());
+    }
+
     /// Extends the [MerkleStore] with the given nodes.
     pub fn extend_merkle_store<I>(&mut self, iter: I)
     where
@@ -112,13 +165,364 @@ impl AdviceInputs {
         &self.store
     }
 
+    /// Returns the expected maximum number of elements in the advice stack.
+    pub const fn stack_capacity(&self) -> usize {
+        self.stack_capacity
+    }
+
+    /// Returns an estimate of the number of bytes needed to serialize these advice inputs.
+    ///
+    /// The estimate is computed from the sizes of the advice stack, the advice map, and the Merkle
+    /// store without serializing any of them. The stack and the map components are exact; the
+    /// Merkle store component assumes that every node is serialized as its digest followed by the
+    /// digests of its two children, and is expected to be within 10% of the actual length.
+    pub fn estimated_size_bytes(&self) -> usize {
+        // lengths are serialized as u64 values, and elements in their canonical u64 form
+        const LEN_SIZE: usize = core::mem::size_of::<u64>();
+        const ELEMENT_SIZE: usize = core::mem::size_of::<u64>();
+        const DIGEST_SIZE: usize = 4 * ELEMENT_SIZE;
+
+        let stack_size = LEN_SIZE + self.stack.len() * ELEMENT_SIZE;
+        let map_size = LEN_SIZE
+            + self
+                .map
+                .values()
+                .map(|values| 32 + LEN_SIZE + values.len() * ELEMENT_SIZE)
+                .sum::<usize>();
+        let store_size = LEN_SIZE + self.store.inner_nodes().count() * 3 * DIGEST_SIZE;
+
+        stack_size + map_size + store_size
+    }
+
+    // DIFFING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the structural differences between these advice inputs and the `other` inputs.
+    ///
+    /// The advice stacks are compared element by element, the advice maps are compared by their
+    /// keys and the values stored under shared keys, and the Merkle stores are compared by the
+    /// roots of the trees they contain. The stack capacity hint is not compared.
+    pub fn diff(&self, other: &AdviceInputs) -> AdviceDiff {
+        let first_stack_mismatch =
+            self.stack.iter().zip(other.stack.iter()).position(|(a, b)| a != b).or_else(|| {
+                (self.stack.len() != other.stack.len())
+                    .then_some(self.stack.len().min(other.stack.len()))
+            });
+
+        let mut map_keys_only_in_self = Vec::new();
+        let mut map_values_differ = Vec::new();
+        for (key, values) in self.map.iter() {
+            match other.map.get(key) {
+                None => map_keys_only_in_self.push(*key),
+                Some(other_values) if other_values != values => map_values_differ.push(*key),
+                Some(_) => (),
+            }
+        }
+        let map_keys_only_in_other =
+            other.map.keys().filter(|key| !self.map.contains_key(*key)).copied().collect();
+
+        let roots = get_merkle_roots(&self.store);
+        let other_roots = get_merkle_roots(&other.store);
+
+        AdviceDiff {
+            first_stack_mismatch,
+            map_keys_only_in_self,
+            map_keys_only_in_other,
+            map_values_differ,
+            merkle_roots_only_in_self: roots.difference(&other_roots).copied().collect(),
+            merkle_roots_only_in_other: other_roots.difference(&roots).copied().collect(),
+        }
+    }
+
+    // HEX ENCODING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a hex-encoded string of the serialized advice stack, advice map, and Merkle store.
+    ///
+    /// This is intended to be used for sharing advice inputs in a compact form (e.g., to reproduce
+    /// an issue); the inputs can be restored via [AdviceInputs::from_hex].
+    pub fn to_hex(&self) -> String {
+        to_hex(&self.to_bytes()).expect("failed to write hex string")
+    }
+
+    /// Returns advice inputs decoded from the provided hex-encoded string.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not a valid hex encoding of serialized advice inputs.
+    pub fn from_hex(hex: &str) -> Result<Self, DeserializationError> {
+        if hex.len() % 2 != 0 {
+            return Err(DeserializationError::InvalidValue(
+                "hex string must have an even number of characters".into(),
+            ));
+        }
+
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|i| {
+                hex.get(i..i + 2).and_then(|byte| u8::from_str_radix(byte, 16).ok()).ok_or_else(
+                    || DeserializationError::InvalidValue(format!("invalid hex byte at index {i}")),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::read_from_bytes(&bytes)
+    }
+
     // DESTRUCTORS
     // --------------------------------------------------------------------------------------------
 
     /// Decomposes these `[Self]` into their raw components.
     #[allow(clippy::type_complexity)]
     pub(crate) fn into_parts(self) -> (Vec<Felt>, BTreeMap<[u8; 32], Vec<Felt>>, MerkleStore) {
-        let Self { stack, map, store } = self;
+        let Self {
+            stack, map, store, ..
+        } = self;
         (stack, map, store)
     }
 }
+
+// ADVICE DIFF
+// ================================================================================================
+
+/// Structural differences between two sets of advice inputs, as computed by [AdviceInputs::diff].
+///
+/// Map keys and Merkle roots are listed in ascending order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AdviceDiff {
+    first_stack_mismatch: Option<usize>,
+    map_keys_only_in_self: Vec<[u8; 32]>,
+    map_keys_only_in_other: Vec<[u8; 32]>,
+    map_values_differ: Vec<[u8; 32]>,
+    merkle_roots_only_in_self: Vec<RpoDigest>,
+    merkle_roots_only_in_other: Vec<RpoDigest>,
+}
+
+impl AdviceDiff {
+    /// Returns true if no differences were found.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Returns the position of the first element at which the advice stacks differ.
+    ///
+    /// If one stack is a prefix of the other, this is the length of the shorter stack.
+    pub fn first_stack_mismatch(&self) -> Option<usize> {
+        self.first_stack_mismatch
+    }
+
+    /// Returns the keys present in the advice map of the diffed inputs but not in the other one.
+    pub fn map_keys_only_in_self(&self) -> &[[u8; 32]] {
+        &self.map_keys_only_in_self
+    }
+
+    /// Returns the keys present in the advice map of the other inputs but not in the diffed one.
+    pub fn map_keys_only_in_other(&self) -> &[[u8; 32]] {
+        &self.map_keys_only_in_other
+    }
+
+    /// Returns the keys present in both advice maps under which different values are stored.
+    pub fn map_values_differ(&self) -> &[[u8; 32]] {
+        &self.map_values_differ
+    }
+
+    /// Returns the roots of the Merkle trees present in the store of the diffed inputs but not in
+    /// the other one.
+    pub fn merkle_roots_only_in_self(&self) -> &[RpoDigest] {
+        &self.merkle_roots_only_in_self
+    }
+
+    /// Returns the roots of the Merkle trees present in the store of the other inputs but not in
+    /// the diffed one.
+    pub fn merkle_roots_only_in_other(&self) -> &[RpoDigest] {
+        &self.merkle_roots_only_in_other
+    }
+}
+
+/// Returns the roots of all trees in the provided [MerkleStore], i.e., the nodes which are not
+/// children of any other node in the store.
+fn get_merkle_roots(store: &MerkleStore) -> BTreeSet<RpoDigest> {
+    let mut nodes = BTreeSet::new();
+    let mut children = BTreeSet::new();
+    for node in store.inner_nodes() {
+        nodes.insert(node.value);
+        children.insert(node.left);
+        children.insert(node.right);
+    }
+    nodes.difference(&children).copied().collect()
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AdviceInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let Self {
+            stack, map, store, ..
+        } = self;
+
+        target.write_u64(stack.len() as u64);
+        stack.iter().for_each(|value| target.write(*value));
+
+        target.write_u64(map.len() as u64);
+        for (key, values) in map.iter() {
+            target.write_bytes(key);
+            target.write_u64(values.len() as u64);
+            values.iter().for_each(|value| target.write(*value));
+        }
+
+        store.write_into(target);
+    }
+}
+
+impl Deserializable for AdviceInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let stack = read_elements(source)?;
+
+        let map_len = source.read_u64()?;
+        let mut map = BTreeMap::new();
+        for _ in 0..map_len {
+            let key = source.read_array::<32>()?;
+            let values = read_elements(source)?;
+            map.insert(key, values);
+        }
+
+        let store = MerkleStore::read_from(source)?;
+
+        Ok(Self {
+            stack,
+            map,
+            store,
+            stack_capacity: 0,
+        })
+    }
+}
+
+/// Reads a length-prefixed list of field elements from the `source`.
+fn read_elements<R: ByteReader>(source: &mut R) -> Result<Vec<Felt>, DeserializationError> {
+    let len = source.read_u64()?;
+    (0..len).map(|_| Felt::read_from(source)).collect()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AdviceInputs, DeserializationError, Felt};
+    use vm_core::{
+        crypto::merkle::{MerkleStore, MerkleTree, NodeIndex},
+        utils::{collections::Vec, IntoBytes, Serializable},
+        FieldElement, Word,
+    };
+
+    #[test]
+    fn advice_inputs_hex_round_trip() {
+        let leaves = (1..=8).map(|i| [Felt::new(i), Felt::ZERO, Felt::ZERO, Felt::ZERO]);
+        let tree = MerkleTree::new(leaves.collect::<Vec<Word>>()).unwrap();
+        let key = [Felt::new(7), Felt::new(8), Felt::new(9), Felt::new(10)].into_bytes();
+
+        let inputs = AdviceInputs::default()
+            .with_stack_values([1, 2, 3, u64::MAX >> 1])
+            .unwrap()
+            .with_map([(key, vec![Felt::new(4), Felt::new(5)])])
+            .with_merkle_store(MerkleStore::from(&tree));
+
+        let decoded = AdviceInputs::from_hex(&inputs.to_hex()).unwrap();
+        assert_eq!(inputs.stack(), decoded.stack());
+        assert_eq!(inputs.mapped_values(&key), decoded.mapped_values(&key));
+
+        let index = NodeIndex::new(tree.depth(), 5).unwrap();
+        assert_eq!(
+            inputs.merkle_store().get_node(tree.root(), index).unwrap(),
+            decoded.merkle_store().get_node(tree.root(), index).unwrap()
+        );
+        assert_eq!(inputs.to_hex(), decoded.to_hex());
+    }
+
+    #[test]
+    fn advice_inputs_estimated_size() {
+        let leaves = (1..=16).map(|i| [Felt::new(i), Felt::ZERO, Felt::ZERO, Felt::ZERO]);
+        let tree = MerkleTree::new(leaves.collect::<Vec<Word>>()).unwrap();
+        let map = (0..5_u64).map(|i| {
+            let key = [Felt::new(i), Felt::ZERO, Felt::ZERO, Felt::ZERO].into_bytes();
+            (key, (0..i * 3).map(Felt::new).collect())
+        });
+
+        let inputs = AdviceInputs::default()
+            .with_stack_values(0..100)
+            .unwrap()
+            .with_map(map)
+            .with_merkle_store(MerkleStore::from(&tree));
+
+        // the estimate must be within 10% of the actual serialized length
+        let actual = inputs.to_bytes().len();
+        let estimate = inputs.estimated_size_bytes();
+        assert!(estimate.abs_diff(actual) * 10 <= actual, "estimate {estimate}, actual {actual}");
+
+        // the stack and the map components of the estimate are exact
+        let empty = AdviceInputs::default();
+        let inputs = empty.clone().with_stack_values(0..100).unwrap().with_map([([1; 32], vec![])]);
+        assert_eq!(
+            inputs.estimated_size_bytes() - empty.estimated_size_bytes(),
+            inputs.to_bytes().len() - empty.to_bytes().len()
+        );
+    }
+
+    #[test]
+    fn advice_inputs_from_malformed_hex() {
+        let hex = AdviceInputs::default().with_stack_values([1, 2, 3]).unwrap().to_hex();
+
+        // odd number of characters
+        let result = AdviceInputs::from_hex(&hex[1..]);
+        assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+
+        // invalid hex characters
+        let result = AdviceInputs::from_hex(&hex.replacen('0', "z", 1));
+        assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+
+        // truncated inputs
+        let result = AdviceInputs::from_hex(&hex[..hex.len() - 2]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn advice_inputs_diff() {
+        let leaves = (1..=8).map(|i| [Felt::new(i), Felt::ZERO, Felt::ZERO, Felt::ZERO]);
+        let tree = MerkleTree::new(leaves.collect::<Vec<Word>>()).unwrap();
+        let shared_key = [1; 32];
+        let differing_key = [2; 32];
+        let extra_key = [3; 32];
+
+        let inputs = AdviceInputs::default()
+            .with_stack_values([1, 2, 3])
+            .unwrap()
+            .with_map([(shared_key, vec![Felt::new(4)]), (differing_key, vec![Felt::new(5)])]);
+        let other = inputs
+            .clone()
+            .with_map([(differing_key, vec![Felt::new(6)]), (extra_key, vec![])])
+            .with_merkle_store(MerkleStore::from(&tree));
+
+        let diff = inputs.diff(&other);
+        assert!(!diff.is_empty());
+        assert_eq!(None, diff.first_stack_mismatch());
+        assert!(diff.map_keys_only_in_self().is_empty());
+        assert_eq!([extra_key], diff.map_keys_only_in_other());
+        assert_eq!([differing_key], diff.map_values_differ());
+        assert!(diff.merkle_roots_only_in_self().is_empty());
+        assert_eq!([tree.root()], diff.merkle_roots_only_in_other());
+
+        // diffing in the opposite direction swaps the sides
+        let diff = other.diff(&inputs);
+        assert_eq!([extra_key], diff.map_keys_only_in_self());
+        assert_eq!([differing_key], diff.map_values_differ());
+        assert_eq!([tree.root()], diff.merkle_roots_only_in_self());
+
+        // differences in the advice stack are located at the first differing element
+        let other = inputs.clone().with_stack_values([4]).unwrap();
+        assert_eq!(Some(3), inputs.diff(&other).first_stack_mismatch());
+        let other = AdviceInputs::default().with_stack_values([1, 5, 3]).unwrap();
+        assert_eq!(Some(1), inputs.diff(&other).first_stack_mismatch());
+
+        assert!(inputs.diff(&inputs.clone()).is_empty());
+    }
+}
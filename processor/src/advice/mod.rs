@@ -8,13 +8,16 @@ use vm_core::{
         collections::{BTreeMap, KvMap, RecordingMap, Vec},
         IntoBytes,
     },
+    WORD_SIZE,
 };
 
 mod inputs;
-pub use inputs::AdviceInputs;
+pub use inputs::{AdviceDiff, AdviceInputs};
 
 mod providers;
-pub use providers::{MemAdviceProvider, RecAdviceProvider};
+#[cfg(any(test, feature = "internals"))]
+pub use providers::{AdviceRead, ReplayAdviceProvider, ScriptedAdviceProvider};
+pub use providers::{MemAdviceProvider, RecAdviceProvider, SliceAdviceProvider};
 
 mod source;
 pub use source::AdviceSource;
@@ -85,6 +88,12 @@ pub trait AdviceProvider {
     /// Returns an error if the advice stack does not contain two words.
     fn pop_stack_dword(&mut self) -> Result<[Word; 2], ExecutionError>;
 
+    /// Returns the number of elements remaining on the advice stack.
+    ///
+    /// This can be used to make sure that the advice stack contains enough elements before they
+    /// are consumed.
+    fn advice_stack_len(&self) -> usize;
+
     /// Pushes the value(s) specified by the source onto the advice stack.
     ///
     /// # Errors
@@ -149,7 +158,8 @@ pub trait AdviceProvider {
     /// returns the Merkle path from the updated node to the new root.
     ///
     /// The tree is cloned prior to the update. Thus, the advice provider retains the original and
-    /// the updated tree.
+    /// the updated tree. The root of the original tree is recorded so that the update can be
+    /// reverted via [AdviceProvider::rollback_merkle_update].
     ///
     /// # Errors
     /// Returns an error if:
@@ -166,6 +176,18 @@ pub trait AdviceProvider {
         value: Word,
     ) -> Result<MerklePath, ExecutionError>;
 
+    /// Reverts the most recent update recorded for a Merkle tree with the specified root; returns
+    /// the root of the tree as it was prior to the update.
+    ///
+    /// Since the original tree is retained by the advice provider on every update, nodes of the
+    /// returned tree can be accessed in the same way as before the update was made. Rolling back
+    /// a chain of updates can be done by calling this method repeatedly with the returned roots.
+    ///
+    /// # Errors
+    /// Returns an error if no update producing a tree with the specified root has been recorded by
+    /// this advice provider.
+    fn rollback_merkle_update(&mut self, new_root: Word) -> Result<Word, ExecutionError>;
+
     /// Creates a new Merkle tree in the advice provider by combining Merkle trees with the
     /// specified roots. The root of the new tree is defined as `hash(left_root, right_root)`.
     ///
@@ -206,6 +228,10 @@ where
         T::pop_stack_dword(self)
     }
 
+    fn advice_stack_len(&self) -> usize {
+        T::advice_stack_len(self)
+    }
+
     fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
         T::push_stack(self, source)
     }
@@ -251,6 +277,10 @@ where
         T::update_merkle_node(self, root, depth, index, value)
     }
 
+    fn rollback_merkle_update(&mut self, new_root: Word) -> Result<Word, ExecutionError> {
+        T::rollback_merkle_update(self, new_root)
+    }
+
     fn merge_roots(&mut self, lhs: Word, rhs: Word) -> Result<Word, ExecutionError> {
         T::merge_roots(self, lhs, rhs)
     }
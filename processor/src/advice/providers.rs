@@ -1,7 +1,12 @@
 use super::{
-    AdviceInputs, AdviceProvider, AdviceSource, BTreeMap, ExecutionError, Felt, IntoBytes, KvMap,
-    MerklePath, MerkleStore, NodeIndex, RecordingMap, RpoDigest, StarkField, StoreNode, Vec, Word,
+    AdviceInputs, AdviceProvider, AdviceSource, BTreeMap, ExecutionError, Felt, InputError,
+    IntoBytes, KvMap, MerklePath, MerkleStore, NodeIndex, RecordingMap, RpoDigest, StarkField,
+    StoreNode, Vec, Word, WORD_SIZE,
 };
+use core::ops::Range;
+
+#[cfg(any(test, feature = "internals"))]
+use alloc::collections::VecDeque;
 
 // TYPE ALIASES
 // ================================================================================================
@@ -27,6 +32,7 @@ where
     stack: Vec<Felt>,
     map: M,
     store: MerkleStore<S>,
+    root_history: BTreeMap<RpoDigest, RpoDigest>,
 }
 
 impl<M, S> From<AdviceInputs> for BaseAdviceProvider<M, S>
@@ -35,13 +41,16 @@ where
     S: KvMap<RpoDigest, StoreNode>,
 {
     fn from(inputs: AdviceInputs) -> Self {
+        let stack_capacity = inputs.stack_capacity();
         let (mut stack, map, store) = inputs.into_parts();
         stack.reverse();
+        stack.reserve(stack_capacity.saturating_sub(stack.len()));
         Self {
             step: 0,
             stack,
             map: map.into_iter().collect(),
             store: store.inner_nodes().collect(),
+            root_history: BTreeMap::new(),
         }
     }
 }
@@ -79,6 +88,10 @@ where
         Ok([word0, word1])
     }
 
+    fn advice_stack_len(&self) -> usize {
+        self.stack.len()
+    }
+
     fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
         match source {
             AdviceSource::Value(value) => {
@@ -98,6 +111,19 @@ where
                 }
                 Ok(())
             }
+
+            AdviceSource::MapWord { key } => {
+                let values = self
+                    .map
+                    .get(&key.into_bytes())
+                    .ok_or(ExecutionError::AdviceKeyNotFound(key))?;
+                if values.len() != WORD_SIZE {
+                    return Err(ExecutionError::ExpectedWordLength(key, values.len()));
+                }
+
+                self.stack.extend(values.iter().rev());
+                Ok(())
+            }
         }
     }
 
@@ -115,16 +141,7 @@ where
         depth: &Felt,
         index: &Felt,
     ) -> Result<Word, ExecutionError> {
-        let index = NodeIndex::from_elements(depth, index).map_err(|_| {
-            ExecutionError::InvalidTreeNodeIndex {
-                depth: *depth,
-                value: *index,
-            }
-        })?;
-        self.store
-            .get_node(root.into(), index)
-            .map(|v| v.into())
-            .map_err(ExecutionError::MerkleStoreLookupFailed)
+        get_store_node(&self.store, root, depth, index)
     }
 
     fn get_merkle_path(
@@ -133,16 +150,7 @@ where
         depth: &Felt,
         index: &Felt,
     ) -> Result<MerklePath, ExecutionError> {
-        let index = NodeIndex::from_elements(depth, index).map_err(|_| {
-            ExecutionError::InvalidTreeNodeIndex {
-                depth: *depth,
-                value: *index,
-            }
-        })?;
-        self.store
-            .get_path(root.into(), index)
-            .map(|value| value.path)
-            .map_err(ExecutionError::MerkleStoreLookupFailed)
+        get_store_path(&self.store, root, depth, index)
     }
 
     fn get_leaf_depth(
@@ -151,11 +159,7 @@ where
         tree_depth: &Felt,
         index: &Felt,
     ) -> Result<u8, ExecutionError> {
-        let tree_depth = u8::try_from(tree_depth.as_int())
-            .map_err(|_| ExecutionError::InvalidTreeDepth { depth: *tree_depth })?;
-        self.store
-            .get_leaf_depth(root.into(), tree_depth, index.as_int())
-            .map_err(ExecutionError::MerkleStoreLookupFailed)
+        get_store_leaf_depth(&self.store, root, tree_depth, index)
     }
 
     fn update_merkle_node(
@@ -165,16 +169,21 @@ where
         index: &Felt,
         value: Word,
     ) -> Result<MerklePath, ExecutionError> {
-        let node_index = NodeIndex::from_elements(depth, index).map_err(|_| {
-            ExecutionError::InvalidTreeNodeIndex {
-                depth: *depth,
-                value: *index,
-            }
-        })?;
-        self.store
-            .set_node(root.into(), node_index, value.into())
-            .map(|root| root.path)
-            .map_err(ExecutionError::MerkleStoreUpdateFailed)
+        let node_index = get_node_index(depth, index)?;
+        let old_root = RpoDigest::from(root);
+        let root_path = self
+            .store
+            .set_node(old_root, node_index, value.into())
+            .map_err(ExecutionError::MerkleStoreUpdateFailed)?;
+        self.root_history.insert(root_path.root, old_root);
+        Ok(root_path.path)
+    }
+
+    fn rollback_merkle_update(&mut self, new_root: Word) -> Result<Word, ExecutionError> {
+        self.root_history
+            .remove(&RpoDigest::from(new_root))
+            .map(|old_root| old_root.into())
+            .ok_or(ExecutionError::MerkleUpdateNotFound(new_root))
     }
 
     fn merge_roots(&mut self, lhs: Word, rhs: Word) -> Result<Word, ExecutionError> {
@@ -230,6 +239,18 @@ impl MemAdviceProvider {
     pub fn has_merkle_root(&self, root: crate::crypto::RpoDigest) -> bool {
         self.provider.store.get_node(root, NodeIndex::root()).is_ok()
     }
+
+    /// Returns an iterator over the entries of the advice map, ordered by the byte representation
+    /// of their keys.
+    pub fn map_entries(&self) -> impl Iterator<Item = (Word, &[Felt])> + '_ {
+        self.provider.map.iter().map(|(key, values)| {
+            let key = core::array::from_fn(|i| {
+                let bytes = key[i * 8..(i + 1) * 8].try_into().expect("invalid key length");
+                Felt::new(u64::from_le_bytes(bytes))
+            });
+            (key, values.as_slice())
+        })
+    }
 }
 
 /// Pass-through implementations of [AdviceProvider] methods.
@@ -249,6 +270,10 @@ impl AdviceProvider for MemAdviceProvider {
         self.provider.pop_stack_dword()
     }
 
+    fn advice_stack_len(&self) -> usize {
+        self.provider.advice_stack_len()
+    }
+
     fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
         self.provider.push_stack(source)
     }
@@ -273,6 +298,10 @@ impl AdviceProvider for MemAdviceProvider {
         self.provider.update_merkle_node(root, depth, index, value)
     }
 
+    fn rollback_merkle_update(&mut self, new_root: Word) -> Result<Word, ExecutionError> {
+        self.provider.rollback_merkle_update(new_root)
+    }
+
     fn merge_roots(&mut self, lhs: Word, rhs: Word) -> Result<Word, ExecutionError> {
         self.provider.merge_roots(lhs, rhs)
     }
@@ -311,6 +340,7 @@ impl RecAdviceProvider {
             stack: _,
             map,
             store,
+            root_history: _,
         } = provider;
 
         let map = map.into_proof();
@@ -375,6 +405,10 @@ impl AdviceProvider for RecAdviceProvider {
         self.provider.pop_stack_dword()
     }
 
+    fn advice_stack_len(&self) -> usize {
+        self.provider.advice_stack_len()
+    }
+
     fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
         self.provider.push_stack(source)
     }
@@ -399,6 +433,10 @@ impl AdviceProvider for RecAdviceProvider {
         self.provider.update_merkle_node(root, depth, index, value)
     }
 
+    fn rollback_merkle_update(&mut self, new_root: Word) -> Result<Word, ExecutionError> {
+        self.provider.rollback_merkle_update(new_root)
+    }
+
     fn merge_roots(&mut self, lhs: Word, rhs: Word) -> Result<Word, ExecutionError> {
         self.provider.merge_roots(lhs, rhs)
     }
@@ -407,3 +445,971 @@ impl AdviceProvider for RecAdviceProvider {
         self.provider.advance_clock()
     }
 }
+
+// SLICE ADVICE PROVIDER
+// ================================================================================================
+
+/// A read-only [AdviceProvider] implementation which serves advice map lookups from borrowed
+/// slices, and thus, does not allocate memory for the advice map.
+///
+/// Values of the advice map are stored back to back in a flat value arena. The map is defined by
+/// an index of `(key, range)` entries sorted by key, where `range` specifies the location of the
+/// values in the arena. Merkle paths are served from a borrowed [MerkleStore].
+///
+/// The advice stack is served from a borrowed slice as well: a cursor tracks how many of its
+/// values have been popped. Values pushed onto the advice stack during execution are kept in a
+/// separate buffer on top of the remaining slice values, which allocates only once values are
+/// pushed. Calls which mutate the advice map or the Merkle store return
+/// [ExecutionError::AdviceProviderReadOnly].
+#[derive(Debug, Clone)]
+pub struct SliceAdviceProvider<'a, S>
+where
+    S: KvMap<RpoDigest, StoreNode>,
+{
+    step: u32,
+    stack: &'a [Felt],
+    stack_cursor: usize,
+    pushed: Vec<Felt>,
+    map_index: &'a [([u8; 32], Range<usize>)],
+    map_values: &'a [Felt],
+    store: &'a MerkleStore<S>,
+}
+
+impl<'a, S> SliceAdviceProvider<'a, S>
+where
+    S: KvMap<RpoDigest, StoreNode>,
+{
+    /// Returns a new [SliceAdviceProvider] instantiated with the specified advice stack, advice
+    /// map index and value arena, and Merkle store.
+    ///
+    /// The advice stack is expected in the same order as for [AdviceInputs::with_stack()], i.e.,
+    /// the first value will be the first one popped off the stack.
+    ///
+    /// # Errors
+    /// Returns an error if the entries of the map index are not sorted by key in strictly
+    /// ascending order, or if a value range of an entry is not contained in the value arena.
+    pub fn new(
+        stack: &'a [Felt],
+        map_index: &'a [([u8; 32], Range<usize>)],
+        map_values: &'a [Felt],
+        store: &'a MerkleStore<S>,
+    ) -> Result<Self, InputError> {
+        for (idx, (key, range)) in map_index.iter().enumerate() {
+            let is_sorted = idx == 0 || map_index[idx - 1].0 < *key;
+            if !is_sorted || range.start > range.end || range.end > map_values.len() {
+                return Err(InputError::InvalidAdviceMapEntry(*key));
+            }
+        }
+
+        Ok(Self {
+            step: 0,
+            stack,
+            stack_cursor: 0,
+            pushed: Vec::new(),
+            map_index,
+            map_values,
+            store,
+        })
+    }
+
+    /// Returns the values stored in the advice map under the specified key.
+    fn get_map_values(&self, key: &[u8; 32]) -> Option<&'a [Felt]> {
+        let (map_index, map_values) = (self.map_index, self.map_values);
+        map_index
+            .binary_search_by(|(entry_key, _)| entry_key.cmp(key))
+            .ok()
+            .map(|pos| &map_values[map_index[pos].1.clone()])
+    }
+
+    /// Pops a value off the advice stack, taking values pushed during execution first.
+    fn pop_value(&mut self) -> Option<Felt> {
+        if let Some(value) = self.pushed.pop() {
+            return Some(value);
+        }
+
+        let value = self.stack.get(self.stack_cursor).copied()?;
+        self.stack_cursor += 1;
+        Some(value)
+    }
+}
+
+impl<'a, S> AdviceProvider for SliceAdviceProvider<'a, S>
+where
+    S: KvMap<RpoDigest, StoreNode>,
+{
+    // ADVICE STACK
+    // --------------------------------------------------------------------------------------------
+
+    fn pop_stack(&mut self) -> Result<Felt, ExecutionError> {
+        self.pop_value().ok_or(ExecutionError::AdviceStackReadFailed(self.step))
+    }
+
+    fn pop_stack_word(&mut self) -> Result<Word, ExecutionError> {
+        if self.advice_stack_len() < WORD_SIZE {
+            return Err(ExecutionError::AdviceStackReadFailed(self.step));
+        }
+
+        Ok(core::array::from_fn(|_| self.pop_value().expect("missing stack value")))
+    }
+
+    fn pop_stack_dword(&mut self) -> Result<[Word; 2], ExecutionError> {
+        let word0 = self.pop_stack_word()?;
+        let word1 = self.pop_stack_word()?;
+
+        Ok([word0, word1])
+    }
+
+    fn advice_stack_len(&self) -> usize {
+        self.pushed.len() + self.stack.len() - self.stack_cursor
+    }
+
+    fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
+        match source {
+            AdviceSource::Value(value) => {
+                self.pushed.push(value);
+                Ok(())
+            }
+
+            AdviceSource::Map { key, include_len } => {
+                let values = self
+                    .get_map_values(&key.into_bytes())
+                    .ok_or(ExecutionError::AdviceKeyNotFound(key))?;
+
+                self.pushed.extend(values.iter().rev());
+                if include_len {
+                    self.pushed.push(Felt::from(values.len() as u64));
+                }
+                Ok(())
+            }
+
+            AdviceSource::MapWord { key } => {
+                let values = self
+                    .get_map_values(&key.into_bytes())
+                    .ok_or(ExecutionError::AdviceKeyNotFound(key))?;
+                if values.len() != WORD_SIZE {
+                    return Err(ExecutionError::ExpectedWordLength(key, values.len()));
+                }
+
+                self.pushed.extend(values.iter().rev());
+                Ok(())
+            }
+        }
+    }
+
+    fn insert_into_map(&mut self, _key: Word, _values: Vec<Felt>) -> Result<(), ExecutionError> {
+        Err(ExecutionError::AdviceProviderReadOnly("insert_into_map"))
+    }
+
+    // ADVISE SETS
+    // --------------------------------------------------------------------------------------------
+
+    fn get_tree_node(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<Word, ExecutionError> {
+        get_store_node(&self.store, root, depth, index)
+    }
+
+    fn get_merkle_path(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<MerklePath, ExecutionError> {
+        get_store_path(&self.store, root, depth, index)
+    }
+
+    fn get_leaf_depth(
+        &self,
+        root: Word,
+        tree_depth: &Felt,
+        index: &Felt,
+    ) -> Result<u8, ExecutionError> {
+        get_store_leaf_depth(&self.store, root, tree_depth, index)
+    }
+
+    fn update_merkle_node(
+        &mut self,
+        _root: Word,
+        _depth: &Felt,
+        _index: &Felt,
+        _value: Word,
+    ) -> Result<MerklePath, ExecutionError> {
+        Err(ExecutionError::AdviceProviderReadOnly("update_merkle_node"))
+    }
+
+    fn rollback_merkle_update(&mut self, _new_root: Word) -> Result<Word, ExecutionError> {
+        Err(ExecutionError::AdviceProviderReadOnly("rollback_merkle_update"))
+    }
+
+    fn merge_roots(&mut self, _lhs: Word, _rhs: Word) -> Result<Word, ExecutionError> {
+        Err(ExecutionError::AdviceProviderReadOnly("merge_roots"))
+    }
+
+    // CONTEXT MANAGEMENT
+    // --------------------------------------------------------------------------------------------
+
+    fn advance_clock(&mut self) {
+        self.step += 1;
+    }
+}
+
+// SCRIPTED ADVICE PROVIDER
+// ================================================================================================
+
+/// A single read from the advice stack recorded by [ScriptedAdviceProvider].
+#[cfg(any(test, feature = "internals"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdviceRead {
+    /// A single element was popped off the advice stack.
+    Element(Felt),
+    /// A word was popped off the advice stack.
+    Word(Word),
+    /// A double word was popped off the advice stack.
+    DoubleWord([Word; 2]),
+}
+
+/// An [AdviceProvider] implementation which serves advice from a predefined script and records
+/// every read from the advice stack, intended for property-based testing.
+///
+/// The script is consumed front to back: the first element of the script is the first one popped
+/// off the advice stack. Successful advice stack reads are appended to a read log which can be
+/// inspected via [ScriptedAdviceProvider::read_log()]; failed reads are not recorded.
+///
+/// The advice map starts out empty and Merkle paths are served from the Merkle store provided via
+/// [ScriptedAdviceProvider::with_merkle_store()]. Methods which mutate the Merkle store are not
+/// supported and return [ExecutionError::AdviceProviderReadOnly].
+#[cfg(any(test, feature = "internals"))]
+#[derive(Debug, Clone, Default)]
+pub struct ScriptedAdviceProvider {
+    step: u32,
+    script: VecDeque<Felt>,
+    read_log: Vec<AdviceRead>,
+    map: SimpleAdviceMap,
+    store: MerkleStore<SimpleMerkleMap>,
+}
+
+#[cfg(any(test, feature = "internals"))]
+impl ScriptedAdviceProvider {
+    /// Returns a new [ScriptedAdviceProvider] which serves the specified script.
+    pub fn new<I>(script: I) -> Self
+    where
+        I: IntoIterator<Item = Felt>,
+    {
+        Self {
+            script: script.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the Merkle store from which Merkle paths are served.
+    pub fn with_merkle_store(mut self, store: MerkleStore<SimpleMerkleMap>) -> Self {
+        self.store = store;
+        self
+    }
+
+    /// Returns the reads from the advice stack performed so far, in the order in which they
+    /// occurred.
+    pub fn read_log(&self) -> &[AdviceRead] {
+        &self.read_log
+    }
+
+    /// Returns the part of the script which has not been consumed yet, starting with the element
+    /// which will be popped off the advice stack next.
+    pub fn remaining_script(&self) -> impl Iterator<Item = &Felt> {
+        self.script.iter()
+    }
+
+    /// Pops a word off the front of the script without recording the read.
+    ///
+    /// The caller is expected to make sure that the script contains at least a full word.
+    fn pop_script_word(&mut self) -> Word {
+        core::array::from_fn(|_| self.script.pop_front().expect("missing script value"))
+    }
+}
+
+#[cfg(any(test, feature = "internals"))]
+impl AdviceProvider for ScriptedAdviceProvider {
+    // ADVICE STACK
+    // --------------------------------------------------------------------------------------------
+
+    fn pop_stack(&mut self) -> Result<Felt, ExecutionError> {
+        let value = self
+            .script
+            .pop_front()
+            .ok_or(ExecutionError::AdviceStackReadFailed(self.step))?;
+        self.read_log.push(AdviceRead::Element(value));
+        Ok(value)
+    }
+
+    fn pop_stack_word(&mut self) -> Result<Word, ExecutionError> {
+        if self.script.len() < WORD_SIZE {
+            return Err(ExecutionError::AdviceStackReadFailed(self.step));
+        }
+
+        let word = self.pop_script_word();
+        self.read_log.push(AdviceRead::Word(word));
+        Ok(word)
+    }
+
+    fn pop_stack_dword(&mut self) -> Result<[Word; 2], ExecutionError> {
+        if self.script.len() < 2 * WORD_SIZE {
+            return Err(ExecutionError::AdviceStackReadFailed(self.step));
+        }
+
+        let dword = [self.pop_script_word(), self.pop_script_word()];
+        self.read_log.push(AdviceRead::DoubleWord(dword));
+        Ok(dword)
+    }
+
+    fn advice_stack_len(&self) -> usize {
+        self.script.len()
+    }
+
+    fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
+        match source {
+            AdviceSource::Value(value) => {
+                self.script.push_front(value);
+                Ok(())
+            }
+
+            AdviceSource::Map { key, include_len } => {
+                let values = self
+                    .map
+                    .get(&key.into_bytes())
+                    .ok_or(ExecutionError::AdviceKeyNotFound(key))?;
+
+                values.iter().rev().for_each(|value| self.script.push_front(*value));
+                if include_len {
+                    self.script.push_front(Felt::from(values.len() as u64));
+                }
+                Ok(())
+            }
+
+            AdviceSource::MapWord { key } => {
+                let values = self
+                    .map
+                    .get(&key.into_bytes())
+                    .ok_or(ExecutionError::AdviceKeyNotFound(key))?;
+                if values.len() != WORD_SIZE {
+                    return Err(ExecutionError::ExpectedWordLength(key, values.len()));
+                }
+
+                values.iter().rev().for_each(|value| self.script.push_front(*value));
+                Ok(())
+            }
+        }
+    }
+
+    fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
+        self.map.insert(key.into_bytes(), values);
+        Ok(())
+    }
+
+    // ADVISE SETS
+    // --------------------------------------------------------------------------------------------
+
+    fn get_tree_node(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<Word, ExecutionError> {
+        get_store_node(&self.store, root, depth, index)
+    }
+
+    fn get_merkle_path(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<MerklePath, ExecutionError> {
+        get_store_path(&self.store, root, depth, index)
+    }
+
+    fn get_leaf_depth(
+        &self,
+        root: Word,
+        tree_depth: &Felt,
+        index: &Felt,
+    ) -> Result<u8, ExecutionError> {
+        get_store_leaf_depth(&self.store, root, tree_depth, index)
+    }
+
+    fn update_merkle_node(
+        &mut self,
+        _root: Word,
+        _depth: &Felt,
+        _index: &Felt,
+        _value: Word,
+    ) -> Result<MerklePath, ExecutionError> {
+        Err(ExecutionError::AdviceProviderReadOnly("update_merkle_node"))
+    }
+
+    fn rollback_merkle_update(&mut self, _new_root: Word) -> Result<Word, ExecutionError> {
+        Err(ExecutionError::AdviceProviderReadOnly("rollback_merkle_update"))
+    }
+
+    fn merge_roots(&mut self, _lhs: Word, _rhs: Word) -> Result<Word, ExecutionError> {
+        Err(ExecutionError::AdviceProviderReadOnly("merge_roots"))
+    }
+
+    // CONTEXT MANAGEMENT
+    // --------------------------------------------------------------------------------------------
+
+    fn advance_clock(&mut self) {
+        self.step += 1;
+    }
+}
+
+// REPLAY ADVICE PROVIDER
+// ================================================================================================
+
+/// An [AdviceProvider] implementation which wraps a [MemAdviceProvider] and records every read
+/// from the advice stack together with the clock cycle at which it occurred, intended for
+/// debugging diverging executions.
+///
+/// Recording the same program executed against two sets of advice inputs produces two read logs
+/// which can be compared via [ReplayAdviceProvider::first_divergent_read()] to find the first read
+/// at which the executions diverged. Failed reads are not recorded.
+#[cfg(any(test, feature = "internals"))]
+#[derive(Debug, Clone, Default)]
+pub struct ReplayAdviceProvider {
+    step: u32,
+    provider: MemAdviceProvider,
+    read_log: Vec<(u32, AdviceRead)>,
+}
+
+#[cfg(any(test, feature = "internals"))]
+impl ReplayAdviceProvider {
+    /// Returns a new [ReplayAdviceProvider] which serves advice from the specified provider.
+    pub fn new(provider: MemAdviceProvider) -> Self {
+        Self {
+            provider,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the reads from the advice stack performed so far, in the order in which they
+    /// occurred, each paired with the clock cycle at which it was performed.
+    pub fn read_log(&self) -> &[(u32, AdviceRead)] {
+        &self.read_log
+    }
+
+    /// Returns the index (into the read log) of the first read at which this provider and the
+    /// `other` provider diverged, or None if one read log is a prefix of the other.
+    ///
+    /// Two reads diverge if they were performed at different clock cycles or returned different
+    /// values.
+    pub fn first_divergent_read(&self, other: &Self) -> Option<usize> {
+        self.read_log
+            .iter()
+            .zip(other.read_log.iter())
+            .position(|(lhs, rhs)| lhs != rhs)
+    }
+
+    /// Returns the underlying provider.
+    pub fn into_inner(self) -> MemAdviceProvider {
+        self.provider
+    }
+}
+
+#[cfg(any(test, feature = "internals"))]
+impl From<AdviceInputs> for ReplayAdviceProvider {
+    fn from(inputs: AdviceInputs) -> Self {
+        Self::new(inputs.into())
+    }
+}
+
+#[cfg(any(test, feature = "internals"))]
+impl AdviceProvider for ReplayAdviceProvider {
+    // ADVICE STACK
+    // --------------------------------------------------------------------------------------------
+
+    fn pop_stack(&mut self) -> Result<Felt, ExecutionError> {
+        let value = self.provider.pop_stack()?;
+        self.read_log.push((self.step, AdviceRead::Element(value)));
+        Ok(value)
+    }
+
+    fn pop_stack_word(&mut self) -> Result<Word, ExecutionError> {
+        let word = self.provider.pop_stack_word()?;
+        self.read_log.push((self.step, AdviceRead::Word(word)));
+        Ok(word)
+    }
+
+    fn pop_stack_dword(&mut self) -> Result<[Word; 2], ExecutionError> {
+        let dword = self.provider.pop_stack_dword()?;
+        self.read_log.push((self.step, AdviceRead::DoubleWord(dword)));
+        Ok(dword)
+    }
+
+    fn advice_stack_len(&self) -> usize {
+        self.provider.advice_stack_len()
+    }
+
+    fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
+        self.provider.push_stack(source)
+    }
+
+    fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
+        self.provider.insert_into_map(key, values)
+    }
+
+    // ADVISE SETS
+    // --------------------------------------------------------------------------------------------
+
+    fn get_tree_node(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<Word, ExecutionError> {
+        self.provider.get_tree_node(root, depth, index)
+    }
+
+    fn get_merkle_path(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<MerklePath, ExecutionError> {
+        self.provider.get_merkle_path(root, depth, index)
+    }
+
+    fn get_leaf_depth(
+        &self,
+        root: Word,
+        tree_depth: &Felt,
+        index: &Felt,
+    ) -> Result<u8, ExecutionError> {
+        self.provider.get_leaf_depth(root, tree_depth, index)
+    }
+
+    fn update_merkle_node(
+        &mut self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+        value: Word,
+    ) -> Result<MerklePath, ExecutionError> {
+        self.provider.update_merkle_node(root, depth, index, value)
+    }
+
+    fn rollback_merkle_update(&mut self, new_root: Word) -> Result<Word, ExecutionError> {
+        self.provider.rollback_merkle_update(new_root)
+    }
+
+    fn merge_roots(&mut self, lhs: Word, rhs: Word) -> Result<Word, ExecutionError> {
+        self.provider.merge_roots(lhs, rhs)
+    }
+
+    // CONTEXT MANAGEMENT
+    // --------------------------------------------------------------------------------------------
+
+    fn advance_clock(&mut self) {
+        self.step += 1;
+        self.provider.advance_clock();
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the node at the specified depth and index of the tree with the specified root in the
+/// provided Merkle store.
+fn get_store_node<S>(
+    store: &MerkleStore<S>,
+    root: Word,
+    depth: &Felt,
+    index: &Felt,
+) -> Result<Word, ExecutionError>
+where
+    S: KvMap<RpoDigest, StoreNode>,
+{
+    let index = get_node_index(depth, index)?;
+    store
+        .get_node(root.into(), index)
+        .map(|v| v.into())
+        .map_err(ExecutionError::MerkleStoreLookupFailed)
+}
+
+/// Returns the path to the node at the specified depth and index of the tree with the specified
+/// root in the provided Merkle store.
+fn get_store_path<S>(
+    store: &MerkleStore<S>,
+    root: Word,
+    depth: &Felt,
+    index: &Felt,
+) -> Result<MerklePath, ExecutionError>
+where
+    S: KvMap<RpoDigest, StoreNode>,
+{
+    let index = get_node_index(depth, index)?;
+    store
+        .get_path(root.into(), index)
+        .map(|value| value.path)
+        .map_err(ExecutionError::MerkleStoreLookupFailed)
+}
+
+/// Returns the depth of the leaf at the specified index of the tree with the specified root and
+/// depth in the provided Merkle store.
+fn get_store_leaf_depth<S>(
+    store: &MerkleStore<S>,
+    root: Word,
+    tree_depth: &Felt,
+    index: &Felt,
+) -> Result<u8, ExecutionError>
+where
+    S: KvMap<RpoDigest, StoreNode>,
+{
+    let tree_depth = u8::try_from(tree_depth.as_int())
+        .map_err(|_| ExecutionError::InvalidTreeDepth { depth: *tree_depth })?;
+    store
+        .get_leaf_depth(root.into(), tree_depth, index.as_int())
+        .map_err(ExecutionError::MerkleStoreLookupFailed)
+}
+
+/// Returns the node index defined by the specified depth and index.
+fn get_node_index(depth: &Felt, index: &Felt) -> Result<NodeIndex, ExecutionError> {
+    NodeIndex::from_elements(depth, index).map_err(|_| ExecutionError::InvalidTreeNodeIndex {
+        depth: *depth,
+        value: *index,
+    })
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AdviceInputs, AdviceProvider, AdviceRead, AdviceSource, ExecutionError, Felt, IntoBytes,
+        MemAdviceProvider, ReplayAdviceProvider, ScriptedAdviceProvider, SliceAdviceProvider, Word,
+    };
+    use crate::{Process, StackInputs};
+    use miden_assembly::Assembler;
+    use vm_core::{
+        crypto::merkle::{MerkleStore, MerkleTree, NodeIndex},
+        utils::collections::Vec,
+        FieldElement, Kernel,
+    };
+
+    #[test]
+    fn advice_stack_len() {
+        let inputs = AdviceInputs::default().with_stack_values(1..=10).unwrap();
+        let mut provider = MemAdviceProvider::from(inputs);
+        assert_eq!(10, provider.advice_stack_len());
+
+        provider.pop_stack().unwrap();
+        assert_eq!(9, provider.advice_stack_len());
+
+        provider.pop_stack_word().unwrap();
+        assert_eq!(5, provider.advice_stack_len());
+
+        provider.push_stack(AdviceSource::Value(Felt::new(11))).unwrap();
+        assert_eq!(6, provider.advice_stack_len());
+
+        provider.pop_stack_word().unwrap();
+        assert_eq!(2, provider.advice_stack_len());
+
+        // a failed read does not consume any elements
+        provider.pop_stack_word().unwrap_err();
+        assert_eq!(2, provider.advice_stack_len());
+
+        provider.pop_stack().unwrap();
+        provider.pop_stack().unwrap();
+        assert_eq!(0, provider.advice_stack_len());
+        assert!(provider.pop_stack().is_err());
+    }
+
+    #[test]
+    fn stack_capacity() {
+        let leaves = init_leaves(&(0..256).collect::<Vec<_>>());
+        let tree = MerkleTree::new(leaves.clone()).unwrap();
+        let map = leaves.iter().map(|leaf| (leaf.into_bytes(), leaf.to_vec())).collect::<Vec<_>>();
+        let inputs = AdviceInputs::default()
+            .with_stack_values(0..10_000)
+            .unwrap()
+            .with_map(map)
+            .with_merkle_store(MerkleStore::from(&tree));
+
+        let hinted = inputs.clone().with_stack_capacity(20_000);
+        assert_eq!(20_000, hinted.stack_capacity());
+
+        let mut provider = MemAdviceProvider::from(inputs);
+        let mut hinted_provider = MemAdviceProvider::from(hinted);
+        assert!(hinted_provider.provider.stack.capacity() >= 20_000);
+
+        // the hint does not affect the results of any queries
+        assert_eq!(provider.stack(), hinted_provider.stack());
+        assert_eq!(provider.map(), hinted_provider.map());
+
+        let root: Word = tree.root().into();
+        let depth = Felt::new(tree.depth() as u64);
+        for (i, leaf) in leaves.iter().enumerate() {
+            let index = Felt::new(i as u64);
+            assert_eq!(*leaf, hinted_provider.get_tree_node(root, &depth, &index).unwrap());
+            assert_eq!(
+                provider.get_merkle_path(root, &depth, &index).unwrap(),
+                hinted_provider.get_merkle_path(root, &depth, &index).unwrap()
+            );
+        }
+
+        while provider.advice_stack_len() > 0 {
+            assert_eq!(provider.pop_stack().unwrap(), hinted_provider.pop_stack().unwrap());
+        }
+        assert!(hinted_provider.pop_stack().is_err());
+    }
+
+    #[test]
+    fn rollback_merkle_update() {
+        let leaves = init_leaves(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        let tree = MerkleTree::new(leaves.clone()).unwrap();
+        let store = MerkleStore::from(&tree);
+        let inputs = AdviceInputs::default().with_merkle_store(store);
+        let mut provider = MemAdviceProvider::from(inputs);
+
+        let root: Word = tree.root().into();
+        let depth = Felt::new(tree.depth() as u64);
+        let index = Felt::new(5);
+        let new_value = [Felt::new(9), Felt::ZERO, Felt::ZERO, Felt::ZERO];
+
+        // update the node and make sure the new tree contains the updated value
+        provider.update_merkle_node(root, &depth, &index, new_value).unwrap();
+        let mut new_leaves = leaves.clone();
+        new_leaves[5] = new_value;
+        let new_tree = MerkleTree::new(new_leaves).unwrap();
+        let new_root: Word = new_tree.root().into();
+        assert_eq!(new_value, provider.get_tree_node(new_root, &depth, &index).unwrap());
+
+        // rolling back the update returns the original root and the pre-update node value
+        let old_root = provider.rollback_merkle_update(new_root).unwrap();
+        assert_eq!(root, old_root);
+        assert_eq!(leaves[5], provider.get_tree_node(old_root, &depth, &index).unwrap());
+
+        // the same update cannot be rolled back twice
+        assert!(matches!(
+            provider.rollback_merkle_update(new_root),
+            Err(ExecutionError::MerkleUpdateNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn push_map_word() {
+        let keys = init_leaves(&[1, 2, 3]);
+        let word = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+        let inputs = AdviceInputs::default().with_map([
+            (keys[0].into_bytes(), word.to_vec()),
+            (keys[1].into_bytes(), vec![Felt::new(9), Felt::new(10)]),
+        ]);
+        let mut provider = MemAdviceProvider::from(inputs);
+
+        // an entry with exactly four elements is pushed as a word
+        provider.push_stack(AdviceSource::MapWord { key: keys[0] }).unwrap();
+        assert_eq!(word, provider.pop_stack_word().unwrap());
+        assert!(provider.pop_stack().is_err());
+
+        // an entry of any other length is rejected and the advice stack is not modified
+        assert!(matches!(
+            provider.push_stack(AdviceSource::MapWord { key: keys[1] }),
+            Err(ExecutionError::ExpectedWordLength(_, 2))
+        ));
+        assert!(provider.pop_stack().is_err());
+
+        assert!(matches!(
+            provider.push_stack(AdviceSource::MapWord { key: keys[2] }),
+            Err(ExecutionError::AdviceKeyNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn slice_provider_reads() {
+        let leaves = init_leaves(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        let tree = MerkleTree::new(leaves.clone()).unwrap();
+        let store: MerkleStore = MerkleStore::from(&tree);
+
+        // build the advice map from a single value arena
+        let keys = init_leaves(&[10, 20, 30]);
+        let arena = (1..=6).map(Felt::new).collect::<Vec<_>>();
+        let mut index = vec![
+            (keys[0].into_bytes(), 0..2),
+            (keys[1].into_bytes(), 2..2),
+            (keys[2].into_bytes(), 2..6),
+        ];
+        index.sort_by_key(|e| e.0);
+
+        let stack = [Felt::new(100), Felt::new(101)];
+        let mut provider = SliceAdviceProvider::new(&stack, &index, &arena, &store).unwrap();
+
+        // advice stack values are popped in the order they were provided
+        assert_eq!(Felt::new(100), provider.pop_stack().unwrap());
+
+        // map values are pushed onto the advice stack
+        provider.push_stack(map_source(keys[2], true)).unwrap();
+        assert_eq!(Felt::new(4), provider.pop_stack().unwrap());
+        let word = provider.pop_stack_word().unwrap();
+        assert_eq!([arena[2], arena[3], arena[4], arena[5]], word);
+
+        provider.push_stack(map_source(keys[0], false)).unwrap();
+        assert_eq!(arena[0], provider.pop_stack().unwrap());
+        assert_eq!(arena[1], provider.pop_stack().unwrap());
+
+        provider.push_stack(map_source(keys[1], true)).unwrap();
+        assert_eq!(Felt::ZERO, provider.pop_stack().unwrap());
+        assert_eq!(Felt::new(101), provider.pop_stack().unwrap());
+        assert_eq!(0, provider.advice_stack_len());
+        assert!(provider.pop_stack().is_err());
+
+        let missing = init_leaves(&[40])[0];
+        assert!(matches!(
+            provider.push_stack(map_source(missing, false)),
+            Err(ExecutionError::AdviceKeyNotFound(_))
+        ));
+
+        // Merkle nodes and paths are served from the store
+        let root: Word = tree.root().into();
+        let depth = Felt::new(tree.depth() as u64);
+        for (i, leaf) in leaves.iter().enumerate() {
+            let index = Felt::new(i as u64);
+            assert_eq!(*leaf, provider.get_tree_node(root, &depth, &index).unwrap());
+            let path = tree.get_path(NodeIndex::new(3, i as u64).unwrap()).unwrap();
+            assert_eq!(path, provider.get_merkle_path(root, &depth, &index).unwrap());
+        }
+
+        // mutating calls are rejected
+        assert!(matches!(
+            provider.update_merkle_node(root, &depth, &Felt::ZERO, leaves[1]),
+            Err(ExecutionError::AdviceProviderReadOnly(_))
+        ));
+        assert!(matches!(
+            provider.insert_into_map(missing, Vec::new()),
+            Err(ExecutionError::AdviceProviderReadOnly(_))
+        ));
+        assert!(matches!(
+            provider.merge_roots(root, root),
+            Err(ExecutionError::AdviceProviderReadOnly(_))
+        ));
+
+        // words can span values pushed during execution and values of the borrowed stack
+        let stack = [Felt::new(100), Felt::new(101), Felt::new(102)];
+        let mut provider = SliceAdviceProvider::new(&stack, &index, &arena, &store).unwrap();
+        provider.push_stack(AdviceSource::Value(Felt::new(99))).unwrap();
+        assert_eq!(4, provider.advice_stack_len());
+        let word = provider.pop_stack_word().unwrap();
+        assert_eq!([Felt::new(99), Felt::new(100), Felt::new(101), Felt::new(102)], word);
+        assert!(provider.pop_stack_word().is_err());
+    }
+
+    #[test]
+    fn slice_provider_invalid_index() {
+        let store: MerkleStore = MerkleStore::new();
+        let arena = [Felt::new(1), Felt::new(2)];
+        let stack = [];
+
+        // value range is out of bounds of the arena
+        let index = [([0; 32], 0..3)];
+        assert!(SliceAdviceProvider::new(&stack, &index, &arena, &store).is_err());
+
+        // keys are not sorted
+        let index = [([1; 32], 0..1), ([0; 32], 1..2)];
+        assert!(SliceAdviceProvider::new(&stack, &index, &arena, &store).is_err());
+    }
+
+    #[test]
+    fn scripted_provider_reads() {
+        let script = (1..=14).map(Felt::new).collect::<Vec<_>>();
+        let mut provider = ScriptedAdviceProvider::new(script.clone());
+        assert_eq!(14, provider.advice_stack_len());
+
+        // the script is served front to back
+        assert_eq!(script[0], provider.pop_stack().unwrap());
+        let word = provider.pop_stack_word().unwrap();
+        assert_eq!([script[1], script[2], script[3], script[4]], word);
+
+        // values pushed onto the advice stack are read before the rest of the script
+        provider.push_stack(AdviceSource::Value(Felt::new(100))).unwrap();
+        assert_eq!(Felt::new(100), provider.pop_stack().unwrap());
+
+        let dword = provider.pop_stack_dword().unwrap();
+        assert_eq!([script[5], script[6], script[7], script[8]], dword[0]);
+        assert_eq!([script[9], script[10], script[11], script[12]], dword[1]);
+
+        // a failed read neither consumes the script nor gets recorded
+        assert!(provider.pop_stack_word().is_err());
+        assert_eq!(vec![&script[13]], provider.remaining_script().collect::<Vec<_>>());
+
+        let expected = [
+            AdviceRead::Element(script[0]),
+            AdviceRead::Word(word),
+            AdviceRead::Element(Felt::new(100)),
+            AdviceRead::DoubleWord(dword),
+        ];
+        assert_eq!(&expected, provider.read_log());
+
+        // map entries are pushed so that the first value ends up on top of the advice stack
+        let key = init_leaves(&[1])[0];
+        provider.insert_into_map(key, vec![Felt::new(7), Felt::new(8)]).unwrap();
+        provider.push_stack(map_source(key, true)).unwrap();
+        assert_eq!(Felt::new(2), provider.pop_stack().unwrap());
+        assert_eq!(Felt::new(7), provider.pop_stack().unwrap());
+        assert_eq!(Felt::new(8), provider.pop_stack().unwrap());
+        assert_eq!(script[13], provider.pop_stack().unwrap());
+        assert!(provider.pop_stack().is_err());
+    }
+
+    #[test]
+    fn replay_provider_first_divergent_read() {
+        let source = "
+            begin
+                adv_push.2 add
+                repeat.3 adv_push.1 mul end
+                adv_loadw
+            end";
+        let program = Assembler::default().compile(source).unwrap();
+
+        let run = |advice_stack: [u64; 9]| {
+            let advice_inputs = AdviceInputs::default().with_stack_values(advice_stack).unwrap();
+            let advice_provider = ReplayAdviceProvider::from(advice_inputs);
+            let mut process =
+                Process::new(Kernel::default(), StackInputs::default(), advice_provider);
+            process.execute(&program).unwrap();
+            process.advice_provider
+        };
+
+        // identical runs do not diverge
+        let expected = run([1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(6, expected.read_log().len());
+        assert_eq!(None, expected.first_divergent_read(&run([1, 2, 3, 4, 5, 6, 7, 8, 9])));
+
+        // the runs diverge at the second read performed by the loop
+        let actual = run([1, 2, 3, 10, 5, 6, 7, 8, 9]);
+        let idx = expected.first_divergent_read(&actual).unwrap();
+        assert_eq!(3, idx);
+        assert_eq!(expected.read_log()[..idx], actual.read_log()[..idx]);
+
+        // the divergent reads happen at the same step, but return different values
+        let (expected_step, expected_read) = expected.read_log()[idx];
+        let (actual_step, actual_read) = actual.read_log()[idx];
+        assert_eq!(expected_step, actual_step);
+        assert!(expected_step > expected.read_log()[idx - 1].0);
+        assert_eq!(AdviceRead::Element(Felt::new(4)), expected_read);
+        assert_eq!(AdviceRead::Element(Felt::new(10)), actual_read);
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn map_source(key: Word, include_len: bool) -> AdviceSource {
+        AdviceSource::Map { key, include_len }
+    }
+
+    fn init_leaves(values: &[u64]) -> Vec<Word> {
+        values
+            .iter()
+            .map(|&v| [Felt::new(v), Felt::ZERO, Felt::ZERO, Felt::ZERO])
+            .collect()
+    }
+}
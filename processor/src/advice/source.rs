@@ -30,4 +30,25 @@ pub enum AdviceSource {
     /// # Errors
     /// Returns an error if the key was not found in the key-value map.
     Map { key: Word, include_len: bool },
+
+    /// Fetches a word (4 elements) under the specified key from the advice map and pushes it onto
+    /// the advice stack.
+    ///
+    /// The elements are pushed in the same order as for [AdviceSource::Map], and thus, the word
+    /// can then be popped off the advice stack as a whole.
+    ///
+    /// Note: this operation doesn't consume the map element so it can be called multiple times
+    /// for the same key.
+    ///
+    /// # Example
+    /// Given an advice stack `[a, b, c, ...]`, and a map `x |-> [d, e, f, g]`:
+    ///
+    /// A call `push_stack(AdviceSource::MapWord { key: x })` will result in advice stack:
+    /// `[d, e, f, g, a, b, c, ...]`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The key was not found in the key-value map.
+    /// - The value under the key does not consist of exactly 4 elements.
+    MapWord { key: Word },
 }
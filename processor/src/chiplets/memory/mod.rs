@@ -71,7 +71,7 @@ const INIT_MEM_VALUE: Word = [ZERO; 4];
 ///   clock cycles computed as described above.
 ///
 /// For the first row of the trace, values in `d0`, `d1`, and `d_inv` are set to zeros.
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct Memory {
     /// Memory segment traces sorted by their execution context ID.
     trace: BTreeMap<u32, MemorySegmentTrace>,
@@ -124,6 +124,28 @@ impl Memory {
         }
     }
 
+    /// Returns true if the specified address in the specified context has been written to at
+    /// least once. Addresses which have only been read are not considered written.
+    pub fn is_written(&self, ctx: u32, addr: u64) -> bool {
+        match self.trace.get(&ctx) {
+            Some(segment) => segment.is_written(addr),
+            None => false,
+        }
+    }
+
+    /// Returns a sorted list of addresses which have been written to at least once in any of the
+    /// execution contexts. Addresses which have only been read are not included.
+    pub fn written_addresses(&self) -> Vec<u64> {
+        let mut result = self
+            .trace
+            .values()
+            .flat_map(|segment| segment.written_addresses())
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        result.dedup();
+        result
+    }
+
     // STATE ACCESSORS AND MUTATORS
     // --------------------------------------------------------------------------------------------
 
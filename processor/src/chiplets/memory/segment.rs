@@ -13,7 +13,7 @@ use super::{BTreeMap, Felt, StarkField, Vec, Word, INIT_MEM_VALUE};
 /// A memory segment is an isolated address space accessible from a specific execution context.
 /// Within each segment, the memory is word-addressable. That is, four field elements are located
 /// at each memory address, and we can read and write elements to/from memory in batches of four.
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct MemorySegmentTrace(BTreeMap<u64, Vec<MemorySegmentAccess>>);
 
 impl MemorySegmentTrace {
@@ -62,6 +62,22 @@ impl MemorySegmentTrace {
         result
     }
 
+    /// Returns true if the specified address has been written to at least once.
+    pub fn is_written(&self, addr: u64) -> bool {
+        self.0
+            .get(&addr)
+            .map_or(false, |addr_trace| addr_trace.iter().any(MemorySegmentAccess::is_write))
+    }
+
+    /// Returns an iterator over the addresses which have been written to at least once, in
+    /// ascending order.
+    pub fn written_addresses(&self) -> impl Iterator<Item = u64> + '_ {
+        self.0
+            .iter()
+            .filter(|(_, addr_trace)| addr_trace.iter().any(MemorySegmentAccess::is_write))
+            .map(|(&addr, _)| addr)
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -172,6 +188,11 @@ impl MemorySegmentAccess {
         }
     }
 
+    /// Returns true if this memory access is a write.
+    pub(super) fn is_write(&self) -> bool {
+        self.op == MemoryOperation::Write
+    }
+
     /// Returns the word value for this memory access.
     pub(super) fn value(&self) -> Word {
         self.value
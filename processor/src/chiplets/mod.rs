@@ -17,7 +17,8 @@ pub use hasher::init_state_from_words;
 use hasher::Hasher;
 
 mod memory;
-use memory::{Memory, MemoryLookup};
+pub(crate) use memory::Memory;
+use memory::MemoryLookup;
 
 mod kernel_rom;
 use kernel_rom::{KernelProcLookup, KernelRom};
@@ -437,6 +438,16 @@ impl Chiplets {
         self.memory.get_value(ctx, addr as u64)
     }
 
+    /// Returns a reference to the memory chiplet.
+    pub(crate) fn memory(&self) -> &Memory {
+        &self.memory
+    }
+
+    /// Replaces the state of the memory chiplet with the provided one.
+    pub(crate) fn set_memory(&mut self, memory: Memory) {
+        self.memory = memory;
+    }
+
     /// Returns the entire memory state for the specified execution context at the specified cycle.
     /// The state is returned as a vector of (address, value) tuples, and includes addresses which
     /// have been accessed at least once.
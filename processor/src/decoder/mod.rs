@@ -1,7 +1,7 @@
 use super::{
     AdviceProvider, Call, ColMatrix, ExecutionError, Felt, FieldElement, Join, Loop, OpBatch,
-    Operation, Process, Span, Split, StarkField, Vec, Word, MIN_TRACE_LEN, ONE, OP_BATCH_SIZE,
-    ZERO,
+    Operation, Process, Span, Split, StarkField, Vec, Word, MAX_CONTEXT_DEPTH, MIN_TRACE_LEN, ONE,
+    OP_BATCH_SIZE, ZERO,
 };
 use miden_air::trace::{
     chiplets::hasher::DIGEST_LEN,
@@ -170,6 +170,13 @@ where
 
     /// Starts decoding of a CALL or a SYSCALL block.
     pub(super) fn start_call_block(&mut self, block: &Call) -> Result<(), ExecutionError> {
+        // make sure the new execution context does not exceed the maximum nesting depth
+        if self.system.ctx_depth() >= MAX_CONTEXT_DEPTH {
+            return Err(ExecutionError::CallDepthExceeded {
+                max: MAX_CONTEXT_DEPTH,
+            });
+        }
+
         // use the hasher to compute the hash of the CALL or SYSCALL block; the row address
         // returned by the hasher is used as the ID of the block; the result of the hash is
         // expected to be in row addr + 7.
@@ -86,10 +86,16 @@ where
         let end_addr = self.stack.get(end_idx).as_int();
 
         if start_addr > u32::MAX as u64 {
-            return Err(ExecutionError::MemoryAddressOutOfBounds(start_addr));
+            return Err(ExecutionError::MemoryAddressOutOfBounds {
+                addr: start_addr,
+                max: u32::MAX as u64,
+            });
         }
         if end_addr > u32::MAX as u64 {
-            return Err(ExecutionError::MemoryAddressOutOfBounds(end_addr));
+            return Err(ExecutionError::MemoryAddressOutOfBounds {
+                addr: end_addr,
+                max: u32::MAX as u64,
+            });
         }
 
         if start_addr > end_addr {
@@ -15,6 +15,12 @@ type QuadFelt = QuadExtension<Felt>;
 /// Maximum depth of a Sparse Merkle Tree
 const SMT_MAX_TREE_DEPTH: Felt = Felt::new(64);
 
+/// Limbs of the modulus of the Curve25519 base field, p = 2^255 - 19, with the least significant
+/// limb first.
+const CURVE25519_MODULUS: [u32; 8] = [
+    0xffffffed, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0x7fffffff,
+];
+
 /// Lookup table for Sparse Merkle Tree depth normalization
 const SMT_NORMALIZED_DEPTHS: [u8; 65] = [
     16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 32, 32, 32, 32, 32, 32, 32,
@@ -186,6 +192,45 @@ where
         Ok(())
     }
 
+    /// Given an element of the prime field of order 2^255 - 19 on the top of the stack, computes
+    /// its multiplicative inverse and pushes the result onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [b7, b6, b5, b4, b3, b2, b1, b0, ...]
+    ///
+    /// Where (a0, ..., a7) and (b0, ..., b7) are the 32-bit limbs of the input element and of its
+    /// inverse respectively, with a0 and b0 representing the 32 least significant bits.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the input limbs is not a u32 value.
+    /// - The input element is zero modulo 2^255 - 19.
+    pub(super) fn push_curve25519_inv_result(&mut self) -> Result<(), ExecutionError> {
+        let mut element = [0u32; 8];
+        for (i, limb) in element.iter_mut().enumerate() {
+            let value = self.stack.get(i);
+            *limb =
+                u32::try_from(value.as_int()).map_err(|_| ExecutionError::NotU32Value(value))?;
+        }
+
+        let element = curve25519_reduce(element);
+        if element == [0; 8] {
+            return Err(ExecutionError::DivideByZero(self.system.clk()));
+        }
+        let result = curve25519_inv(&element);
+
+        for limb in result {
+            self.advice_provider.push_stack(AdviceSource::Value(Felt::from(limb)))?;
+        }
+
+        Ok(())
+    }
+
     /// Given evaluations of a polynomial over some specified domain, interpolates the evaluations
     ///  into a polynomial in coefficient form and pushes the result into the advice stack.
     ///
@@ -350,3 +395,70 @@ fn u64_to_u32_elements(value: u64) -> (Felt, Felt) {
     let lo = Felt::new((value as u32) as u64);
     (hi, lo)
 }
+
+/// Computes a * b modulo 2^255 - 19. Inputs and the result are 256-bit values which are not
+/// necessarily fully reduced.
+fn curve25519_mul(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    // compute the 512-bit product
+    let mut product = [0u32; 16];
+    for i in 0..8 {
+        let mut carry = 0u64;
+        for j in 0..8 {
+            let t = a[i] as u64 * b[j] as u64 + product[i + j] as u64 + carry;
+            product[i + j] = t as u32;
+            carry = t >> 32;
+        }
+        product[i + 8] = carry as u32;
+    }
+
+    // reduce the product using the fact that 2^256 = 38 (mod p)
+    let mut result = [0u32; 8];
+    let mut carry = 0u64;
+    for i in 0..8 {
+        let t = product[i] as u64 + product[i + 8] as u64 * 38 + carry;
+        result[i] = t as u32;
+        carry = t >> 32;
+    }
+    while carry != 0 {
+        carry *= 38;
+        for limb in result.iter_mut() {
+            let t = *limb as u64 + carry;
+            *limb = t as u32;
+            carry = t >> 32;
+        }
+    }
+
+    result
+}
+
+/// Computes the multiplicative inverse of a non-zero element modulo 2^255 - 19 as a^(p - 2). The
+/// result is fully reduced.
+fn curve25519_inv(a: &[u32; 8]) -> [u32; 8] {
+    let mut exponent = CURVE25519_MODULUS;
+    exponent[0] -= 2;
+
+    let mut result = [1, 0, 0, 0, 0, 0, 0, 0];
+    for i in (0..256).rev() {
+        result = curve25519_mul(&result, &result);
+        if (exponent[i / 32] >> (i % 32)) & 1 == 1 {
+            result = curve25519_mul(&result, a);
+        }
+    }
+
+    curve25519_reduce(result)
+}
+
+/// Reduces a 256-bit value modulo 2^255 - 19.
+fn curve25519_reduce(mut a: [u32; 8]) -> [u32; 8] {
+    // since a < 2^256 < 3p, at most two subtractions are needed
+    while a.iter().rev().cmp(CURVE25519_MODULUS.iter().rev()).is_ge() {
+        let mut borrow = false;
+        for (limb, m) in a.iter_mut().zip(CURVE25519_MODULUS) {
+            let (t, b1) = limb.overflowing_sub(m);
+            let (t, b2) = t.overflowing_sub(borrow as u32);
+            *limb = t;
+            borrow = b1 || b2;
+        }
+    }
+    a
+}
@@ -25,6 +25,7 @@ where
                     self.decoder.append_asmop(self.system.clk(), assembly_op.clone());
                 }
             }
+            Decorator::Event(event_id) => self.emitted_events.push(*event_id),
         }
         Ok(())
     }
@@ -44,6 +45,7 @@ where
             AdviceInjector::DivU64 => self.push_u64_div_result(),
             AdviceInjector::Ext2Inv => self.push_ext2_inv_result(),
             AdviceInjector::Ext2Intt => self.push_ext2_intt_result(),
+            AdviceInjector::Curve25519Inv => self.push_curve25519_inv_result(),
             AdviceInjector::SmtGet => self.push_smtget_inputs(),
             AdviceInjector::MemToMap => self.insert_mem_values_into_adv_map(),
             AdviceInjector::HdwordToMap { domain } => self.insert_hdword_into_adv_map(*domain),
@@ -3,6 +3,7 @@ use super::{
     Process,
 };
 use crate::{MemAdviceProvider, StackInputs, Word};
+use miden_assembly::Assembler;
 use test_utils::{crypto::get_smt_remaining_key, rand::seeded_word};
 use vm_core::{
     crypto::{
@@ -143,6 +144,100 @@ fn push_smtget() {
     }
 }
 
+#[test]
+fn push_curve25519_inv() {
+    // the inverse of 2 modulo 2^255 - 19 is (p + 1) / 2 = 2^254 - 9
+    let stack_inputs = StackInputs::try_from_values([2]).unwrap();
+    let advice_provider = MemAdviceProvider::default();
+    let mut process = Process::new(Kernel::default(), stack_inputs, advice_provider);
+    process.execute_op(Operation::Noop).unwrap();
+
+    // push the inverse onto the advice stack
+    process
+        .execute_decorator(&Decorator::Advice(AdviceInjector::Curve25519Inv))
+        .unwrap();
+
+    // pop the limbs of the inverse from the advice stack and push them onto the operand stack
+    for _ in 0..8 {
+        process.execute_op(Operation::AdvPop).unwrap();
+    }
+
+    let mut expected = vec![Felt::new(0xfffffff7)];
+    expected.extend([Felt::new(u32::MAX as u64); 6]);
+    expected.push(Felt::new(0x3fffffff));
+    expected.push(Felt::new(2));
+    assert_eq!(build_expected(&expected), process.stack.trace_state());
+
+    // zero has no inverse
+    let stack_inputs = StackInputs::try_from_values([0]).unwrap();
+    let advice_provider = MemAdviceProvider::default();
+    let mut process = Process::new(Kernel::default(), stack_inputs, advice_provider);
+    process.execute_op(Operation::Noop).unwrap();
+    assert!(process
+        .execute_decorator(&Decorator::Advice(AdviceInjector::Curve25519Inv))
+        .is_err());
+}
+
+#[test]
+fn emit_events() {
+    let source = "
+        proc.foo
+            emit.5
+            mul.3
+        end
+        begin
+            emit.7
+            push.1
+            add
+            exec.foo
+            emit.3
+            emit.7
+        end";
+    let program = Assembler::default().compile(source).unwrap();
+    let stack_inputs = StackInputs::try_from_values([2]).unwrap();
+    let mut process =
+        Process::new(Kernel::default(), stack_inputs.clone(), MemAdviceProvider::default());
+    let outputs = process.execute(&program).unwrap();
+
+    // events are recorded in the order they were emitted
+    assert_eq!(&[7, 5, 3, 7], process.emitted_events());
+
+    // the stack is the same as for the program without any events
+    let source = "
+        proc.foo
+            mul.3
+        end
+        begin
+            push.1
+            add
+            exec.foo
+        end";
+    let program = Assembler::default().compile(source).unwrap();
+    let mut process = Process::new(Kernel::default(), stack_inputs, MemAdviceProvider::default());
+    let expected = process.execute(&program).unwrap();
+
+    assert!(process.emitted_events().is_empty());
+    assert_eq!(expected.stack(), outputs.stack());
+}
+
+#[test]
+fn emit_advances_one_cycle() {
+    // both spans consist of a single operation group, and thus, each emitted event costs exactly
+    // one cycle
+    let program = Assembler::default().compile("begin swap dup add end").unwrap();
+    let mut process =
+        Process::new(Kernel::default(), StackInputs::default(), MemAdviceProvider::default());
+    process.execute(&program).unwrap();
+    let cycle_count = process.cycle_count();
+
+    let source = "begin emit.1 swap emit.2 dup add emit.3 end";
+    let program = Assembler::default().compile(source).unwrap();
+    let mut process =
+        Process::new(Kernel::default(), StackInputs::default(), MemAdviceProvider::default());
+    process.execute(&program).unwrap();
+    assert_eq!(cycle_count + 3, process.cycle_count());
+}
+
 // HELPER FUNCTIONS
 // --------------------------------------------------------------------------------------------
 
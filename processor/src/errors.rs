@@ -16,10 +16,13 @@ use std::error::Error;
 #[derive(Debug)]
 pub enum ExecutionError {
     AdviceKeyNotFound(Word),
+    AdviceProviderReadOnly(&'static str),
     AdviceStackReadFailed(u32),
+    CallDepthExceeded { max: usize },
     CallerNotInSyscall,
     CodeBlockNotFound(Digest),
     DivideByZero(u32),
+    ExpectedWordLength(Word, usize),
     Ext2InttError(Ext2InttError),
     FailedAssertion(u32),
     InvalidFmpValue(Felt, Felt),
@@ -30,15 +33,18 @@ pub enum ExecutionError {
     InvalidStackWordOffset(usize),
     InvalidTreeDepth { depth: Felt },
     InvalidTreeNodeIndex { depth: Felt, value: Felt },
-    MemoryAddressOutOfBounds(u64),
+    MemoryAddressOutOfBounds { addr: u64, max: u64 },
     MerkleStoreMergeFailed(MerkleError),
     MerkleStoreLookupFailed(MerkleError),
     MerkleStoreUpdateFailed(MerkleError),
+    MerkleUpdateNotFound(Word),
     NotBinaryValue(Felt),
     NotU32Value(Felt),
     ProverError(ProverError),
     SyscallTargetNotInKernel(Digest),
     UnexecutableCodeBlock(CodeBlock),
+    UninitializedMemory { addr: u64 },
+    WriteToReadonly { addr: u64 },
 }
 
 impl Display for ExecutionError {
@@ -50,7 +56,13 @@ impl Display for ExecutionError {
                 let hex = to_hex(Felt::elements_as_bytes(key))?;
                 write!(f, "Can't push values onto the advice stack: value for key {hex} not present in the advice map.")
             }
+            AdviceProviderReadOnly(method) => {
+                write!(f, "Advice provider is read-only and does not support {method}")
+            }
             AdviceStackReadFailed(step) => write!(f, "Advice stack read failed at step {step}"),
+            CallDepthExceeded { max } => {
+                write!(f, "Number of nested calls and syscalls cannot exceed {max}")
+            }
             CallerNotInSyscall => {
                 write!(f, "Instruction `caller` used outside of kernel context")
             }
@@ -62,6 +74,10 @@ impl Display for ExecutionError {
                 )
             }
             DivideByZero(clk) => write!(f, "Division by zero at clock cycle {clk}"),
+            ExpectedWordLength(key, len) => {
+                let hex = to_hex(Felt::elements_as_bytes(key))?;
+                write!(f, "Expected a word under key {hex} in the advice map, but the value has {len} elements")
+            }
             Ext2InttError(err) => write!(f, "Failed to execute Ext2Intt operation: {err}"),
             FailedAssertion(clk) => write!(f, "Assertion failed at clock cycle {clk}"),
             InvalidFmpValue(old, new) => {
@@ -91,8 +107,8 @@ impl Display for ExecutionError {
             InvalidTreeNodeIndex { depth, value } => {
                 write!(f, "The provided index {value} is out of bounds for a node at depth {depth}")
             }
-            MemoryAddressOutOfBounds(addr) => {
-                write!(f, "Memory address cannot exceed 2^32 but was {addr}")
+            MemoryAddressOutOfBounds { addr, max } => {
+                write!(f, "Memory address cannot exceed {max} but was {addr}")
             }
             MerkleStoreLookupFailed(reason) => {
                 write!(f, "Advice provider Merkle store backend lookup failed: {reason}")
@@ -103,6 +119,10 @@ impl Display for ExecutionError {
             MerkleStoreUpdateFailed(reason) => {
                 write!(f, "Advice provider Merkle store backend update failed: {reason}")
             }
+            MerkleUpdateNotFound(root) => {
+                let hex = to_hex(Felt::elements_as_bytes(root))?;
+                write!(f, "Can't roll back Merkle store update: no update producing root {hex} was recorded by the advice provider")
+            }
             NotBinaryValue(v) => {
                 write!(f, "An operation expected a binary value, but received {v}")
             }
@@ -117,6 +137,12 @@ impl Display for ExecutionError {
             UnexecutableCodeBlock(block) => {
                 write!(f, "Execution reached unexecutable code block {block:?}")
             }
+            UninitializedMemory { addr } => {
+                write!(f, "Memory address {addr} was read before being written to")
+            }
+            WriteToReadonly { addr } => {
+                write!(f, "Memory address {addr} is read-only and cannot be written to")
+            }
         }
     }
 }
@@ -1,7 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-#[cfg(not(feature = "std"))]
-#[macro_use]
+#[cfg_attr(not(feature = "std"), macro_use)]
 extern crate alloc;
 
 use miden_air::trace::{
@@ -9,8 +8,9 @@ use miden_air::trace::{
     SYS_TRACE_WIDTH,
 };
 pub use vm_core::{
-    chiplets::hasher::Digest, errors::InputError, utils::DeserializationError, AssemblyOp, Kernel,
-    Operation, Program, ProgramInfo, QuadExtension, StackInputs, StackOutputs, Word,
+    chiplets::hasher::Digest, code_blocks, errors::InputError, utils::DeserializationError,
+    AssemblyOp, Kernel, OpCode, Operation, Program, ProgramInfo, QuadExtension, StackInputs,
+    StackOutputs, Word,
 };
 use vm_core::{
     code_blocks::{
@@ -21,6 +21,7 @@ use vm_core::{
     StackTopState, StarkField, ONE, ZERO,
 };
 
+use core::ops::Range;
 use winter_prover::ColMatrix;
 
 mod decorators;
@@ -28,7 +29,7 @@ mod operations;
 
 mod system;
 use system::System;
-pub use system::{FMP_MIN, SYSCALL_FMP_MIN};
+pub use system::{FMP_MIN, MAX_CONTEXT_DEPTH, SYSCALL_FMP_MIN};
 
 mod decoder;
 use decoder::Decoder;
@@ -41,8 +42,11 @@ use range::RangeChecker;
 
 mod advice;
 pub use advice::{
-    AdviceInputs, AdviceProvider, AdviceSource, MemAdviceProvider, RecAdviceProvider,
+    AdviceDiff, AdviceInputs, AdviceProvider, AdviceSource, MemAdviceProvider, RecAdviceProvider,
+    SliceAdviceProvider,
 };
+#[cfg(any(test, feature = "internals"))]
+pub use advice::{AdviceRead, ReplayAdviceProvider, ScriptedAdviceProvider};
 
 mod chiplets;
 use chiplets::Chiplets;
@@ -59,6 +63,9 @@ pub mod utils;
 mod debug;
 pub use debug::{AsmOpInfo, VmState, VmStateIterator};
 
+#[cfg(test)]
+mod tests;
+
 // RE-EXPORTS
 // ================================================================================================
 
@@ -71,8 +78,11 @@ pub mod crypto {
     pub use vm_core::crypto::{
         hash::{Blake3_192, Blake3_256, ElementHasher, Hasher, Rpo256, RpoDigest},
         merkle::{MerkleError, MerklePath, MerkleStore, MerkleTree, SimpleSmt},
-        random::{RandomCoin, RpoRandomCoin, WinterRandomCoin},
+        random::{Challenge, RandomCoin, RpoRandomCoin, TranscriptRandomCoin, WinterRandomCoin},
     };
+
+    #[cfg(feature = "std")]
+    pub use vm_core::crypto::random::{record_challenges, RecordingRandomCoin};
 }
 
 // TYPE ALIASES
@@ -122,6 +132,28 @@ where
     Ok(trace)
 }
 
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs, with the trace padded to at least `min_trace_len` rows.
+///
+/// The trace is padded in the same way as the trace returned by [execute()], and its length is
+/// rounded up to the next power of two. Forcing a longer trace does not affect the outputs of the
+/// program, but increases the size of the domain over which the trace is proven.
+pub fn execute_with_min_trace_len<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    min_trace_len: usize,
+) -> Result<ExecutionTrace, ExecutionError>
+where
+    A: AdviceProvider,
+{
+    let mut process = Process::new(program.kernel().clone(), stack_inputs, advice_provider);
+    let stack_outputs = process.execute(program)?;
+    let trace = ExecutionTrace::with_min_len(process, stack_outputs, min_trace_len);
+    assert_eq!(&program.hash(), trace.program_hash(), "inconsistent program hash");
+    Ok(trace)
+}
+
 /// Returns an iterator which allows callers to step through the execution and inspect VM state at
 /// each execution step.
 pub fn execute_iter<A>(
@@ -158,6 +190,11 @@ where
     range: RangeChecker,
     chiplets: Chiplets,
     advice_provider: A,
+    emitted_events: Vec<u32>,
+    strict_memory: bool,
+    readonly_regions: Vec<Range<u64>>,
+    max_memory_addr: Option<u64>,
+    op_cycle_counts: Option<BTreeMap<OpCode, u64>>,
 }
 
 impl<A> Process<A>
@@ -189,9 +226,48 @@ where
             range: RangeChecker::new(),
             chiplets: Chiplets::new(kernel),
             advice_provider,
+            emitted_events: Vec::new(),
+            strict_memory: false,
+            readonly_regions: Vec::new(),
+            max_memory_addr: None,
+            op_cycle_counts: None,
         }
     }
 
+    /// Sets whether memory reads in this process should fail on addresses which have never been
+    /// written to.
+    ///
+    /// By default, memory is assumed to be initialized to ZEROs, and reading an address which
+    /// hasn't been written to returns four ZERO elements. When strict memory mode is enabled,
+    /// such reads (performed by `mem_load`, `mem_loadw`, `loc_load`, and `loc_loadw`
+    /// instructions) return [ExecutionError::UninitializedMemory] instead.
+    pub fn with_strict_memory(mut self, strict: bool) -> Self {
+        self.strict_memory = strict;
+        self
+    }
+
+    /// Marks `len` memory addresses starting at `start` as read-only for this process.
+    ///
+    /// Writes into a read-only region (performed by `mem_store`, `mem_storew`, `loc_store`,
+    /// `loc_storew`, and `adv_pipe` instructions) return [ExecutionError::WriteToReadonly] instead
+    /// of updating the memory. The region applies to all execution contexts. This method can be called
+    /// multiple times to protect several regions.
+    pub fn with_readonly_region(mut self, start: u64, len: u64) -> Self {
+        self.readonly_regions.push(start..start.saturating_add(len));
+        self
+    }
+
+    /// Sets the largest memory address which can be accessed by this process.
+    ///
+    /// Memory operations (including `loc_*` instructions, `mem_stream`, and `adv_pipe`) which
+    /// target an address greater than `max_addr` return [ExecutionError::MemoryAddressOutOfBounds]
+    /// instead of accessing the memory. The bound applies to all execution contexts. By default,
+    /// memory addresses are not bounded.
+    pub fn with_max_memory(mut self, max_addr: u64) -> Self {
+        self.max_memory_addr = Some(max_addr);
+        self
+    }
+
     // PROGRAM EXECUTOR
     // --------------------------------------------------------------------------------------------
 
@@ -203,6 +279,21 @@ where
         Ok(self.stack.build_stack_outputs())
     }
 
+    /// Executes the provided [Program] in this process, continuing from the state left by the
+    /// previously executed programs.
+    ///
+    /// Unlike [Process::execute()], the process is not required to be fresh: the program starts
+    /// executing at the current clock cycle, against the current memory, stack, and advice
+    /// provider state. Thus, several programs can be executed back to back with all of them
+    /// sharing the same memory and advice state. Syscalls in the program are resolved against the
+    /// kernel of this process.
+    ///
+    /// Stack outputs are not built by this method; they can be read from the stack after the last
+    /// program has been executed.
+    pub fn execute_continued(&mut self, program: &Program) -> Result<(), ExecutionError> {
+        self.execute_code_block(program.root(), program.cb_table())
+    }
+
     // CODE BLOCK EXECUTORS
     // --------------------------------------------------------------------------------------------
 
@@ -444,6 +535,11 @@ where
         self.chiplets.get_mem_value(ctx, addr)
     }
 
+    /// Returns the ids of the events emitted during execution, in the order they were emitted.
+    pub fn emitted_events(&self) -> &[u32] {
+        &self.emitted_events
+    }
+
     pub fn into_parts(self) -> (System, Decoder, Stack, RangeChecker, Chiplets, A) {
         (
             self.system,
@@ -470,4 +566,95 @@ where
     pub range: RangeChecker,
     pub chiplets: Chiplets,
     pub advice_provider: A,
+    pub emitted_events: Vec<u32>,
+    pub strict_memory: bool,
+    pub readonly_regions: Vec<Range<u64>>,
+    pub max_memory_addr: Option<u64>,
+    pub op_cycle_counts: Option<BTreeMap<OpCode, u64>>,
+}
+
+#[cfg(any(test, feature = "internals"))]
+impl<A> Process<A>
+where
+    A: AdviceProvider,
+{
+    /// Enables cycle profiling for this process.
+    ///
+    /// When profiling is enabled, the process keeps track of how many cycles were spent executing
+    /// each operation. The counts can be retrieved via [Process::op_cycle_counts()] once the
+    /// program has been executed.
+    pub fn with_profiler(mut self) -> Self {
+        self.op_cycle_counts = Some(BTreeMap::new());
+        self
+    }
+
+    /// Returns the number of cycles (i.e., VM steps) executed by this process so far.
+    pub fn cycle_count(&self) -> u64 {
+        self.system.clk() as u64
+    }
+
+    /// Returns the number of cycles spent executing each operation, keyed by the operation's
+    /// [OpCode] (immediate values are ignored, so all PUSH operations share one entry),
+    /// or None if profiling was not enabled via [Process::with_profiler()].
+    ///
+    /// Cycles spent on control flow (e.g., starting and ending code blocks) are attributed to the
+    /// NOOP or DROP operations executed by the stack during these cycles.
+    pub fn op_cycle_counts(&self) -> Option<&BTreeMap<OpCode, u64>> {
+        self.op_cycle_counts.as_ref()
+    }
+
+    /// Returns a sorted list of memory addresses which have been written to at least once during
+    /// execution, across all execution contexts.
+    ///
+    /// This complements [Process::get_memory_value()] by enumerating the addresses which are worth
+    /// inspecting; addresses which have only been read are not included.
+    pub fn written_addresses(&self) -> Vec<u64> {
+        self.chiplets.memory().written_addresses()
+    }
+}
+
+#[cfg(any(test, feature = "internals"))]
+impl<A> Process<A>
+where
+    A: AdviceProvider + Clone,
+{
+    /// Returns a snapshot of the clock cycle, memory, stack, and advice provider state of this
+    /// process.
+    ///
+    /// The snapshot can be used to fork execution: after trying out a branch of execution, the
+    /// process can be reverted to the captured state via [Process::restore()].
+    pub fn snapshot(&self) -> ProcessSnapshot<A> {
+        ProcessSnapshot {
+            clk: self.system.clk(),
+            memory: self.chiplets.memory().clone(),
+            stack: self.stack.clone(),
+            advice_provider: self.advice_provider.clone(),
+        }
+    }
+
+    /// Reverts the clock cycle, memory, stack, and advice provider of this process to the state
+    /// captured in the provided snapshot.
+    ///
+    /// Other components of the process (e.g., the system and decoder traces, and the remaining
+    /// chiplets) are not reverted, and thus, still contain the rows recorded after the snapshot was
+    /// taken. A restored process can be used to continue exploring execution outcomes, but it must
+    /// not be used to build an execution trace.
+    pub fn restore(&mut self, snapshot: ProcessSnapshot<A>) {
+        self.system.set_clk(snapshot.clk);
+        self.chiplets.set_memory(snapshot.memory);
+        self.stack = snapshot.stack;
+        self.advice_provider = snapshot.advice_provider;
+    }
+}
+
+/// State of a [Process] captured via [Process::snapshot()].
+#[cfg(any(test, feature = "internals"))]
+pub struct ProcessSnapshot<A>
+where
+    A: AdviceProvider,
+{
+    clk: u32,
+    memory: chiplets::Memory,
+    stack: Stack,
+    advice_provider: A,
 }
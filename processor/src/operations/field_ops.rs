@@ -1,5 +1,5 @@
 use super::{utils::assert_binary, AdviceProvider, ExecutionError, Felt, FieldElement, Process};
-use vm_core::{Operation, StarkField, ZERO};
+use vm_core::{Operation, StarkField, ONE, ZERO};
 
 // FIELD OPERATIONS
 // ================================================================================================
@@ -190,7 +190,7 @@ where
         let bit = b.as_int() & 1;
 
         // value which would be incorporated in the accumulator.
-        let value = Felt::new((exp.as_int() - 1) * bit + 1);
+        let value = (exp - ONE) * Felt::new(bit) + ONE;
 
         // current value of acc after including the value based on whether the bit is
         // 1 or not.
@@ -222,7 +222,7 @@ where
 mod tests {
     use super::{
         super::{Felt, FieldElement, Operation, StarkField, STACK_TOP_SIZE},
-        Process,
+        ExecutionError, Process,
     };
     use crate::{AdviceInputs, StackInputs};
     use rand_utils::rand_value;
@@ -365,12 +365,18 @@ mod tests {
         // --- first operand is not binary ------------------------------------
         let stack = StackInputs::try_from_values([2, 1, 2]).unwrap();
         let mut process = Process::new_dummy(stack);
-        assert!(process.execute_op(Operation::And).is_err());
+        assert!(matches!(
+            process.execute_op(Operation::And),
+            Err(ExecutionError::NotBinaryValue(value)) if value == Felt::new(2)
+        ));
 
         // --- second operand is not binary -----------------------------------
         let stack = StackInputs::try_from_values([2, 2, 1]).unwrap();
         let mut process = Process::new_dummy(stack);
-        assert!(process.execute_op(Operation::And).is_err());
+        assert!(matches!(
+            process.execute_op(Operation::And),
+            Err(ExecutionError::NotBinaryValue(value)) if value == Felt::new(2)
+        ));
 
         // --- calling AND with a stack of minimum depth is ok ----------------
         let mut process = Process::new_dummy_with_empty_stack();
@@ -414,12 +420,18 @@ mod tests {
         // --- first operand is not binary ------------------------------------
         let stack = StackInputs::try_from_values([2, 1, 2]).unwrap();
         let mut process = Process::new_dummy(stack);
-        assert!(process.execute_op(Operation::Or).is_err());
+        assert!(matches!(
+            process.execute_op(Operation::Or),
+            Err(ExecutionError::NotBinaryValue(value)) if value == Felt::new(2)
+        ));
 
         // --- second operand is not binary -----------------------------------
         let stack = StackInputs::try_from_values([2, 2, 1]).unwrap();
         let mut process = Process::new_dummy(stack);
-        assert!(process.execute_op(Operation::Or).is_err());
+        assert!(matches!(
+            process.execute_op(Operation::Or),
+            Err(ExecutionError::NotBinaryValue(value)) if value == Felt::new(2)
+        ));
 
         // --- calling OR with a stack of minimum depth is a ok ----------------
         let mut process = Process::new_dummy_with_empty_stack();
@@ -445,7 +457,10 @@ mod tests {
         // --- operand is not binary ------------------------------------------
         let stack = StackInputs::try_from_values([2, 2]).unwrap();
         let mut process = Process::new_dummy(stack);
-        assert!(process.execute_op(Operation::Not).is_err());
+        assert!(matches!(
+            process.execute_op(Operation::Not),
+            Err(ExecutionError::NotBinaryValue(value)) if value == Felt::new(2)
+        ));
     }
 
     // COMPARISON OPERATIONS
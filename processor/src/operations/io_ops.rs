@@ -1,4 +1,5 @@
-use super::{AdviceProvider, ExecutionError, Felt, Operation, Process};
+use super::{AdviceProvider, ExecutionError, Felt, Operation, Process, StarkField};
+use vm_core::ONE;
 
 // CONSTANTS
 // ================================================================================================
@@ -33,7 +34,7 @@ where
     /// - The memory address is popped off the stack.
     /// - A word is retrieved from memory at the specified address. The memory is always
     ///   initialized to ZEROs, and thus, if the specified address has never been written to,
-    ///   four ZERO elements are returned.
+    ///   four ZERO elements are returned (unless strict memory mode is enabled).
     /// - The top four elements of the stack are overwritten with values retrieved from memory.
     ///
     /// Thus, the net result of the operation is that the stack is shifted left by one item.
@@ -41,6 +42,8 @@ where
         // get the address from the stack and read the word from current memory context
         let ctx = self.system.ctx();
         let addr = self.stack.get(0);
+        self.check_mem_in_bounds(addr)?;
+        self.check_mem_initialized(ctx, addr)?;
         let word = self.chiplets.read_mem(ctx, addr);
 
         // reverse the order of the memory word & update the stack state
@@ -58,7 +61,7 @@ where
     /// - The memory address is popped off the stack.
     /// - A word is retrieved from memory at the specified address. The memory is always
     ///   initialized to ZEROs, and thus, if the specified address has never been written to,
-    ///   four ZERO elements are returned.
+    ///   four ZERO elements are returned (unless strict memory mode is enabled).
     /// - The first element of the word retrieved from memory is pushed to the top of the stack.
     ///
     /// The first 3 helper registers are filled with the elements of the word which were not pushed
@@ -68,6 +71,8 @@ where
         // get the address from the stack and read the word from memory
         let ctx = self.system.ctx();
         let addr = self.stack.get(0);
+        self.check_mem_in_bounds(addr)?;
+        self.check_mem_initialized(ctx, addr)?;
         let mut word = self.chiplets.read_mem(ctx, addr);
         // put the retrieved word into stack order
         word.reverse();
@@ -96,6 +101,8 @@ where
         // get the address from position 12 on the stack
         let ctx = self.system.ctx();
         let addr = self.stack.get(12);
+        self.check_mem_in_bounds(addr)?;
+        self.check_mem_in_bounds(addr + ONE)?;
 
         // load two words from memory
         let words = self.chiplets.read_mem_double(ctx, addr);
@@ -132,6 +139,8 @@ where
         // get the address from the stack and build the word to be saved from the stack values
         let ctx = self.system.ctx();
         let addr = self.stack.get(0);
+        self.check_mem_in_bounds(addr)?;
+        self.check_mem_writable(addr)?;
 
         // build the word in memory order (reverse of stack order)
         let word = [self.stack.get(4), self.stack.get(3), self.stack.get(2), self.stack.get(1)];
@@ -166,6 +175,8 @@ where
         let ctx = self.system.ctx();
         let addr = self.stack.get(0);
         let value = self.stack.get(1);
+        self.check_mem_in_bounds(addr)?;
+        self.check_mem_writable(addr)?;
 
         // write the value to the memory and get the previous word
         let mut old_word = self.chiplets.write_mem_element(ctx, addr, value);
@@ -195,6 +206,10 @@ where
         // get the address from position 12 on the stack
         let ctx = self.system.ctx();
         let addr = self.stack.get(12);
+        self.check_mem_in_bounds(addr)?;
+        self.check_mem_in_bounds(addr + ONE)?;
+        self.check_mem_writable(addr)?;
+        self.check_mem_writable(addr + ONE)?;
 
         // pop two words from the advice stack
         let words = self.advice_provider.pop_stack_dword()?;
@@ -252,6 +267,51 @@ where
 
         Ok(())
     }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Makes sure that the specified address does not exceed the largest memory address which can
+    /// be accessed by this process.
+    ///
+    /// # Errors
+    /// Returns an error if the address is greater than the maximum memory address of this process.
+    fn check_mem_in_bounds(&self, addr: Felt) -> Result<(), ExecutionError> {
+        let addr = addr.as_int();
+        match self.max_memory_addr {
+            Some(max) if addr > max => Err(ExecutionError::MemoryAddressOutOfBounds { addr, max }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Makes sure that the specified address in the specified context has been written to before,
+    /// if strict memory mode is enabled for this process.
+    ///
+    /// # Errors
+    /// Returns an error if strict memory mode is enabled and the address has never been written
+    /// to. Addresses which have only been read (e.g., via `MSTREAM` or while strict memory mode
+    /// was disabled) are still considered uninitialized.
+    fn check_mem_initialized(&self, ctx: u32, addr: Felt) -> Result<(), ExecutionError> {
+        if self.strict_memory && !self.chiplets.memory().is_written(ctx, addr.as_int()) {
+            return Err(ExecutionError::UninitializedMemory {
+                addr: addr.as_int(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Makes sure that the specified address does not belong to any of the read-only memory
+    /// regions of this process.
+    ///
+    /// # Errors
+    /// Returns an error if the address is within a read-only region.
+    fn check_mem_writable(&self, addr: Felt) -> Result<(), ExecutionError> {
+        let addr = addr.as_int();
+        if self.readonly_regions.iter().any(|region| region.contains(&addr)) {
+            return Err(ExecutionError::WriteToReadonly { addr });
+        }
+        Ok(())
+    }
 }
 
 // TESTS
@@ -261,10 +321,11 @@ where
 mod tests {
     use super::{
         super::{Operation, STACK_TOP_SIZE},
-        AdviceProvider, Felt, Process,
+        AdviceProvider, ExecutionError, Felt, Process,
     };
-    use crate::AdviceSource;
-    use vm_core::{utils::ToElements, Word, ONE, ZERO};
+    use crate::{AdviceSource, MemAdviceProvider, StackInputs, FMP_MIN};
+    use miden_assembly::Assembler;
+    use vm_core::{utils::ToElements, Kernel, Word, ONE, ZERO};
 
     #[test]
     fn op_push() {
@@ -352,6 +413,96 @@ mod tests {
         assert!(process.execute_op(Operation::MLoad).is_ok());
     }
 
+    #[test]
+    fn mem_load_strict_memory() {
+        let word = [1, 3, 5, 7].to_elements().try_into().unwrap();
+
+        // --- strict mode fails on unwritten addresses -----------------------------------------
+        for op in [Operation::MLoad, Operation::MLoadW] {
+            let mut process =
+                Process::new_dummy_with_decoder_helpers_and_empty_stack().with_strict_memory(true);
+            process.execute_op(Operation::Push(Felt::new(3))).unwrap();
+            assert!(matches!(
+                process.execute_op(op),
+                Err(ExecutionError::UninitializedMemory { addr: 3 })
+            ));
+        }
+
+        // --- strict mode returns written values -----------------------------------------------
+        let mut process =
+            Process::new_dummy_with_decoder_helpers_and_empty_stack().with_strict_memory(true);
+        store_value(&mut process, 1, word);
+        process.execute_op(Operation::Push(ONE)).unwrap();
+        process.execute_op(Operation::MLoad).unwrap();
+        process.execute_op(Operation::Push(ONE)).unwrap();
+        process.execute_op(Operation::MLoadW).unwrap();
+
+        let expected_stack = build_expected_stack(&[7, 5, 3, 1, 1]);
+        assert_eq!(expected_stack, process.stack.trace_state());
+
+        // --- lenient mode returns written values and zeros for unwritten addresses ------------
+        let mut process = Process::new_dummy_with_decoder_helpers_and_empty_stack();
+        store_value(&mut process, 1, word);
+        process.execute_op(Operation::Push(ONE)).unwrap();
+        process.execute_op(Operation::MLoad).unwrap();
+        process.execute_op(Operation::Push(Felt::new(3))).unwrap();
+        process.execute_op(Operation::MLoadW).unwrap();
+
+        let expected_stack = build_expected_stack(&[0, 0, 0, 0, 1]);
+        assert_eq!(expected_stack, process.stack.trace_state());
+    }
+
+    #[test]
+    fn loc_load_strict_memory() {
+        let source = "
+            proc.foo.1
+                loc_load.0
+            end
+            proc.bar.1
+                push.9 loc_store.0 loc_load.0
+            end
+            begin
+                exec.foo exec.bar add
+            end";
+        let program = Assembler::default().compile(source).unwrap();
+
+        // in strict mode, reading a local which has never been written to fails
+        let mut process =
+            Process::new(Kernel::default(), StackInputs::default(), MemAdviceProvider::default())
+                .with_strict_memory(true);
+        let err = process.execute(&program).unwrap_err();
+        assert!(matches!(err, ExecutionError::UninitializedMemory { .. }));
+
+        // in lenient mode, the unwritten local is read as zero
+        let mut process =
+            Process::new(Kernel::default(), StackInputs::default(), MemAdviceProvider::default());
+        let outputs = process.execute(&program).unwrap();
+        assert_eq!(&[9, 0, 0, 0], &outputs.stack()[..4]);
+    }
+
+    #[test]
+    fn strict_memory_read_only_addresses() {
+        // MSTREAM reads addresses 7 and 8 without writing to them
+        let program = Assembler::default()
+            .compile("begin push.7 padw padw padw mem_stream padw push.7 mem_loadw end");
+        let mut process =
+            Process::new(Kernel::default(), StackInputs::default(), MemAdviceProvider::default())
+                .with_strict_memory(true);
+        let err = process.execute(&program.unwrap()).unwrap_err();
+        assert!(matches!(err, ExecutionError::UninitializedMemory { addr: 7 }));
+
+        // an address read while strict memory mode was disabled is still uninitialized
+        let mut process =
+            Process::new(Kernel::default(), StackInputs::default(), MemAdviceProvider::default());
+        let program_a = Assembler::default().compile("begin mem_load.3 drop end");
+        process.execute(&program_a.unwrap()).unwrap();
+
+        process.strict_memory = true;
+        let program_b = Assembler::default().compile("begin mem_load.3 end");
+        let err = process.execute_continued(&program_b.unwrap()).unwrap_err();
+        assert!(matches!(err, ExecutionError::UninitializedMemory { addr: 3 }));
+    }
+
     #[test]
     fn op_mstream() {
         let mut process = Process::new_dummy_with_decoder_helpers_and_empty_stack();
@@ -474,6 +625,120 @@ mod tests {
         assert!(process.execute_op(Operation::MStore).is_ok());
     }
 
+    #[test]
+    fn mem_store_readonly_region() {
+        // --- writes inside the read-only region fail ------------------------------------------
+        for (addr, op) in [(4, Operation::MStore), (7, Operation::MStoreW)] {
+            let mut process = Process::new_dummy_with_decoder_helpers_and_empty_stack()
+                .with_readonly_region(4, 4);
+            process.execute_op(Operation::Push(Felt::new(5))).unwrap();
+            process.execute_op(Operation::Push(Felt::new(addr))).unwrap();
+            assert!(matches!(
+                process.execute_op(op),
+                Err(ExecutionError::WriteToReadonly { addr: a }) if a == addr
+            ));
+            assert_eq!(0, process.chiplets.get_mem_size());
+        }
+
+        // --- writes just outside the read-only region succeed ---------------------------------
+        let mut process =
+            Process::new_dummy_with_decoder_helpers_and_empty_stack().with_readonly_region(4, 4);
+        let word = [1, 3, 5, 7].to_elements().try_into().unwrap();
+        store_value(&mut process, 3, word);
+        store_element(&mut process, 8, Felt::new(9));
+
+        assert_eq!(2, process.chiplets.get_mem_size());
+        assert_eq!(word, process.chiplets.get_mem_value(0, 3).unwrap());
+        assert_eq!([Felt::new(9), ZERO, ZERO, ZERO], process.chiplets.get_mem_value(0, 8).unwrap());
+    }
+
+    #[test]
+    fn loc_store_readonly_region() {
+        let source = "
+            proc.foo.1
+                push.7 loc_store.0
+            end
+            begin
+                exec.foo
+            end";
+        let program = Assembler::default().compile(source).unwrap();
+
+        // locals of the first procedure are allocated at the first address above the initial fmp
+        let local_addr = FMP_MIN + 1;
+
+        // writing into a local inside the read-only region fails
+        let mut process =
+            Process::new(Kernel::default(), StackInputs::default(), MemAdviceProvider::default())
+                .with_readonly_region(local_addr, 1);
+        let err = process.execute(&program).unwrap_err();
+        assert!(matches!(err, ExecutionError::WriteToReadonly { addr } if addr == local_addr));
+
+        // writing into a local just outside the read-only region succeeds
+        let mut process =
+            Process::new(Kernel::default(), StackInputs::default(), MemAdviceProvider::default())
+                .with_readonly_region(local_addr + 1, 1);
+        assert!(process.execute(&program).is_ok());
+    }
+
+    #[test]
+    fn mem_access_out_of_bounds() {
+        // --- accesses beyond the bound fail -----------------------------------------------------
+        for op in [Operation::MStore, Operation::MStoreW, Operation::MLoad, Operation::MLoadW] {
+            let mut process =
+                Process::new_dummy_with_decoder_helpers_and_empty_stack().with_max_memory(7);
+            process.execute_op(Operation::Push(Felt::new(5))).unwrap();
+            process.execute_op(Operation::Push(Felt::new(8))).unwrap();
+            assert!(matches!(
+                process.execute_op(op),
+                Err(ExecutionError::MemoryAddressOutOfBounds { addr: 8, max: 7 })
+            ));
+            assert_eq!(0, process.chiplets.get_mem_size());
+        }
+
+        // --- accesses up to the bound succeed ---------------------------------------------------
+        let mut process =
+            Process::new_dummy_with_decoder_helpers_and_empty_stack().with_max_memory(7);
+        let word = [1, 3, 5, 7].to_elements().try_into().unwrap();
+        store_value(&mut process, 6, word);
+        store_element(&mut process, 7, Felt::new(9));
+
+        assert_eq!(2, process.chiplets.get_mem_size());
+        assert_eq!(word, process.chiplets.get_mem_value(0, 6).unwrap());
+        assert_eq!([Felt::new(9), ZERO, ZERO, ZERO], process.chiplets.get_mem_value(0, 7).unwrap());
+    }
+
+    #[test]
+    fn loc_store_out_of_bounds() {
+        let source = "
+            proc.foo.1
+                push.7 loc_store.0
+            end
+            begin
+                exec.foo
+            end";
+        let program = Assembler::default().compile(source).unwrap();
+
+        // locals of the first procedure are allocated at the first address above the initial fmp
+        let local_addr = FMP_MIN + 1;
+
+        // writing into a local above the maximum address fails
+        let mut process =
+            Process::new(Kernel::default(), StackInputs::default(), MemAdviceProvider::default())
+                .with_max_memory(local_addr - 1);
+        let err = process.execute(&program).unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionError::MemoryAddressOutOfBounds { addr, max }
+                if addr == local_addr && max == local_addr - 1
+        ));
+
+        // writing into a local at the maximum address succeeds
+        let mut process =
+            Process::new(Kernel::default(), StackInputs::default(), MemAdviceProvider::default())
+                .with_max_memory(local_addr);
+        assert!(process.execute(&program).is_ok());
+    }
+
     #[test]
     fn op_pipe() {
         let mut process = Process::new_dummy_with_decoder_helpers_and_empty_stack();
@@ -518,6 +783,46 @@ mod tests {
         assert_eq!(expected_stack, process.stack.trace_state());
     }
 
+    #[test]
+    fn op_pipe_readonly_region() {
+        // --- a write of either word into the read-only region fails ----------------------------
+        for addr in [3, 4] {
+            let mut process = Process::new_dummy_with_advice_stack(&[1, 2, 3, 4, 5, 6, 7, 8])
+                .with_readonly_region(4, 4);
+            process.execute_op(Operation::Push(Felt::new(addr))).unwrap();
+            for i in 1..13 {
+                process.execute_op(Operation::Push(Felt::new(i))).unwrap();
+            }
+            assert!(matches!(
+                process.execute_op(Operation::Pipe),
+                Err(ExecutionError::WriteToReadonly { addr: 4 })
+            ));
+            assert_eq!(0, process.chiplets.get_mem_size());
+            assert_eq!(8, process.advice_provider.advice_stack_len());
+        }
+    }
+
+    #[test]
+    fn execute_continued_shares_memory() {
+        let stack_inputs = StackInputs::try_from_values([2]).unwrap();
+        let mut process =
+            Process::new(Kernel::default(), stack_inputs, MemAdviceProvider::default());
+
+        // the first program writes values into memory
+        let program_a =
+            Assembler::default().compile("begin push.5 mem_store.0 push.7 mem_store.1 end");
+        process.execute(&program_a.unwrap()).unwrap();
+        let clk_a = process.system.clk();
+
+        // the second program reads the values written by the first one
+        let program_b = Assembler::default().compile("begin mem_load.0 mem_load.1 mul add end");
+        process.execute_continued(&program_b.unwrap()).unwrap();
+        assert!(process.system.clk() > clk_a);
+
+        let outputs = process.stack.build_stack_outputs();
+        assert_eq!(&[37, 0, 0, 0], &outputs.stack()[..4]);
+    }
+
     // ADVICE INPUT TESTS
     // --------------------------------------------------------------------------------------------
 
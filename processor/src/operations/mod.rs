@@ -1,4 +1,6 @@
-use super::{AdviceProvider, ExecutionError, Felt, FieldElement, Operation, Process, StarkField};
+use super::{
+    AdviceProvider, ExecutionError, Felt, FieldElement, OpCode, Operation, Process, StarkField,
+};
 use vm_core::stack::STACK_TOP_SIZE;
 
 mod crypto_ops;
@@ -149,6 +151,10 @@ where
             Operation::FriE2F4 => self.op_fri_ext2fold4()?,
         }
 
+        if let Some(op_cycle_counts) = self.op_cycle_counts.as_mut() {
+            *op_cycle_counts.entry(OpCode::from(*op)).or_default() += 1;
+        }
+
         self.advance_clock();
 
         Ok(())
@@ -19,6 +19,10 @@ pub const SYSCALL_FMP_MIN: u64 = 2_u64.pow(31);
 /// Value of FMP register should not exceed 3 * 2^30 - 1.
 pub const FMP_MAX: u64 = 3 * 2_u64.pow(30) - 1;
 
+/// Maximum number of nested execution contexts, i.e., CALLs and SYSCALLs which have been started
+/// but have not yet returned.
+pub const MAX_CONTEXT_DEPTH: usize = 128;
+
 // SYSTEM INFO
 // ================================================================================================
 
@@ -32,9 +36,12 @@ pub const FMP_MAX: u64 = 3 * 2_u64.pow(30) - 1;
 /// - in_syscall flag which indicates whether the execution is currently in a SYSCALL block.
 /// - hash of the function which initiated the current execution context. if the context was
 ///   initiated from the root context, this will be set to ZEROs.
+/// - context depth, which is the number of CALLs and SYSCALLs which have been started but have
+///   not yet returned. this is not a part of the execution trace.
 pub struct System {
     clk: u32,
     ctx: u32,
+    ctx_depth: usize,
     fmp: Felt,
     in_syscall: bool,
     fn_hash: Word,
@@ -60,6 +67,7 @@ impl System {
         Self {
             clk: 0,
             ctx: 0,
+            ctx_depth: 0,
             fmp,
             in_syscall: false,
             fn_hash: [ZERO; 4],
@@ -91,6 +99,12 @@ impl System {
         self.ctx
     }
 
+    /// Returns the number of CALLs and SYSCALLs which have been started but have not yet returned.
+    #[inline(always)]
+    pub fn ctx_depth(&self) -> usize {
+        self.ctx_depth
+    }
+
     /// Returns the current value of the free memory pointer for a process.
     #[inline(always)]
     pub fn fmp(&self) -> Felt {
@@ -131,6 +145,15 @@ impl System {
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
+    /// Sets the clock cycle to the specified value.
+    ///
+    /// This is used to revert a process to a previously captured state; the system trace is not
+    /// modified.
+    #[cfg(any(test, feature = "internals"))]
+    pub fn set_clk(&mut self, clk: u32) {
+        self.clk = clk;
+    }
+
     /// Increments the clock cycle.
     pub fn advance_clock(&mut self) {
         self.clk += 1;
@@ -167,6 +190,7 @@ impl System {
     pub fn start_call(&mut self, fn_hash: Word) {
         debug_assert!(!self.in_syscall, "call in syscall");
         self.ctx = self.clk + 1;
+        self.ctx_depth += 1;
         self.fmp = Felt::from(FMP_MIN);
         self.fn_hash = fn_hash;
     }
@@ -187,6 +211,7 @@ impl System {
     pub fn start_syscall(&mut self) {
         debug_assert!(!self.in_syscall, "already in syscall");
         self.ctx = 0;
+        self.ctx_depth += 1;
         self.fmp = Felt::from(SYSCALL_FMP_MIN);
         self.in_syscall = true;
     }
@@ -197,7 +222,9 @@ impl System {
     /// Note that we set in_syscall flag to true regardless of whether we return from a CALL or a
     /// SYSCALL.
     pub fn restore_context(&mut self, ctx: u32, fmp: Felt, fn_hash: Word) {
+        debug_assert!(self.ctx_depth > 0, "no context to restore");
         self.ctx = ctx;
+        self.ctx_depth -= 1;
         self.fmp = fmp;
         self.in_syscall = false;
         self.fn_hash = fn_hash;
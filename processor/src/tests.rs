@@ -0,0 +1,124 @@
+use super::{
+    AdviceInputs, AdviceProvider, Kernel, MemAdviceProvider, OpCode, Operation, Process,
+    StackInputs, Word, ONE, ZERO,
+};
+use miden_assembly::Assembler;
+use test_utils::assert_max_cycles;
+use vm_core::utils::ToElements;
+
+// PROCESS INTROSPECTION TESTS
+// ================================================================================================
+
+#[test]
+fn cycle_count() {
+    // the loop body is unrolled into a single span of 20 operations which are split into 3
+    // operation groups; thus, the span takes 23 cycles: SPAN, 20 operations, a NOOP to pad the
+    // number of groups to 4, and END
+    let program = Assembler::default().compile("begin repeat.10 dup drop end end").unwrap();
+    let mut process =
+        Process::new(Kernel::default(), StackInputs::default(), MemAdviceProvider::default());
+    process.execute(&program).unwrap();
+    assert_eq!(23, process.cycle_count());
+
+    assert_max_cycles(&program, StackInputs::default(), 23);
+}
+
+#[test]
+#[should_panic(expected = "exceeding the budget")]
+fn cycle_count_over_budget() {
+    let program = Assembler::default().compile("begin repeat.10 dup drop end end").unwrap();
+    assert_max_cycles(&program, StackInputs::default(), 22);
+}
+
+#[test]
+fn written_addresses() {
+    // address 7 is only read, and thus, it is not reported as written
+    let source = "
+        begin
+            push.1 mem_store.100
+            push.2.3.4.5 mem_storew.42 dropw
+            push.6 mem_store.5
+            mem_load.7 drop
+            push.7 mem_store.42
+        end";
+    let program = Assembler::default().compile(source).unwrap();
+    let mut process =
+        Process::new(Kernel::default(), StackInputs::default(), MemAdviceProvider::default());
+    process.execute(&program).unwrap();
+    assert_eq!(vec![5, 42, 100], process.written_addresses());
+}
+
+#[test]
+fn op_cycle_counts() {
+    let source = "begin push.3 push.2 add repeat.3 dup mul end end";
+    let program = Assembler::default().compile(source).unwrap();
+    let mut process =
+        Process::new(Kernel::default(), StackInputs::default(), MemAdviceProvider::default())
+            .with_profiler();
+    process.execute(&program).unwrap();
+
+    let op_cycle_counts = process.op_cycle_counts().unwrap();
+    assert_eq!(Some(&1), op_cycle_counts.get(&OpCode::from(Operation::Add)));
+    assert_eq!(Some(&3), op_cycle_counts.get(&OpCode::from(Operation::Dup0)));
+    assert_eq!(Some(&3), op_cycle_counts.get(&OpCode::from(Operation::Mul)));
+    assert_eq!(None, op_cycle_counts.get(&OpCode::from(Operation::Swap)));
+
+    // PUSH operations with different immediates share a single entry
+    assert_eq!(Some(&2), op_cycle_counts.get(&OpCode::from(Operation::Push(ZERO))));
+    assert_eq!("push", OpCode::from(Operation::Push(ONE)).to_string());
+
+    // every cycle is attributed to exactly one operation
+    assert_eq!(process.cycle_count(), op_cycle_counts.values().sum::<u64>());
+}
+
+#[test]
+fn op_cycle_counts_disabled_by_default() {
+    let program = Assembler::default().compile("begin push.1 push.2 add end").unwrap();
+    let mut process =
+        Process::new(Kernel::default(), StackInputs::default(), MemAdviceProvider::default());
+    process.execute(&program).unwrap();
+    assert!(process.op_cycle_counts().is_none());
+}
+
+// PROCESS SNAPSHOT TESTS
+// ================================================================================================
+
+#[test]
+fn snapshot_restore() {
+    let advice_inputs = AdviceInputs::default().with_stack_values([9]).unwrap();
+    let mut process = Process::new(
+        Kernel::default(),
+        StackInputs::default(),
+        MemAdviceProvider::from(advice_inputs),
+    );
+
+    let program_a = Assembler::default().compile("begin push.1.3.5.7 mem_storew.0 end");
+    process.execute(&program_a.unwrap()).unwrap();
+    let cycle_count = process.cycle_count();
+    let decoder_trace_len = process.decoder.trace_len();
+    let snapshot = process.snapshot();
+
+    // overwrite the word at address 0, write a new word at address 1, and consume the advice
+    let source = "begin push.2.4.6.8 mem_storew.0 mem_storew.1 adv_push.1 end";
+    let program_b = Assembler::default().compile(source);
+    process.execute_continued(&program_b.unwrap()).unwrap();
+    let word2: Word = [2, 4, 6, 8].to_elements().try_into().unwrap();
+    assert_eq!(Some(word2), process.get_memory_value(0, 0));
+    assert_eq!(0, process.advice_provider.advice_stack_len());
+    assert!(process.cycle_count() > cycle_count);
+
+    // after restoring, the clock, memory, stack, and advice provider are in the pre-mutation state
+    process.restore(snapshot);
+    assert_eq!(cycle_count, process.cycle_count());
+    let word1: Word = [1, 3, 5, 7].to_elements().try_into().unwrap();
+    assert_eq!(Some(word1), process.get_memory_value(0, 0));
+    assert_eq!(None, process.get_memory_value(0, 1));
+    assert_eq!(1, process.advice_provider.advice_stack_len());
+
+    let outputs = process.stack.build_stack_outputs();
+    assert_eq!(&[7, 5, 3, 1], &outputs.stack()[..4]);
+
+    // the decoder trace is not reverted, and thus, the restored process cannot be used to build an
+    // execution trace
+    assert!(process.decoder.trace_len() > decoder_trace_len);
+}
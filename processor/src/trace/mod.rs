@@ -67,6 +67,22 @@ impl ExecutionTrace {
     // --------------------------------------------------------------------------------------------
     /// Builds an execution trace for the provided process.
     pub(super) fn new<A>(process: Process<A>, stack_outputs: StackOutputs) -> Self
+    where
+        A: AdviceProvider,
+    {
+        Self::with_min_len(process, stack_outputs, MIN_TRACE_LEN)
+    }
+
+    /// Builds an execution trace for the provided process, padding the trace to at least
+    /// `min_trace_len` rows.
+    ///
+    /// The length of the resulting trace is the smallest power of two which is greater than or
+    /// equal to both `min_trace_len` and the length needed to hold the execution of the process.
+    pub(super) fn with_min_len<A>(
+        process: Process<A>,
+        stack_outputs: StackOutputs,
+        min_trace_len: usize,
+    ) -> Self
     where
         A: AdviceProvider,
     {
@@ -80,7 +96,7 @@ impl ExecutionTrace {
         // create a new program info instance with the underlying kernel
         let kernel = process.kernel().clone();
         let program_info = ProgramInfo::new(program_hash, kernel);
-        let (main_trace, aux_trace_hints) = finalize_trace(process, rng);
+        let (main_trace, aux_trace_hints) = finalize_trace(process, rng, min_trace_len);
 
         Self {
             meta: Vec::new(),
@@ -169,7 +185,7 @@ impl ExecutionTrace {
         A: AdviceProvider,
     {
         let rng = RpoRandomCoin::new(&[ZERO; 4]);
-        finalize_trace(process, rng)
+        finalize_trace(process, rng, MIN_TRACE_LEN)
     }
 }
 
@@ -258,12 +274,17 @@ impl Trace for ExecutionTrace {
 /// Converts a process into a set of execution trace columns for each component of the trace.
 ///
 /// The process includes:
-/// - Determining the length of the trace required to accommodate the longest trace column.
+/// - Determining the length of the trace required to accommodate the longest trace column, but
+///   no shorter than `min_trace_len`.
 /// - Padding the columns to make sure all columns are of the same length.
 /// - Inserting random values in the last row of all columns. This helps ensure that there
 ///   are no repeating patterns in each column and each column contains a least two distinct
 ///   values. This, in turn, ensures that polynomial degrees of all columns are stable.
-fn finalize_trace<A>(process: Process<A>, mut rng: RpoRandomCoin) -> (Vec<Vec<Felt>>, AuxTraceHints)
+fn finalize_trace<A>(
+    process: Process<A>,
+    mut rng: RpoRandomCoin,
+    min_trace_len: usize,
+) -> (Vec<Vec<Felt>>, AuxTraceHints)
 where
     A: AdviceProvider,
 {
@@ -286,8 +307,8 @@ where
     let max_len = range_table.len.max(clk as usize).max(chiplets.trace_len());
 
     // pad the trace length to the next power of two and ensure that there is space for the
-    // rows to hold random values
-    let trace_len = (max_len + NUM_RAND_ROWS).next_power_of_two();
+    // rows to hold random values; the trace is never shorter than the requested minimum length
+    let trace_len = (max_len + NUM_RAND_ROWS).max(min_trace_len).next_power_of_two();
     assert!(
         trace_len >= MIN_TRACE_LEN,
         "trace length must be at least {MIN_TRACE_LEN}, but was {trace_len}",
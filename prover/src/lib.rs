@@ -1,22 +1,26 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use air::{ProcessorAir, PublicInputs};
+use air::{trace::stack::STACK_TOP_SIZE, ProcessorAir, PublicInputs};
 use core::marker::PhantomData;
 use processor::{
+    code_blocks::CodeBlock,
     crypto::{
         Blake3_192, Blake3_256, ElementHasher, RandomCoin, Rpo256, RpoRandomCoin, WinterRandomCoin,
     },
-    math::Felt,
-    ExecutionTrace,
+    math::{Felt, FieldElement},
+    utils::collections::Vec,
+    AdviceSource, ExecutionTrace, Operation, ProgramInfo,
 };
-use winter_prover::{ProofOptions as WinterProofOptions, Prover};
+use winter_prover::{ProofOptions as WinterProofOptions, Prover as WinterProver, Trace};
 
 #[cfg(feature = "std")]
 use log::debug;
 #[cfg(feature = "std")]
-use std::time::Instant;
+use processor::crypto::{record_challenges, Challenge, RecordingRandomCoin};
 #[cfg(feature = "std")]
-use winter_prover::Trace;
+use std::time::Instant;
+#[cfg(feature = "tracing")]
+use tracing::{field::Empty, info_span};
 
 #[cfg(all(feature = "metal", target_arch = "aarch64", target_os = "macos"))]
 mod gpu;
@@ -31,6 +35,13 @@ pub use processor::{
 };
 pub use winter_prover::StarkProof;
 
+// CONSTANTS
+// ================================================================================================
+
+/// Memory address of the first word used to load the stack inputs in
+/// [execute_with_hashed_inputs()].
+const HASHED_INPUTS_ADDR: u32 = 0;
+
 // PROVER
 // ================================================================================================
 
@@ -51,11 +62,391 @@ pub fn prove<A>(
 ) -> Result<(StackOutputs, ExecutionProof), ExecutionError>
 where
     A: AdviceProvider,
+{
+    execute_with_progress(program, stack_inputs, advice_provider, options, |_| {})
+}
+
+/// Executes and proves the specified `program` in the same way as [prove()], reporting the
+/// progress of execution and proof generation to the provided `progress` callback.
+///
+/// The callback is invoked with the following [ExecutionPhase]s, in order:
+/// 1. [ExecutionPhase::TraceGenerationStarted] before the program is executed.
+/// 2. [ExecutionPhase::TraceGenerated] after the execution trace has been built.
+/// 3. [ExecutionPhase::ProofGenerationStarted] before the STARK proof is generated.
+/// 4. [ExecutionPhase::ProofGenerated] after the STARK proof has been generated.
+///
+/// If execution or proof generation fails, the phases following the failure are not reported.
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails for any reason.
+pub fn execute_with_progress<A, F>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    options: ProofOptions,
+    progress: F,
+) -> Result<(StackOutputs, ExecutionProof), ExecutionError>
+where
+    A: AdviceProvider,
+    F: FnMut(ExecutionPhase),
+{
+    prove_with_min_trace_len(program, stack_inputs, advice_provider, options, 0, progress)
+}
+
+/// Executes and proves the specified `program` in the same way as [prove()], padding the execution
+/// trace to at least `min_trace_len` rows.
+///
+/// The length of the trace is rounded up to the next power of two, and the trace is padded in the
+/// same way as when the minimum length is not specified. This can be used to force a larger
+/// evaluation domain (e.g., for benchmarking); the resulting proof verifies in the same way as a
+/// proof generated by [prove()].
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails for any reason.
+pub fn execute_with_min_trace_length<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    options: ProofOptions,
+    min_trace_len: usize,
+) -> Result<(StackOutputs, ExecutionProof), ExecutionError>
+where
+    A: AdviceProvider,
+{
+    prove_with_min_trace_len(program, stack_inputs, advice_provider, options, min_trace_len, |_| {})
+}
+
+/// Executes and proves the specified `program` in the same way as [prove()], and additionally
+/// returns the ordered list of Fiat-Shamir challenges drawn from the public coin while the proof
+/// was being generated.
+///
+/// This is intended for debugging transcript mismatches between the prover and the verifier; the
+/// returned challenges can be compared against the ones returned by the verifier.
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails for any reason.
+#[cfg(feature = "std")]
+pub fn execute_with_transcript<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    options: ProofOptions,
+) -> Result<(StackOutputs, ExecutionProof, Vec<Challenge>), ExecutionError>
+where
+    A: AdviceProvider,
+{
+    let trace = processor::execute(program, stack_inputs.clone(), advice_provider)?;
+    let stack_outputs = trace.stack_outputs().clone();
+    let hash_fn = options.hash_fn();
+
+    // generate STARK proof recording the challenges drawn from the public coin
+    let (proof, challenges) = record_challenges(|| match hash_fn {
+        HashFunction::Blake3_192 => ExecutionProver::<
+            Blake3_192,
+            RecordingRandomCoin<WinterRandomCoin<_>>,
+        >::new(options, stack_inputs, stack_outputs.clone())
+        .prove(trace),
+        HashFunction::Blake3_256 => ExecutionProver::<
+            Blake3_256,
+            RecordingRandomCoin<WinterRandomCoin<_>>,
+        >::new(options, stack_inputs, stack_outputs.clone())
+        .prove(trace),
+        HashFunction::Rpo256 => ExecutionProver::<Rpo256, RecordingRandomCoin<RpoRandomCoin>>::new(
+            options,
+            stack_inputs,
+            stack_outputs.clone(),
+        )
+        .prove(trace),
+    });
+    let proof = ExecutionProof::new(proof.map_err(ExecutionError::ProverError)?, hash_fn);
+
+    Ok((stack_outputs, proof, challenges))
+}
+
+/// Executes and proves the specified `program` with a commitment to a region of memory appended
+/// to its outputs, and returns the result together with a STARK-based proof of the program's
+/// execution.
+///
+/// After the program completes, the words located in memory at addresses in the range
+/// `[commit_addr, commit_addr + commit_len)` of the root context are hashed using RPO, and the
+/// resulting digest is pushed onto the stack. Thus, the top word of the returned stack outputs is
+/// the commitment to the memory region, and the remaining outputs are the outputs of the program.
+/// The digest is equal to the hash of the elements of all words in the region (taken in memory
+/// order).
+///
+/// Since the commitment is computed by the VM, the proven program differs from the specified
+/// `program`; the returned [ProgramInfo] describes the proven program and should be used to
+/// verify the proof.
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails for any reason.
+pub fn execute_with_mem_commitment<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    commit_addr: u32,
+    commit_len: u32,
+    options: ProofOptions,
+) -> Result<(ProgramInfo, StackOutputs, ExecutionProof), ExecutionError>
+where
+    A: AdviceProvider,
+{
+    let root = CodeBlock::new_join([
+        program.root().clone(),
+        build_mem_commitment_block(commit_addr.into(), commit_len as usize),
+    ]);
+    let program = Program::with_kernel(root, program.kernel().clone(), program.cb_table().clone());
+
+    let (stack_outputs, proof) = prove(&program, stack_inputs, advice_provider, options)?;
+    Ok((ProgramInfo::from(program), stack_outputs, proof))
+}
+
+/// Executes and proves the specified `program` with a combined commitment to several regions of
+/// memory appended to its outputs, and returns the result together with a STARK-based proof of the
+/// program's execution.
+///
+/// After the program completes, each region `(addr, len)` in `regions` is hashed in the same way
+/// as by [execute_with_mem_commitment()], and the digests of the regions are absorbed into an RPO
+/// sponge kept on the stack (in the order in which the regions are specified). Thus, the combined
+/// commitment is equal to the RPO hash of the elements of the region digests taken in order, and
+/// it is the top word of the returned stack outputs, followed by the outputs of the program. The
+/// memory is left unchanged.
+///
+/// Since the commitment is computed by the VM, the proven program differs from the specified
+/// `program`; the returned [ProgramInfo] describes the proven program and should be used to
+/// verify the proof.
+///
+/// # Errors
+/// Returns an error if any of the regions extends beyond the addressable memory, or if program
+/// execution or STARK proof generation fails for any reason.
+pub fn execute_with_mem_commitments<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    regions: &[(u64, usize)],
+    options: ProofOptions,
+) -> Result<(ProgramInfo, StackOutputs, ExecutionProof), ExecutionError>
+where
+    A: AdviceProvider,
+{
+    // all words of the regions must be addressable
+    let max_addr = u32::MAX as u64;
+    for &(addr, len) in regions {
+        let end_addr = addr.saturating_add(len as u64);
+        if end_addr > max_addr + 1 {
+            return Err(ExecutionError::MemoryAddressOutOfBounds {
+                addr: end_addr - 1,
+                max: max_addr,
+            });
+        }
+    }
+
+    let mut blocks = Vec::from([program.root().clone()]);
+    if regions.is_empty() {
+        // the hash of an empty sequence of elements is a word of zeros
+        blocks.push(CodeBlock::new_span(Vec::from([Operation::Pad; 4])));
+    } else {
+        // prepare the capacity of the sponge; for an odd number of digests, the first capacity
+        // element is set to 1
+        let is_odd = regions.len() % 2 == 1;
+        let mut ops = Vec::from([if is_odd {
+            Operation::Push(Felt::ONE)
+        } else {
+            Operation::Pad
+        }]);
+        ops.resize(4, Operation::Pad);
+        blocks.push(CodeBlock::new_span(ops));
+
+        // absorb pairs of digests; the rate is dropped after all but the last permutation
+        let mut pairs = regions.chunks(2).peekable();
+        while let Some(pair) = pairs.next() {
+            blocks.extend(pair.iter().map(|&(addr, len)| build_mem_commitment_block(addr, len)));
+
+            let mut ops = Vec::new();
+            if pair.len() == 1 {
+                // pad the last digest with [1, 0, 0, 0]
+                ops.push(Operation::Push(Felt::ONE));
+                ops.resize(4, Operation::Pad);
+            }
+            ops.push(Operation::HPerm);
+            if pairs.peek().is_some() {
+                ops.resize(ops.len() + 8, Operation::Drop);
+            }
+            blocks.push(CodeBlock::new_span(ops));
+        }
+
+        // keep only the digest
+        let mut ops = Vec::from([Operation::Drop; 4]);
+        ops.push(Operation::SwapW);
+        ops.resize(ops.len() + 4, Operation::Drop);
+        blocks.push(CodeBlock::new_span(ops));
+    }
+
+    let root = blocks
+        .into_iter()
+        .reduce(|first, second| CodeBlock::new_join([first, second]))
+        .expect("no code blocks");
+    let program = Program::with_kernel(root, program.kernel().clone(), program.cb_table().clone());
+
+    let (stack_outputs, proof) = prove(&program, stack_inputs, advice_provider, options)?;
+    Ok((ProgramInfo::from(program), stack_outputs, proof))
+}
+
+/// Executes and proves the specified `program` committing only to the hash of its stack inputs,
+/// and returns the result together with a STARK-based proof of the program's execution.
+///
+/// The hash is computed using RPO over the stack input values in the order in which they were
+/// provided to [StackInputs::new()] (i.e., with the top of the stack last), and the returned proof
+/// has the hash as its only public stack inputs. The inputs themselves are supplied to the VM via
+/// the advice provider. Before the program is executed, the VM hashes the inputs, asserts that
+/// the result equals the committed hash, and places the inputs onto the stack, so that the
+/// program observes the same stack as when executed with the inputs directly. The memory used to
+/// do so is reset to zeros before the program is executed.
+///
+/// Since the inputs are loaded by the VM, the proven program differs from the specified
+/// `program`; the returned [ProgramInfo] describes the proven program and should be used together
+/// with the returned hash to verify the proof.
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails for any reason.
+pub fn execute_with_hashed_inputs<A>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    mut advice_provider: A,
+    options: ProofOptions,
+) -> Result<(ProgramInfo, Digest, StackOutputs, ExecutionProof), ExecutionError>
+where
+    A: AdviceProvider,
+{
+    // the stack inputs are stored in stack order, and thus, with the top of the stack first
+    let inputs = stack_inputs.values().iter().rev().copied().collect::<Vec<_>>();
+    let inputs_hash = Rpo256::hash_elements(&inputs);
+
+    // put the inputs onto the advice stack, padded in the same way as by the hash function
+    let padded_inputs = pad_hashed_inputs(&inputs);
+    for &value in padded_inputs.iter().rev() {
+        advice_provider.push_stack(AdviceSource::Value(value))?;
+    }
+
+    let root =
+        CodeBlock::new_join([build_hashed_inputs_block(inputs.len()), program.root().clone()]);
+    let program = Program::with_kernel(root, program.kernel().clone(), program.cb_table().clone());
+
+    let hashed_stack_inputs = StackInputs::new(inputs_hash.as_elements().to_vec())
+        .expect("a digest is a valid set of stack inputs");
+    let (stack_outputs, proof) = prove(&program, hashed_stack_inputs, advice_provider, options)?;
+    Ok((ProgramInfo::from(program), inputs_hash, stack_outputs, proof))
+}
+
+// REUSABLE PROVER
+// ================================================================================================
+
+/// A prover which can be used to execute and prove multiple programs with the same configuration.
+///
+/// When the `concurrent` feature is enabled, the prover owns a thread pool which is reused for
+/// generating all proofs, rather than relying on the global thread pool for every proof.
+#[derive(Debug)]
+pub struct Prover {
+    options: ProofOptions,
+    #[cfg(feature = "concurrent")]
+    thread_pool: rayon::ThreadPool,
+}
+
+impl Prover {
+    /// Returns a new prover which generates proofs with the specified options.
+    ///
+    /// When the `concurrent` feature is enabled, the thread pool of the prover is created with
+    /// the default number of threads.
+    ///
+    /// # Panics
+    /// Panics if the thread pool could not be created.
+    pub fn new(options: ProofOptions) -> Self {
+        Self {
+            options,
+            #[cfg(feature = "concurrent")]
+            thread_pool: rayon::ThreadPoolBuilder::new()
+                .build()
+                .expect("failed to create thread pool"),
+        }
+    }
+
+    /// Returns the options used by this prover to generate proofs.
+    pub fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    /// Executes and proves the specified `program` in the same way as [prove()] using the options
+    /// of this prover.
+    ///
+    /// # Errors
+    /// Returns an error if program execution or STARK proof generation fails for any reason.
+    pub fn prove<A>(
+        &self,
+        program: &Program,
+        stack_inputs: StackInputs,
+        advice_provider: A,
+    ) -> Result<(StackOutputs, ExecutionProof), ExecutionError>
+    where
+        A: AdviceProvider + Send,
+    {
+        let options = self.options.clone();
+        let prove_program = move || prove(program, stack_inputs, advice_provider, options);
+
+        // run proof generation on the thread pool of this prover
+        #[cfg(feature = "concurrent")]
+        let prove_program = move || self.thread_pool.install(prove_program);
+
+        prove_program()
+    }
+}
+
+// EXECUTION PHASES
+// ================================================================================================
+
+/// Milestones of program execution and proof generation reported by [execute_with_progress()].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExecutionPhase {
+    /// Execution of the program (i.e., generation of the execution trace) has started.
+    TraceGenerationStarted,
+    /// The execution trace has been generated; `trace_len` is the number of rows in the trace.
+    TraceGenerated { trace_len: usize },
+    /// Generation of the STARK proof has started.
+    ProofGenerationStarted,
+    /// The STARK proof has been generated.
+    ProofGenerated,
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes and proves the specified `program` with the execution trace padded to at least
+/// `min_trace_len` rows, reporting the progress to the provided `progress` callback.
+fn prove_with_min_trace_len<A, F>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_provider: A,
+    options: ProofOptions,
+    min_trace_len: usize,
+    mut progress: F,
+) -> Result<(StackOutputs, ExecutionProof), ExecutionError>
+where
+    A: AdviceProvider,
+    F: FnMut(ExecutionPhase),
 {
     // execute the program to create an execution trace
+    progress(ExecutionPhase::TraceGenerationStarted);
+    #[cfg(feature = "tracing")]
+    let span =
+        info_span!("trace_generation", trace_width = Empty, trace_len = Empty, elapsed_ms = Empty)
+            .entered();
     #[cfg(feature = "std")]
     let now = Instant::now();
-    let trace = processor::execute(program, stack_inputs.clone(), advice_provider)?;
+    let trace = processor::execute_with_min_trace_len(
+        program,
+        stack_inputs.clone(),
+        advice_provider,
+        min_trace_len,
+    )?;
     #[cfg(feature = "std")]
     debug!(
         "Generated execution trace of {} columns and {} steps in {} ms",
@@ -63,11 +454,32 @@ where
         trace.length(),
         now.elapsed().as_millis()
     );
+    #[cfg(feature = "tracing")]
+    {
+        span.record("trace_width", trace.layout().main_trace_width());
+        span.record("trace_len", trace.length());
+        span.record("elapsed_ms", now.elapsed().as_millis() as u64);
+        span.exit();
+    }
+    progress(ExecutionPhase::TraceGenerated {
+        trace_len: trace.length(),
+    });
 
     let stack_outputs = trace.stack_outputs().clone();
     let hash_fn = options.hash_fn();
 
     // generate STARK proof
+    progress(ExecutionPhase::ProofGenerationStarted);
+    #[cfg(feature = "tracing")]
+    let span = info_span!(
+        "proof_generation",
+        trace_width = trace.layout().main_trace_width(),
+        trace_len = trace.length(),
+        elapsed_ms = Empty
+    )
+    .entered();
+    #[cfg(feature = "tracing")]
+    let now = Instant::now();
     let proof = match hash_fn {
         HashFunction::Blake3_192 => ExecutionProver::<Blake3_192, WinterRandomCoin<_>>::new(
             options,
@@ -93,11 +505,151 @@ where
         }
     }
     .map_err(ExecutionError::ProverError)?;
+    #[cfg(feature = "tracing")]
+    span.record("elapsed_ms", now.elapsed().as_millis() as u64);
     let proof = ExecutionProof::new(proof, hash_fn);
+    progress(ExecutionPhase::ProofGenerated);
 
     Ok((stack_outputs, proof))
 }
 
+/// Returns a code block which replaces the top of the stack with the hash of `commit_len` words
+/// located in memory starting at `commit_addr`, and leaves the rest of the stack unchanged.
+///
+/// This works in the same way as `std::crypto::hashes::native::hash_memory`, except that the loop
+/// over pairs of words is unrolled.
+fn build_mem_commitment_block(commit_addr: u64, commit_len: usize) -> CodeBlock {
+    let is_odd = commit_len % 2 == 1;
+    let mut ops = Vec::new();
+
+    // prepare hasher state; for an odd number of words, the first capacity element is set to 1
+    ops.push(Operation::Push(Felt::new(commit_addr)));
+    ops.push(if is_odd {
+        Operation::Push(Felt::ONE)
+    } else {
+        Operation::Pad
+    });
+    ops.resize(ops.len() + 11, Operation::Pad);
+
+    // absorb pairs of words
+    for _ in 0..commit_len / 2 {
+        ops.extend([Operation::MStream, Operation::HPerm]);
+    }
+
+    // absorb the last word, padded with [1, 0, 0, 0]
+    if is_odd {
+        // this is equivalent to `dup.12 mem_loadw swapw`
+        ops.extend([
+            Operation::Pad,
+            Operation::Dup13,
+            Operation::Add,
+            Operation::MLoadW,
+            Operation::SwapW,
+        ]);
+        ops.resize(ops.len() + 4, Operation::Drop);
+        ops.push(Operation::Push(Felt::ONE));
+        ops.resize(ops.len() + 3, Operation::Pad);
+        ops.push(Operation::HPerm);
+    }
+
+    // keep only the digest and drop the address
+    ops.resize(ops.len() + 4, Operation::Drop);
+    ops.push(Operation::SwapW);
+    ops.resize(ops.len() + 4, Operation::Drop);
+    ops.extend([Operation::MovUp4, Operation::Drop]);
+
+    CodeBlock::new_span(ops)
+}
+
+/// Returns the provided inputs padded to a multiple of 8 elements in the same way as by
+/// [Rpo256::hash_elements()], i.e., by appending ONE followed by as many ZEROs as needed.
+fn pad_hashed_inputs(inputs: &[Felt]) -> Vec<Felt> {
+    let mut padded = inputs.to_vec();
+    if padded.len() % 8 != 0 {
+        padded.push(Felt::ONE);
+        padded.resize((padded.len() + 7) / 8 * 8, Felt::ZERO);
+    }
+    padded
+}
+
+/// Returns a code block which loads `num_inputs` stack inputs from the advice stack, and places
+/// them onto the stack after making sure that their hash equals the word at the top of the stack.
+///
+/// The code block expects the stack to consist of the hash of the inputs followed by zeros, and
+/// the advice stack to start with the inputs padded via [pad_hashed_inputs()]. The inputs are
+/// hashed while being copied into memory starting at [HASHED_INPUTS_ADDR]; once they are placed
+/// onto the stack, the memory is reset to zeros.
+fn build_hashed_inputs_block(num_inputs: usize) -> CodeBlock {
+    let num_padded_words = (num_inputs + 7) / 8 * 2;
+    let push_addr = |offset: usize| Operation::Push(Felt::from(HASHED_INPUTS_ADDR + offset as u32));
+    let mut ops = Vec::new();
+
+    // hash the inputs while copying them from the advice stack into memory; the first capacity
+    // element is set to 1 if the inputs are padded
+    ops.push(push_addr(0));
+    ops.push(if num_inputs % 8 != 0 {
+        Operation::Push(Felt::ONE)
+    } else {
+        Operation::Pad
+    });
+    ops.resize(ops.len() + 11, Operation::Pad);
+    for _ in 0..num_padded_words / 2 {
+        ops.extend([Operation::Pipe, Operation::HPerm]);
+    }
+
+    // keep only the digest and drop the address
+    ops.resize(ops.len() + 4, Operation::Drop);
+    ops.push(Operation::SwapW);
+    ops.resize(ops.len() + 4, Operation::Drop);
+    ops.extend([Operation::MovUp4, Operation::Drop]);
+
+    // make sure the digest equals the hash at the top of the stack; this is equivalent to
+    // `assert_eqw`
+    ops.extend([
+        Operation::MovUp4,
+        Operation::Eq,
+        Operation::Assert,
+        Operation::MovUp3,
+        Operation::Eq,
+        Operation::Assert,
+        Operation::MovUp2,
+        Operation::Eq,
+        Operation::Assert,
+        Operation::Eq,
+        Operation::Assert,
+    ]);
+
+    // at this point the stack consists of zeros only; the first (i.e., deepest) 16 inputs
+    // overwrite the top of the stack word by word, and the remaining inputs are pushed onto the
+    // stack. the elements of the last word which do not belong to the inputs end up at the top of
+    // the stack, and are dropped.
+    let num_words = (num_inputs + 3) / 4;
+    let num_top_words = num_words.min(STACK_TOP_SIZE / 4);
+    for i in 0..num_top_words {
+        ops.extend([push_addr(i), Operation::MLoadW]);
+        match num_top_words - 1 - i {
+            1 => ops.push(Operation::SwapW),
+            2 => ops.push(Operation::SwapW2),
+            3 => ops.push(Operation::SwapW3),
+            _ => (),
+        }
+    }
+    for i in num_top_words..num_words {
+        ops.resize(ops.len() + 4, Operation::Pad);
+        ops.extend([push_addr(i), Operation::MLoadW]);
+    }
+    ops.resize(ops.len() + num_words * 4 - num_inputs, Operation::Drop);
+
+    // reset the memory used for the inputs to zeros
+    for i in 0..num_padded_words {
+        ops.resize(ops.len() + 4, Operation::Pad);
+        ops.extend([push_addr(i), Operation::MStoreW]);
+        ops.resize(ops.len() + 4, Operation::Drop);
+    }
+
+    CodeBlock::new_span(ops)
+}
+
 // PROVER
 // ================================================================================================
 
@@ -152,7 +704,7 @@ where
     }
 }
 
-impl<H, R> Prover for ExecutionProver<H, R>
+impl<H, R> WinterProver for ExecutionProver<H, R>
 where
     H: ElementHasher<BaseField = Felt>,
     R: RandomCoin<BaseField = Felt, Hasher = H>,
@@ -182,3 +734,100 @@ where
         PublicInputs::new(program_info, self.stack_inputs.clone(), self.stack_outputs.clone())
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use super::*;
+    use air::trace::TRACE_WIDTH;
+    use std::{
+        collections::BTreeMap,
+        sync::{Arc, Mutex},
+    };
+    use tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+        subscriber, Event, Metadata, Subscriber,
+    };
+
+    #[test]
+    fn prove_emits_phase_spans() {
+        let program = Program::new(CodeBlock::new_span(vec![Operation::Pad, Operation::Drop]));
+        let collector = SpanCollector::default();
+        let spans = collector.spans.clone();
+
+        let (_, proof) = subscriber::with_default(collector, || {
+            prove(
+                &program,
+                StackInputs::default(),
+                MemAdviceProvider::default(),
+                ProofOptions::default(),
+            )
+            .unwrap()
+        });
+
+        let spans = spans.lock().unwrap();
+        let stark_proof = proof.stark_proof();
+        let trace_len =
+            (stark_proof.lde_domain_size() / stark_proof.options().blowup_factor()) as u64;
+        for name in ["trace_generation", "proof_generation"] {
+            let (_, fields) = spans.iter().find(|(span_name, _)| *span_name == name).unwrap();
+            assert_eq!(Some(&(TRACE_WIDTH as u64)), fields.get("trace_width"));
+            assert_eq!(Some(&trace_len), fields.get("trace_len"));
+            assert!(fields.contains_key("elapsed_ms"));
+        }
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Names and integer fields of the spans collected by a [SpanCollector].
+    type CollectedSpans = Arc<Mutex<Vec<(&'static str, BTreeMap<&'static str, u64>)>>>;
+
+    /// A subscriber which collects the names and the integer fields of all created spans.
+    #[derive(Default)]
+    struct SpanCollector {
+        spans: CollectedSpans,
+    }
+
+    impl Subscriber for SpanCollector {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut fields = BTreeMap::new();
+            span.record(&mut FieldVisitor(&mut fields));
+
+            let mut spans = self.spans.lock().unwrap();
+            spans.push((span.metadata().name(), fields));
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let mut spans = self.spans.lock().unwrap();
+            let (_, fields) = &mut spans[span.into_u64() as usize - 1];
+            values.record(&mut FieldVisitor(fields));
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    struct FieldVisitor<'a>(&'a mut BTreeMap<&'static str, u64>);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_u64(&mut self, field: &Field, value: u64) {
+            self.0.insert(field.name(), value);
+        }
+
+        fn record_debug(&mut self, _field: &Field, _value: &dyn core::fmt::Debug) {}
+    }
+}
@@ -0,0 +1,123 @@
+use crate::build_test;
+use std::collections::BTreeSet;
+use test_utils::{build_expected_hash, StarkField};
+
+// TEST DATA
+// ================================================================================================
+
+const ITEMS: [[u64; 4]; 5] = [
+    [1, 2, 3, 4],
+    [5, 6, 7, 8],
+    [0, 0, 0, 1],
+    [u32::MAX as u64, 0, 42, 7],
+    [11, 22, 33, 44],
+];
+
+const NOT_INSERTED: [u64; 4] = [9, 10, 11, 12];
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn insert_and_maybe_contains() {
+    let [a, b, c, d, e] = ITEMS.map(|item| item_to_str(&item));
+    let source = format!(
+        "
+    use.std::collections::bloom
+    begin
+        push.1000 exec.bloom::init
+
+        push.{a} push.1000 exec.bloom::insert
+        push.{b} push.1000 exec.bloom::insert
+        push.{c} push.1000 exec.bloom::insert
+        push.{d} push.1000 exec.bloom::insert
+        push.{e} push.1000 exec.bloom::insert
+
+        push.{a} push.1000 exec.bloom::maybe_contains
+        push.{b} push.1000 exec.bloom::maybe_contains
+        push.{c} push.1000 exec.bloom::maybe_contains
+        push.{d} push.1000 exec.bloom::maybe_contains
+        push.{e} push.1000 exec.bloom::maybe_contains
+    end
+    "
+    );
+
+    // there are no false negatives
+    build_test!(&source).expect_stack(&[1; ITEMS.len()]);
+}
+
+#[test]
+fn maybe_contains_not_inserted() {
+    let [a, b, c, d, e] = ITEMS.map(|item| item_to_str(&item));
+    let item = item_to_str(&NOT_INSERTED);
+    let source = format!(
+        "
+    use.std::collections::bloom
+    begin
+        push.1000 exec.bloom::init
+
+        push.{a} push.1000 exec.bloom::insert
+        push.{b} push.1000 exec.bloom::insert
+        push.{c} push.1000 exec.bloom::insert
+        push.{d} push.1000 exec.bloom::insert
+        push.{e} push.1000 exec.bloom::insert
+
+        push.{item} push.1000 exec.bloom::maybe_contains
+    end
+    "
+    );
+
+    // an item which was never inserted is reported as contained only if all of its bits were set
+    // by the inserted items (i.e., a false positive)
+    let set_bits = ITEMS.iter().flat_map(bit_indexes).collect::<BTreeSet<_>>();
+    let expected = bit_indexes(&NOT_INSERTED).iter().all(|idx| set_bits.contains(idx));
+    build_test!(&source).expect_stack(&[expected as u64]);
+
+    // with all bits of the filter set, any item is reported as contained
+    let source = format!(
+        "
+    use.std::collections::bloom
+    begin
+        push.1000
+        repeat.32
+            push.{} dup.1 mem_store add.1
+        end
+        drop
+
+        push.{item} push.1000 exec.bloom::maybe_contains
+    end
+    ",
+        u32::MAX
+    );
+    build_test!(&source).expect_stack(&[1]);
+}
+
+#[test]
+fn init_clears_filter() {
+    let source = "
+    use.std::collections::bloom
+    begin
+        push.1000 exec.bloom::init
+        push.1.2.3.4 push.1000 exec.bloom::insert
+        push.5.6.7.8 push.1000 exec.bloom::insert
+
+        push.1000 exec.bloom::init
+        push.1.2.3.4 push.1000 exec.bloom::maybe_contains
+        push.5.6.7.8 push.1000 exec.bloom::maybe_contains
+    end
+    ";
+
+    build_test!(source).expect_stack(&[0, 0]);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn item_to_str(item: &[u64; 4]) -> String {
+    item.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(".")
+}
+
+/// Returns indexes of the filter bits selected by the specified item.
+fn bit_indexes(item: &[u64; 4]) -> [u64; 4] {
+    build_expected_hash(item).map(|h| h.as_int() as u32 as u64 & 1023)
+}
@@ -0,0 +1,147 @@
+use crate::build_test;
+use test_utils::TestError;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn push_back_and_pop_front() {
+    let source = "
+    use.std::collections::deque
+    begin
+        push.1000 push.4 exec.deque::init
+        push.1000 push.1 exec.deque::push_back
+        push.1000 push.2 exec.deque::push_back
+        push.1000 push.3 exec.deque::push_back
+        push.1000 exec.deque::len
+
+        push.1000 exec.deque::pop_front
+        push.1000 exec.deque::pop_front
+        push.1000 exec.deque::pop_front
+        push.1000 exec.deque::len
+    end
+    ";
+
+    // values are popped in the order of insertion
+    build_test!(source).expect_stack(&[0, 3, 2, 1, 3]);
+}
+
+#[test]
+fn push_back_and_pop_back() {
+    let source = "
+    use.std::collections::deque
+    begin
+        push.1000 push.4 exec.deque::init
+        push.1000 push.1 exec.deque::push_back
+        push.1000 push.2 exec.deque::push_back
+        push.1000 push.3 exec.deque::push_back
+
+        push.1000 exec.deque::pop_back
+        push.1000 exec.deque::pop_back
+        push.1000 exec.deque::pop_back
+    end
+    ";
+
+    // values are popped in the reverse order of insertion
+    build_test!(source).expect_stack(&[1, 2, 3]);
+}
+
+#[test]
+fn push_front_and_pop_front() {
+    let source = "
+    use.std::collections::deque
+    begin
+        push.1000 push.4 exec.deque::init
+        push.1000 push.1 exec.deque::push_front
+        push.1000 push.2 exec.deque::push_front
+        push.1000 push.3 exec.deque::push_front
+
+        push.1000 exec.deque::pop_front
+        push.1000 exec.deque::pop_front
+        push.1000 exec.deque::pop_front
+    end
+    ";
+
+    // values are popped in the reverse order of insertion
+    build_test!(source).expect_stack(&[1, 2, 3]);
+}
+
+#[test]
+fn mixed_front_and_back() {
+    let source = "
+    use.std::collections::deque
+    begin
+        push.1000 push.4 exec.deque::init
+        push.1000 push.1 exec.deque::push_back
+        push.1000 push.2 exec.deque::push_front
+        push.1000 push.3 exec.deque::push_back
+        push.1000 exec.deque::pop_front
+
+        # the buffer wraps around in both directions: the deque is [4, 1, 3, 5]
+        push.1000 push.4 exec.deque::push_front
+        push.1000 push.5 exec.deque::push_back
+        push.1000 exec.deque::len
+
+        push.1000 exec.deque::pop_back
+        push.1000 exec.deque::pop_front
+        push.1000 exec.deque::pop_front
+        push.1000 exec.deque::pop_back
+        push.1000 exec.deque::len
+    end
+    ";
+
+    build_test!(source).expect_stack(&[0, 3, 1, 4, 5, 4, 2]);
+}
+
+#[test]
+fn pop_empty() {
+    // popping from an empty deque fails at either end; assertions cannot carry error codes, so
+    // both ends fail with the same error
+    for pop in ["pop_front", "pop_back"] {
+        let source = format!(
+            "
+        use.std::collections::deque
+        begin
+            push.1000 push.2 exec.deque::init
+            push.1000 exec.deque::{pop}
+        end
+        "
+        );
+        build_test!(&source).expect_error(TestError::ExecutionError("FailedAssertion"));
+
+        // a deque from which all values have been popped is empty as well
+        let source = format!(
+            "
+        use.std::collections::deque
+        begin
+            push.1000 push.2 exec.deque::init
+            push.1000 push.1 exec.deque::push_back
+            push.1000 push.2 exec.deque::push_front
+            push.1000 exec.deque::pop_front
+            push.1000 exec.deque::pop_back
+            push.1000 exec.deque::{pop}
+        end
+        "
+        );
+        build_test!(&source).expect_error(TestError::ExecutionError("FailedAssertion"));
+    }
+}
+
+#[test]
+fn push_full() {
+    // pushing into a full deque fails at either end
+    for push in ["push_front", "push_back"] {
+        let source = format!(
+            "
+        use.std::collections::deque
+        begin
+            push.1000 push.2 exec.deque::init
+            push.1000 push.1 exec.deque::push_back
+            push.1000 push.2 exec.deque::push_front
+            push.1000 push.3 exec.deque::{push}
+        end
+        "
+        );
+        build_test!(&source).expect_error(TestError::ExecutionError("FailedAssertion"));
+    }
+}
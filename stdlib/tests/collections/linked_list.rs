@@ -0,0 +1,74 @@
+use crate::build_test;
+use test_utils::TestError;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn push_front_and_pop_front() {
+    // the list is located at address 1000, and its nodes at addresses 1001, 1002, and 1003
+    let source = "
+    use.std::collections::linked_list
+    begin
+        push.1000 exec.linked_list::init
+        push.1000 push.1001 push.10 exec.linked_list::push_front
+        push.1000 push.1002 push.20 exec.linked_list::push_front
+        push.1000 push.1003 push.30 exec.linked_list::push_front
+        push.1000 exec.linked_list::len
+
+        push.1000 exec.linked_list::pop_front
+        push.1000 exec.linked_list::len
+        push.1000 exec.linked_list::pop_front
+        push.1000 exec.linked_list::pop_front
+        push.1000 exec.linked_list::len
+    end
+    ";
+
+    // values are popped in the reverse order of insertion
+    build_test!(source).expect_stack(&[0, 10, 20, 2, 30, 3]);
+}
+
+#[test]
+fn reuse_popped_node() {
+    // the node at address 1002 is reused after its value has been popped
+    let source = "
+    use.std::collections::linked_list
+    begin
+        push.1000 exec.linked_list::init
+        push.1000 push.1001 push.7 exec.linked_list::push_front
+        push.1000 push.1002 push.8 exec.linked_list::push_front
+        push.1000 exec.linked_list::pop_front
+
+        push.1000 push.1002 push.9 exec.linked_list::push_front
+        push.1000 exec.linked_list::pop_front
+        push.1000 exec.linked_list::pop_front
+        push.1000 exec.linked_list::len
+    end
+    ";
+
+    build_test!(source).expect_stack(&[0, 7, 9, 8]);
+}
+
+#[test]
+fn pop_front_empty() {
+    let source = "
+    use.std::collections::linked_list
+    begin
+        push.1000 exec.linked_list::init
+        push.1000 exec.linked_list::pop_front
+    end
+    ";
+    build_test!(source).expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // a list from which all values have been popped is empty as well
+    let source = "
+    use.std::collections::linked_list
+    begin
+        push.1000 exec.linked_list::init
+        push.1000 push.1001 push.1 exec.linked_list::push_front
+        push.1000 exec.linked_list::pop_front
+        push.1000 exec.linked_list::pop_front
+    end
+    ";
+    build_test!(source).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
@@ -0,0 +1,132 @@
+use crate::build_test;
+use test_utils::{Felt, StarkField, TestError};
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn insert_and_get() {
+    // keys 3 and 7 are both probed starting at slot 3, and keys 2^32 and 1 are both probed
+    // starting at slot 1; thus, the lookups exercise linear probing past occupied slots
+    let source = "
+    use.std::collections::map
+    begin
+        push.1000 push.4 exec.map::init
+        push.1000 push.30 push.3 exec.map::insert
+        push.1000 push.70 push.7 exec.map::insert
+        push.1000 push.40 push.4294967296 exec.map::insert
+        push.1000 push.10 push.1 exec.map::insert
+        push.1000 exec.map::len
+
+        push.1000 push.3 exec.map::get
+        push.1000 push.7 exec.map::get
+        push.1000 push.4294967296 exec.map::get
+        push.1000 push.1 exec.map::get
+        push.1000 push.11 exec.map::get
+    end
+    ";
+
+    // getting a key which is not in the map returns p - 1
+    let absent = Felt::MODULUS - 1;
+    build_test!(source).expect_stack(&[absent, 10, 40, 70, 30, 4]);
+}
+
+#[test]
+fn insert_overwrites_value() {
+    let source = "
+    use.std::collections::map
+    begin
+        push.1000 push.2 exec.map::init
+        push.1000 push.50 push.5 exec.map::insert
+        push.1000 push.60 push.6 exec.map::insert
+        push.1000 push.55 push.5 exec.map::insert
+        push.1000 exec.map::len
+
+        push.1000 push.5 exec.map::get
+        push.1000 push.6 exec.map::get
+    end
+    ";
+
+    // overwriting a value in a full map succeeds and does not change the number of entries
+    build_test!(source).expect_stack(&[60, 55, 2]);
+}
+
+#[test]
+fn remove() {
+    let source = "
+    use.std::collections::map
+    begin
+        push.1000 push.4 exec.map::init
+        push.1000 push.30 push.3 exec.map::insert
+        push.1000 push.70 push.7 exec.map::insert
+        push.1000 push.110 push.11 exec.map::insert
+        push.1000 push.7 exec.map::remove
+        push.1000 push.7 exec.map::remove
+        push.1000 exec.map::len
+
+        # keys 3 and 11 are still found when probing past the removed entry of key 7
+        push.1000 push.3 exec.map::get
+        push.1000 push.7 exec.map::get
+        push.1000 push.11 exec.map::get
+
+        # the slot of the removed entry is reused, and key 11 is not duplicated
+        push.1000 push.150 push.15 exec.map::insert
+        push.1000 push.111 push.11 exec.map::insert
+        push.1000 exec.map::len
+        push.1000 push.15 exec.map::get
+        push.1000 push.11 exec.map::get
+    end
+    ";
+
+    let absent = Felt::MODULUS - 1;
+    build_test!(source).expect_stack(&[111, 150, 3, 110, absent, 30, 2, absent, 70]);
+}
+
+#[test]
+fn insert_full() {
+    // inserting a new key into a full map fails
+    let source = "
+    use.std::collections::map
+    begin
+        push.1000 push.2 exec.map::init
+        push.1000 push.10 push.1 exec.map::insert
+        push.1000 push.20 push.2 exec.map::insert
+        push.1000 push.30 push.3 exec.map::insert
+    end
+    ";
+    build_test!(source).expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // a map from which an entry has been removed is not full
+    let source = "
+    use.std::collections::map
+    begin
+        push.1000 push.2 exec.map::init
+        push.1000 push.10 push.1 exec.map::insert
+        push.1000 push.20 push.2 exec.map::insert
+        push.1000 push.1 exec.map::remove
+        push.1000 push.30 push.3 exec.map::insert
+
+        push.1000 push.2 exec.map::get
+        push.1000 push.3 exec.map::get
+    end
+    ";
+    build_test!(source).expect_stack(&[30, 20, 10]);
+}
+
+#[test]
+fn init_clears_map() {
+    let source = "
+    use.std::collections::map
+    begin
+        push.1000 push.2 exec.map::init
+        push.1000 push.10 push.1 exec.map::insert
+        push.1000 push.20 push.2 exec.map::insert
+
+        push.1000 push.2 exec.map::init
+        push.1000 exec.map::len
+        push.1000 push.1 exec.map::get
+    end
+    ";
+
+    build_test!(source).expect_stack(&[Felt::MODULUS - 1, 0]);
+}
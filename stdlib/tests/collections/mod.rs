@@ -3,6 +3,13 @@ use test_utils::{
     Felt, StarkField, TestError, Word, ONE, ZERO,
 };
 
+mod bloom;
+mod deque;
+mod linked_list;
+mod map;
 mod mmr;
+mod mtree;
+mod priority_queue;
 mod smt;
 mod smt64;
+mod trie;
@@ -0,0 +1,136 @@
+use super::{MerkleStore, StarkField, TestError, Word};
+use crate::build_test;
+use test_utils::crypto::{init_merkle_leaves, MerkleTree};
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn get_many() {
+    let leaves = init_merkle_leaves(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let tree = MerkleTree::new(leaves.clone()).unwrap();
+    let indexes = [5_u64, 0, 7];
+
+    // fetch the leaves in bulk, and then make sure each fetched word matches the node returned by
+    // a single mtree_get
+    let source = format!(
+        "
+        use.std::collections::mtree
+
+        begin
+            {}
+            exec.mtree::get_many
+
+            {}
+            padw mem_loadw.200 padw mem_loadw.201 padw mem_loadw.202
+        end",
+        store_indexes(&indexes, 100),
+        compare_with_mtree_get(&indexes, 3, 200)
+    );
+
+    let initial_stack = build_initial_stack(tree.root().into(), 3, indexes.len(), 100, 200);
+    let store = MerkleStore::from(&tree);
+
+    let mut expected = Vec::new();
+    for &index in indexes.iter().rev() {
+        append_word_to_vec(&mut expected, leaves[index as usize]);
+    }
+    append_word_to_vec(&mut expected, tree.root().into());
+
+    build_test!(&source, &initial_stack, &[], store, vec![]).expect_stack(&expected);
+}
+
+#[test]
+fn get_many_empty() {
+    let leaves = init_merkle_leaves(&[1, 2, 3, 4]);
+    let tree = MerkleTree::new(leaves).unwrap();
+
+    let source = "
+        use.std::collections::mtree
+
+        begin
+            exec.mtree::get_many
+        end";
+
+    // no indexes are read, and so the stack is left with just the root
+    let initial_stack = build_initial_stack(tree.root().into(), 2, 0, 100, 200);
+    let store = MerkleStore::from(&tree);
+
+    let mut expected = Vec::new();
+    append_word_to_vec(&mut expected, tree.root().into());
+    build_test!(source, &initial_stack, &[], store, vec![]).expect_stack(&expected);
+}
+
+#[test]
+fn get_many_invalid_index() {
+    let leaves = init_merkle_leaves(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let tree = MerkleTree::new(leaves).unwrap();
+
+    // index 8 is out of bounds for a tree of depth 3
+    let source = format!(
+        "
+        use.std::collections::mtree
+
+        begin
+            {}
+            exec.mtree::get_many
+        end",
+        store_indexes(&[1, 8], 100)
+    );
+
+    let initial_stack = build_initial_stack(tree.root().into(), 3, 2, 100, 200);
+    let store = MerkleStore::from(&tree);
+    build_test!(&source, &initial_stack, &[], store, vec![])
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Builds the initial stack [ROOT, d, n, idx_ptr, out_ptr, ...].
+fn build_initial_stack(
+    root: Word,
+    depth: u64,
+    num_indexes: usize,
+    idx_ptr: u64,
+    out_ptr: u64,
+) -> Vec<u64> {
+    let mut stack = vec![out_ptr, idx_ptr, num_indexes as u64, depth];
+    stack.extend(root.iter().map(StarkField::as_int));
+    stack
+}
+
+/// Returns MASM code which saves the specified indexes into consecutive memory addresses starting
+/// at `ptr`.
+fn store_indexes(indexes: &[u64], ptr: u32) -> String {
+    indexes
+        .iter()
+        .enumerate()
+        .map(|(i, index)| format!("push.{index} mem_store.{}", ptr + i as u32))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns MASM code which fetches each of the specified indexes via mtree_get and asserts that
+/// the result equals the word saved at the corresponding memory address starting at `ptr`.
+///
+/// The code expects the tree root to be on top of the stack and leaves it there.
+fn compare_with_mtree_get(indexes: &[u64], depth: u64, ptr: u32) -> String {
+    indexes
+        .iter()
+        .enumerate()
+        .map(|(i, index)| {
+            format!(
+                "dupw push.{index}.{depth} mtree_get swapw dropw
+                padw mem_loadw.{} assert_eqw",
+                ptr + i as u32
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Appends the word to the vector in stack order (i.e., the last element of the word first).
+fn append_word_to_vec(target: &mut Vec<u64>, word: Word) {
+    target.extend(word.iter().rev().map(StarkField::as_int));
+}
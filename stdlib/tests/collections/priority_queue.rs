@@ -0,0 +1,104 @@
+use crate::build_test;
+use test_utils::{rand::rand_value, Felt, StarkField, TestError};
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn push_and_pop_min() {
+    let values = [5, 3, 9, 1, 7, 3, 0, 8, 2, 6, 4, 9];
+
+    // the values are provided via the stack, and are pushed into the queue one by one
+    let source = "
+    use.std::collections::priority_queue
+    begin
+        push.1000 exec.priority_queue::init
+
+        repeat.12
+            push.1000 swap exec.priority_queue::push
+        end
+        push.1000 exec.priority_queue::size
+
+        repeat.12
+            push.1000 exec.priority_queue::pop_min
+        end
+        push.1000 exec.priority_queue::size
+    end
+    ";
+
+    // values are popped in ascending order; the size is 0 after all values have been popped
+    let mut expected = values.to_vec();
+    expected.sort_unstable_by(|a, b| b.cmp(a));
+    let expected = [&[0], expected.as_slice(), &[values.len() as u64]].concat();
+    build_test!(source, &values).expect_stack(&expected);
+}
+
+#[test]
+fn push_and_pop_min_random() {
+    let values: Vec<u64> = (0..14).map(|_| rand_value::<Felt>().as_int()).collect();
+
+    let source = "
+    use.std::collections::priority_queue
+    begin
+        push.1000 exec.priority_queue::init
+
+        repeat.14
+            push.1000 swap exec.priority_queue::push
+        end
+
+        repeat.14
+            push.1000 exec.priority_queue::pop_min
+        end
+    end
+    ";
+
+    let mut expected = values.clone();
+    expected.sort_unstable_by(|a, b| b.cmp(a));
+    build_test!(source, &values).expect_stack(&expected);
+}
+
+#[test]
+fn interleaved_push_and_pop_min() {
+    let source = "
+    use.std::collections::priority_queue
+    begin
+        push.1000 exec.priority_queue::init
+        push.1000 push.8 exec.priority_queue::push
+        push.1000 push.3 exec.priority_queue::push
+        push.1000 push.5 exec.priority_queue::push
+        push.1000 exec.priority_queue::pop_min
+
+        push.1000 push.1 exec.priority_queue::push
+        push.1000 push.9 exec.priority_queue::push
+        repeat.4
+            push.1000 exec.priority_queue::pop_min
+        end
+    end
+    ";
+
+    build_test!(source).expect_stack(&[9, 8, 5, 1, 3]);
+}
+
+#[test]
+fn pop_min_empty() {
+    let source = "
+    use.std::collections::priority_queue
+    begin
+        push.1000 exec.priority_queue::init
+        push.1000 exec.priority_queue::pop_min
+    end
+    ";
+    build_test!(source).expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // a heap from which all values have been popped is empty as well
+    let source = "
+    use.std::collections::priority_queue
+    begin
+        push.1000 exec.priority_queue::init
+        push.1000 push.1 exec.priority_queue::push
+        push.1000 exec.priority_queue::pop_min
+        push.1000 exec.priority_queue::pop_min
+    end
+    ";
+    build_test!(source).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
@@ -1,5 +1,6 @@
 use super::{Felt, MerkleStore, SimpleSmt, StarkField, TestError, Word, ONE, ZERO};
 use crate::build_test;
+use test_utils::{execute_twice_assert_equal, ProofOptions};
 
 // TEST DATA
 // ================================================================================================
@@ -56,6 +57,36 @@ fn get() {
     }
 }
 
+#[test]
+fn get_is_deterministic() {
+    let smt = SimpleSmt::with_leaves(64, LEAVES).unwrap();
+
+    let source = "
+    use.std::collections::smt64
+    begin
+      exec.smt64::get
+    end
+    ";
+
+    // reading a value from the tree relies on both the advice map and the Merkle store; make sure
+    // that executing and proving the program twice produces identical results
+    let (index, value) = LEAVES[0];
+    let mut initial_stack = Vec::new();
+    append_word_to_vec(&mut initial_stack, smt.root().into());
+    initial_stack.push(index);
+    let expected_output = build_expected_stack(value, smt.root().into());
+
+    let store = MerkleStore::from(&smt);
+    let test = build_test!(source, &initial_stack, &[], store, vec![]);
+    execute_twice_assert_equal(
+        &test.compile(),
+        test.stack_inputs.clone(),
+        test.advice_inputs.clone(),
+        expected_output.len(),
+        ProofOptions::default(),
+    );
+}
+
 #[test]
 fn insert() {
     let mut smt = SimpleSmt::new(64).unwrap();
@@ -0,0 +1,112 @@
+use crate::build_test;
+use test_utils::{Felt, StarkField, TestError};
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn insert_and_lookup() {
+    // keys are saved into memory with their bytes packed into u32 values in big-endian order
+    let source = "
+    use.std::collections::trie
+    begin
+        push.0x63617200 mem_store.100   # car
+        push.0x63617274 mem_store.101   # cart
+        push.0x63617265 mem_store.102   # care
+        push.0x63617400 mem_store.103   # cat
+        push.0x63617274 mem_store.104   # carting
+        push.0x696e6700 mem_store.105
+        push.0x646f6700 mem_store.106   # dog
+        push.0x63000000 mem_store.107   # c
+
+        push.1000 exec.trie::init
+        push.1000 push.3 push.100 push.1 exec.trie::insert
+        push.1000 push.4 push.101 push.2 exec.trie::insert
+        push.1000 push.4 push.102 push.3 exec.trie::insert
+        push.1000 push.3 push.103 push.4 exec.trie::insert
+        push.1000 push.7 push.104 push.5 exec.trie::insert
+        push.1000 push.3 push.106 push.6 exec.trie::insert
+        push.1000 push.1 push.107 push.7 exec.trie::insert
+
+        push.1000 push.3 push.100 exec.trie::lookup
+        push.1000 push.4 push.101 exec.trie::lookup
+        push.1000 push.4 push.102 exec.trie::lookup
+        push.1000 push.3 push.103 exec.trie::lookup
+        push.1000 push.7 push.104 exec.trie::lookup
+        push.1000 push.3 push.106 exec.trie::lookup
+        push.1000 push.1 push.107 exec.trie::lookup
+    end
+    ";
+
+    // values are looked up in the order of insertion, and thus, the last one is on top
+    build_test!(source).expect_stack(&[7, 6, 5, 4, 3, 2, 1]);
+}
+
+#[test]
+fn lookup_absent() {
+    // prefixes and extensions of inserted keys, as well as keys diverging from inserted keys,
+    // were not inserted themselves
+    let source = "
+    use.std::collections::trie
+    begin
+        push.0x63617274 mem_store.100   # cart
+        push.0x646f6700 mem_store.101   # dog
+        push.0x63617200 mem_store.102   # car
+        push.0x63617274 mem_store.103   # carts
+        push.0x73000000 mem_store.104
+        push.0x63617400 mem_store.105   # cat
+        push.0x646f0000 mem_store.106   # do
+        push.0x78000000 mem_store.107   # x
+
+        push.1000 exec.trie::init
+        push.1000 push.4 push.100 push.1 exec.trie::insert
+        push.1000 push.3 push.101 push.2 exec.trie::insert
+
+        push.1000 push.3 push.102 exec.trie::lookup
+        push.1000 push.5 push.103 exec.trie::lookup
+        push.1000 push.3 push.105 exec.trie::lookup
+        push.1000 push.2 push.106 exec.trie::lookup
+        push.1000 push.1 push.107 exec.trie::lookup
+        push.1000 push.0 push.108 exec.trie::lookup
+    end
+    ";
+
+    // lookups of absent keys return p - 1
+    build_test!(source).expect_stack(&[Felt::MODULUS - 1; 6]);
+}
+
+#[test]
+fn insert_replaces_value() {
+    let source = "
+    use.std::collections::trie
+    begin
+        push.0x6b657900 mem_store.100   # key
+        push.0x6b657973 mem_store.101   # keys
+
+        push.1000 exec.trie::init
+        push.1000 push.3 push.100 push.1 exec.trie::insert
+        push.1000 push.4 push.101 push.2 exec.trie::insert
+        push.1000 push.3 push.100 push.3 exec.trie::insert
+
+        push.1000 push.3 push.100 exec.trie::lookup
+        push.1000 push.4 push.101 exec.trie::lookup
+    end
+    ";
+
+    build_test!(source).expect_stack(&[2, 3]);
+}
+
+#[test]
+fn invalid_key() {
+    // the value holding the bytes of the key is not a valid u32 value
+    let source = "
+    use.std::collections::trie
+    begin
+        push.4294967296 mem_store.100
+
+        push.1000 exec.trie::init
+        push.1000 push.4 push.100 exec.trie::lookup
+    end
+    ";
+    build_test!(source).expect_error(TestError::ExecutionError("NotU32Value"));
+}
@@ -0,0 +1,62 @@
+use crate::build_test;
+use curve25519_dalek::{constants::X25519_BASEPOINT, montgomery::MontgomeryPoint, Scalar};
+use test_utils::{assert_max_cycles, test_case, Test};
+
+#[test_case(1 ; "one")]
+#[test_case(2 ; "two")]
+#[test_case(3 ; "three")]
+#[test_case(4 ; "four")]
+#[test_case(7 ; "seven")]
+#[test_case(8 ; "eight")]
+#[test_case(42 ; "forty-two")]
+#[test_case(255 ; "two hundred fifty-five")]
+fn scalar_mul_base_point(k: u64) {
+    let expected = X25519_BASEPOINT * Scalar::from(k);
+    let expected = point_to_limbs(&expected);
+
+    let test = build_scalar_mul_test(&X25519_BASEPOINT, k);
+    test.expect_stack(&expected);
+}
+
+#[test]
+fn scalar_mul_zero_is_identity() {
+    let test = build_scalar_mul_test(&X25519_BASEPOINT, 0);
+    test.expect_stack(&[0; 8]);
+}
+
+#[test]
+fn scalar_mul_cycle_budget() {
+    // guards against performance regressions in the field arithmetic and the ladder step
+    let test = build_scalar_mul_test(&X25519_BASEPOINT, 1);
+    assert_max_cycles(&test.compile(), test.stack_inputs.clone(), 40_000);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_scalar_mul_test(point: &MontgomeryPoint, k: u64) -> Test {
+    let source = "
+    use.std::crypto::ecc::curve25519
+
+    begin
+        exec.curve25519::scalar_mul
+    end";
+
+    // the limbs of the u-coordinate go on top of the limbs of the scalar, with the least
+    // significant limbs at the top of the stack
+    let mut stack = point_to_limbs(point).to_vec();
+    stack.extend([k & 0xffffffff, k >> 32, 0, 0, 0, 0, 0, 0]);
+    stack.reverse();
+
+    build_test!(source, &stack)
+}
+
+/// Converts the u-coordinate of a point into eight 32-bit limbs, least significant limb first.
+fn point_to_limbs(point: &MontgomeryPoint) -> [u64; 8] {
+    let bytes = point.to_bytes();
+    let mut limbs = [0; 8];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(4)) {
+        *limb = u32::from_le_bytes(chunk.try_into().unwrap()) as u64;
+    }
+    limbs
+}
@@ -0,0 +1,76 @@
+use crate::build_test;
+use test_utils::{rand::rand_value, Felt, FieldElement, QuadFelt, StarkField};
+
+// FRI LAYER FOLDING
+// ================================================================================================
+
+#[test]
+fn fold_layer() {
+    let x = Felt::get_root_of_unity(8).exp(5);
+    let a = QuadFelt::new(rand_value(), rand_value());
+    let b = QuadFelt::new(rand_value(), rand_value());
+
+    // degenerate challenge of zero results in the average of the two sibling evaluations
+    check_fold_layer(x, a, b, QuadFelt::ZERO);
+    assert_eq!(
+        fold_layer_expected(x, a, b, QuadFelt::ZERO),
+        (a + b) * QuadFelt::from(Felt::new(2).inv())
+    );
+
+    // challenges from the base field and from the extension field
+    check_fold_layer(x, a, b, QuadFelt::ONE);
+    check_fold_layer(x, a, b, QuadFelt::from(Felt::new(7)));
+    check_fold_layer(x, a, b, QuadFelt::new(rand_value(), rand_value()));
+    check_fold_layer(x, a, b, QuadFelt::new(rand_value(), rand_value()));
+}
+
+#[test]
+fn fold_layer_zero_domain_point() {
+    let a = QuadFelt::new(rand_value(), rand_value());
+    let b = QuadFelt::new(rand_value(), rand_value());
+    let alpha = QuadFelt::new(rand_value(), rand_value());
+
+    let source = "
+        use.std::crypto::fri::ext2fri
+
+        begin
+            exec.ext2fri::fold_layer
+        end
+        ";
+
+    let test = build_test!(source, &build_stack_inputs(Felt::ZERO, a, b, alpha));
+    assert!(test.execute().is_err());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes `ext2fri::fold_layer` and checks the result against the Rust reference.
+fn check_fold_layer(x: Felt, a: QuadFelt, b: QuadFelt, alpha: QuadFelt) {
+    let source = "
+        use.std::crypto::fri::ext2fri
+
+        begin
+            exec.ext2fri::fold_layer
+        end
+        ";
+
+    let expected = fold_layer_expected(x, a, b, alpha).to_base_elements();
+    let test = build_test!(source, &build_stack_inputs(x, a, b, alpha));
+    test.expect_stack(&[expected[1].as_int(), expected[0].as_int()]);
+}
+
+/// Computes f'(x^2) = (a + b) / 2 + alpha * (a - b) / (2 * x).
+fn fold_layer_expected(x: Felt, a: QuadFelt, b: QuadFelt, alpha: QuadFelt) -> QuadFelt {
+    let two_inv = QuadFelt::from(Felt::new(2).inv());
+    let x_inv = QuadFelt::from(x.inv());
+    (a + b) * two_inv + alpha * (a - b) * two_inv * x_inv
+}
+
+/// Builds stack inputs such that the stack looks like [x, a1, a0, b1, b0, alpha1, alpha0, ...].
+fn build_stack_inputs(x: Felt, a: QuadFelt, b: QuadFelt, alpha: QuadFelt) -> Vec<u64> {
+    let [a0, a1] = a.to_base_elements();
+    let [b0, b1] = b.to_base_elements();
+    let [alpha0, alpha1] = alpha.to_base_elements();
+    [alpha0, alpha1, b0, b1, a0, a1, x].iter().map(|v| v.as_int()).collect()
+}
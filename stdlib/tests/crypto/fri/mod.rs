@@ -1,5 +1,5 @@
 use crate::build_test;
-use test_utils::{collections::BTreeMap, crypto::MerkleStore, Felt, StarkField};
+use test_utils::{collections::BTreeMap, crypto::MerkleStore, Felt, StarkField, Test, TestError};
 
 mod channel;
 pub use channel::*;
@@ -7,6 +7,7 @@ pub use channel::*;
 pub(crate) mod verifier_fri_e2f4;
 pub use verifier_fri_e2f4::*;
 
+mod fold;
 mod remainder;
 
 #[test]
@@ -105,6 +106,88 @@ fn fri_fold4_ext2_remainder64() {
     test.expect_stack(&[]);
 }
 
+#[test]
+fn stark_verify_single_query() {
+    let source = "
+        use.std::crypto::fri::frie2f4
+        use.std::crypto::stark::verifier
+
+        begin
+            exec.frie2f4::preprocess
+            # => [query_ptr, layer_ptr, rem_ptr, g, ...]
+
+            exec.verifier::verify_query
+            drop
+        end
+        ";
+
+    build_single_query_test(source).expect_stack(&[]);
+}
+
+#[test]
+fn stark_verify_single_query_tampered() {
+    // the value of the query at the first layer is modified before the query is verified
+    let source = "
+        use.std::crypto::fri::frie2f4
+        use.std::crypto::stark::verifier
+
+        begin
+            exec.frie2f4::preprocess
+            # => [query_ptr, layer_ptr, rem_ptr, g, ...]
+
+            dup push.0.0.0.0 movup.4 mem_loadw
+            # => [poe, p, e1, e0, query_ptr, layer_ptr, rem_ptr, g, ...]
+
+            movup.3 add.1 movdn.3
+            dup.4 mem_storew dropw
+
+            exec.verifier::verify_query
+            drop
+        end
+        ";
+
+    build_single_query_test(source)
+        .expect_error(TestError::ExecutionError("InvalidFriLayerFolding"));
+}
+
+/// Builds a test for the specified source from a FRI proof for a small trace, with the proof
+/// data provided via the advice provider as expected by `frie2f4::preprocess`.
+fn build_single_query_test(source: &str) -> Test {
+    let trace_len_e = 12;
+    let blowup_exp = 3;
+    let depth = trace_len_e + blowup_exp;
+    let domain_size = 1 << depth;
+
+    let FriResult {
+        merkle_sets,
+        advice_maps,
+        positions,
+        alphas,
+        commitments,
+        remainder,
+        num_queries,
+    } = fri_prove_verify_fold4_ext2(trace_len_e).unwrap();
+
+    let advice_stack = prepare_advice_stack(
+        depth,
+        domain_size,
+        num_queries,
+        positions,
+        alphas,
+        commitments,
+        remainder,
+    );
+
+    let advice_map: BTreeMap<[u8; 32], Vec<Felt>> = BTreeMap::from_iter(advice_maps);
+    let domain_generator = Felt::get_root_of_unity(domain_size.ilog2()).as_int();
+
+    let mut store = MerkleStore::new();
+    for path_set in &merkle_sets {
+        store.add_merkle_path_set(path_set).unwrap();
+    }
+    build_test!(source, &[domain_generator], &advice_stack, store, advice_map)
+}
+
 fn prepare_advice_stack(
     depth: usize,
     domain_size: u32,
@@ -0,0 +1,72 @@
+use crate::build_test;
+use test_utils::{
+    crypto::{init_merkle_leaves, MerkleTree},
+    StarkField, TestError, Word,
+};
+
+#[test]
+fn root_from_leaves() {
+    for num_leaves in [2, 4, 8] {
+        let values = (0..num_leaves).map(|i| i * 17 + 3).collect::<Vec<u64>>();
+        let leaves = init_merkle_leaves(&values);
+        let tree = MerkleTree::new(leaves.clone()).unwrap();
+
+        let source = format!(
+            "
+            use.std::crypto::merkle
+
+            begin
+                {}
+                push.{num_leaves}.1000
+                exec.merkle::root_from_leaves
+            end",
+            store_leaves(&leaves, 1000)
+        );
+
+        let expected = tree.root().iter().map(StarkField::as_int).rev().collect::<Vec<_>>();
+        build_test!(&source, &[]).expect_stack(&expected);
+    }
+}
+
+#[test]
+fn root_from_leaves_not_power_of_two() {
+    let leaves = init_merkle_leaves(&[1, 2, 3]);
+
+    let source = format!(
+        "
+        use.std::crypto::merkle
+
+        begin
+            {}
+            push.3.1000
+            exec.merkle::root_from_leaves
+        end",
+        store_leaves(&leaves, 1000)
+    );
+
+    let test = build_test!(&source, &[]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns assembly code which stores the provided leaves at consecutive memory addresses
+/// starting at `ptr`.
+fn store_leaves(leaves: &[Word], ptr: u32) -> String {
+    leaves
+        .iter()
+        .enumerate()
+        .map(|(i, leaf)| {
+            format!(
+                "push.{}.{}.{}.{}.{} mem_storew dropw",
+                leaf[0].as_int(),
+                leaf[1].as_int(),
+                leaf[2].as_int(),
+                leaf[3].as_int(),
+                ptr + i as u32
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
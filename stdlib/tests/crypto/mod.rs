@@ -1,8 +1,12 @@
 mod blake3;
+mod curve25519;
 mod ecdsa_secp256k1;
 mod falcon;
 mod fri;
 mod keccak256;
+mod merkle;
 mod native;
+mod poseidon;
+mod rpo;
 mod sha256;
 mod stark;
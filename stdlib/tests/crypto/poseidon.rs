@@ -0,0 +1,139 @@
+use crate::build_test;
+use std::collections::VecDeque;
+use test_utils::{rand::rand_array, Felt, FieldElement, StarkField};
+
+// CONSTANTS
+// ================================================================================================
+
+const STATE_WIDTH: usize = 3;
+const NUM_FULL_ROUNDS: usize = 8;
+const NUM_PARTIAL_ROUNDS: usize = 22;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn permute() {
+    let source = "
+    use.std::crypto::hashes::poseidon
+
+    begin
+        exec.poseidon::permute
+    end
+    ";
+
+    let constants = round_constants();
+    for state in [[Felt::ZERO; STATE_WIDTH], rand_array(), rand_array()] {
+        let expected = reference_permute(state, &constants).map(|e| e.as_int());
+
+        // the first element of the state is expected to be at the top of the stack
+        let inputs = state.map(|e| e.as_int());
+        let inputs = inputs.iter().rev().copied().collect::<Vec<_>>();
+        build_test!(source, &inputs).expect_stack(&expected);
+    }
+}
+
+#[test]
+fn hash_2to1() {
+    let source = "
+    use.std::crypto::hashes::poseidon
+
+    begin
+        exec.poseidon::hash_2to1
+    end
+    ";
+
+    let constants = round_constants();
+    for [a, b] in [[Felt::ZERO; 2], rand_array(), rand_array()] {
+        let expected = reference_permute([Felt::ZERO, a, b], &constants)[1];
+        build_test!(source, &[b.as_int(), a.as_int()]).expect_stack(&[expected.as_int()]);
+    }
+}
+
+// REFERENCE IMPLEMENTATION
+// ================================================================================================
+
+/// Applies the Poseidon permutation with the specified round constants to the provided state.
+fn reference_permute(mut state: [Felt; STATE_WIDTH], constants: &[Felt]) -> [Felt; STATE_WIDTH] {
+    let half_full_rounds = NUM_FULL_ROUNDS / 2;
+    for (round, round_constants) in constants.chunks(STATE_WIDTH).enumerate() {
+        for (element, &constant) in state.iter_mut().zip(round_constants) {
+            *element += constant;
+        }
+
+        let is_full_round =
+            round < half_full_rounds || round >= half_full_rounds + NUM_PARTIAL_ROUNDS;
+        if is_full_round {
+            state = state.map(|element| element.exp(7));
+        } else {
+            state[0] = state[0].exp(7);
+        }
+
+        let sum = state.iter().fold(Felt::ZERO, |acc, &element| acc + element);
+        state = state.map(|element| element + sum);
+    }
+    state
+}
+
+/// Returns the round constants generated using the Grain LFSR in the same way as by the reference
+/// implementation of Poseidon.
+fn round_constants() -> Vec<Felt> {
+    let mut lfsr = GrainLfsr::new();
+    let num_constants = (NUM_FULL_ROUNDS + NUM_PARTIAL_ROUNDS) * STATE_WIDTH;
+    let mut constants = Vec::with_capacity(num_constants);
+    while constants.len() < num_constants {
+        let value = (0..64).fold(0_u64, |acc, _| (acc << 1) | lfsr.next_bit() as u64);
+        if value < Felt::MODULUS {
+            constants.push(Felt::new(value));
+        }
+    }
+    constants
+}
+
+/// The self-shrinking Grain LFSR used for generating Poseidon round constants.
+struct GrainLfsr(VecDeque<u8>);
+
+impl GrainLfsr {
+    /// Returns the LFSR initialized with the parameters of the permutation, after discarding the
+    /// first 160 output bits.
+    fn new() -> Self {
+        let params = [
+            (1, 2),
+            (0, 4),
+            (64, 12),
+            (STATE_WIDTH, 12),
+            (NUM_FULL_ROUNDS, 10),
+            (NUM_PARTIAL_ROUNDS, 10),
+        ];
+        let mut bits = VecDeque::with_capacity(80);
+        for (value, num_bits) in params {
+            bits.extend((0..num_bits).rev().map(|i| ((value >> i) & 1) as u8));
+        }
+        bits.resize(80, 1);
+
+        let mut lfsr = Self(bits);
+        for _ in 0..160 {
+            lfsr.step();
+        }
+        lfsr
+    }
+
+    /// Returns the next bit of the self-shrinking output sequence.
+    fn next_bit(&mut self) -> u8 {
+        loop {
+            let (selector, bit) = (self.step(), self.step());
+            if selector == 1 {
+                return bit;
+            }
+        }
+    }
+
+    /// Advances the LFSR by one step and returns the new bit.
+    fn step(&mut self) -> u8 {
+        let bits = &self.0;
+        let bit = bits[62] ^ bits[51] ^ bits[38] ^ bits[23] ^ bits[13] ^ bits[0];
+        self.0.pop_front();
+        self.0.push_back(bit);
+        bit
+    }
+}
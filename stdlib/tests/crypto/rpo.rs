@@ -0,0 +1,203 @@
+use crate::build_test;
+use std::iter;
+use test_utils::{build_expected_hash, StarkField, TestError};
+
+#[test]
+fn absorb_multiple_chunks() {
+    let source = "
+    use.std::crypto::hashes::rpo
+
+    begin
+        push.1.2.3.4.1000 mem_storew dropw
+        push.5.6.7.8.1001 mem_storew dropw
+        push.9.10.11.12.1002 mem_storew dropw
+        push.13.14.15.16.1003 mem_storew dropw
+        push.17.18.19.20.1004 mem_storew dropw
+        push.21.22.23.24.1005 mem_storew dropw
+
+        exec.rpo::init
+
+        # absorb the first chunk
+        push.1002.1000
+        exec.rpo::absorb
+
+        # absorb the remaining two chunks
+        push.1006.1002
+        exec.rpo::absorb
+
+        exec.rpo::squeeze
+    end
+    ";
+
+    let data = (1..=24).collect::<Vec<u64>>();
+    let expected = build_expected_hash(&data).into_iter().map(|e| e.as_int()).collect::<Vec<_>>();
+    build_test!(source, &[]).expect_stack(&expected);
+}
+
+#[test]
+fn absorb_matches_single_pass() {
+    // absorbing data chunk-by-chunk must produce the same digest as absorbing it at once
+    let chunked = "
+    use.std::crypto::hashes::rpo
+
+    begin
+        push.1.0.0.0.1000 mem_storew dropw
+        push.0.1.0.0.1001 mem_storew dropw
+        push.0.0.1.0.1002 mem_storew dropw
+        push.0.0.0.1.1003 mem_storew dropw
+
+        exec.rpo::init
+        push.1002.1000 exec.rpo::absorb
+        push.1002.1002 exec.rpo::absorb
+        push.1004.1002 exec.rpo::absorb
+        exec.rpo::squeeze
+    end
+    ";
+    let single_pass = "
+    use.std::crypto::hashes::rpo
+
+    begin
+        push.1.0.0.0.1000 mem_storew dropw
+        push.0.1.0.0.1001 mem_storew dropw
+        push.0.0.1.0.1002 mem_storew dropw
+        push.0.0.0.1.1003 mem_storew dropw
+
+        exec.rpo::init
+        push.1004.1000 exec.rpo::absorb
+        exec.rpo::squeeze
+    end
+    ";
+
+    #[rustfmt::skip]
+    let expected: Vec<u64> = build_expected_hash(&[
+        1, 0, 0, 0,
+        0, 1, 0, 0,
+        0, 0, 1, 0,
+        0, 0, 0, 1,
+    ]).into_iter().map(|e| e.as_int()).collect();
+
+    build_test!(chunked, &[]).expect_stack(&expected);
+    build_test!(single_pass, &[]).expect_stack(&expected);
+}
+
+#[test]
+fn absorb_invalid_range() {
+    // the range must consist of rate-sized chunks
+    let odd_words = "
+    use.std::crypto::hashes::rpo
+
+    begin
+        exec.rpo::init
+        push.1003.1000 exec.rpo::absorb
+    end
+    ";
+    build_test!(odd_words, &[]).expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // start address cannot be greater than end address
+    let reversed = "
+    use.std::crypto::hashes::rpo
+
+    begin
+        exec.rpo::init
+        push.1000.1002 exec.rpo::absorb
+    end
+    ";
+    build_test!(reversed, &[]).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn hash_advice() {
+    let source = "
+    use.std::crypto::hashes::rpo
+
+    begin
+        exec.rpo::hash_advice
+    end
+    ";
+
+    // 4 and 12 elements require padding, while 16 elements fill two rate-sized chunks exactly
+    for num_elements in [4_u64, 12, 16] {
+        let data = (1..=num_elements).collect::<Vec<u64>>();
+        let expected =
+            build_expected_hash(&data).into_iter().map(|e| e.as_int()).collect::<Vec<_>>();
+        build_test!(source, &[num_elements / 4], &data).expect_stack(&expected);
+    }
+}
+
+#[test]
+fn hash_advice_matches_absorb() {
+    // hashing data read from the advice stack must produce the same digest as hashing the same
+    // data read from memory
+    let source = "
+    use.std::crypto::hashes::rpo
+
+    begin
+        push.1.2.3.4.1000 mem_storew dropw
+        push.5.6.7.8.1001 mem_storew dropw
+        push.9.10.11.12.1002 mem_storew dropw
+        push.13.14.15.16.1003 mem_storew dropw
+
+        exec.rpo::init
+        push.1004.1000 exec.rpo::absorb
+        exec.rpo::squeeze
+
+        push.4 exec.rpo::hash_advice
+        assert_eqw
+    end
+    ";
+
+    let advice_stack = (1..=16).collect::<Vec<u64>>();
+    build_test!(source, &[], &advice_stack).expect_stack(&[]);
+}
+
+#[test]
+fn hash_advice_not_enough_data() {
+    let source = "
+    use.std::crypto::hashes::rpo
+
+    begin
+        push.3 exec.rpo::hash_advice
+    end
+    ";
+
+    let advice_stack = (1..=8).collect::<Vec<u64>>();
+    build_test!(source, &[], &advice_stack)
+        .expect_error(TestError::ExecutionError("AdviceStackReadFailed"));
+}
+
+#[test]
+fn hash_elements() {
+    // counts which are multiples of the rate width do not require padding, while the others
+    // require padding the last chunk with a partial word or with a full word
+    for num_elements in [0_u64, 3, 4, 5, 8, 11, 12, 16, 19] {
+        let data = (1..=num_elements).collect::<Vec<u64>>();
+
+        // store the elements followed by a word of non-zero values, which must be ignored
+        let num_words = (num_elements as usize + 3) / 4 + 1;
+        let words = data.iter().copied().chain(iter::repeat(99)).take(num_words * 4);
+        let stores = words
+            .collect::<Vec<_>>()
+            .chunks(4)
+            .enumerate()
+            .map(|(i, w)| {
+                format!("push.{}.{}.{}.{}.{} mem_storew dropw", w[0], w[1], w[2], w[3], 1000 + i)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let source = format!(
+            "
+            use.std::crypto::hashes::rpo
+
+            begin
+                {stores}
+                push.{num_elements}.1000 exec.rpo::hash_elements
+            end
+            "
+        );
+
+        let expected =
+            build_expected_hash(&data).into_iter().map(|e| e.as_int()).collect::<Vec<_>>();
+        build_test!(&source, &[]).expect_stack(&expected);
+    }
+}
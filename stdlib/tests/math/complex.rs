@@ -0,0 +1,75 @@
+use crate::build_test;
+use test_utils::{rand::rand_array, Felt, StarkField};
+
+#[test]
+fn add() {
+    let source = "
+        use.std::math::complex
+        begin
+            exec.complex::add
+        end";
+
+    let [a_re, a_im, b_re, b_im] = rand_array::<Felt, 4>();
+    let stack = [a_re, a_im, b_re, b_im].map(|v| v.as_int());
+
+    let expected = [b_im + a_im, b_re + a_re].map(|v| v.as_int());
+    build_test!(source, &stack).expect_stack(&expected);
+}
+
+#[test]
+fn mul() {
+    let source = "
+        use.std::math::complex
+        begin
+            exec.complex::mul
+        end";
+
+    let [a, b, c, d] = rand_array::<Felt, 4>();
+    let stack = [a, b, c, d].map(|v| v.as_int());
+
+    // (a + b * x) * (c + d * x) = ac + (ad + bc) * x + bd * x^2, where x^2 = x - 2
+    let re = a * c - Felt::new(2) * b * d;
+    let im = a * d + b * c + b * d;
+    build_test!(source, &stack).expect_stack(&[im.as_int(), re.as_int()]);
+}
+
+#[test]
+fn conj() {
+    let source = "
+        use.std::math::complex
+        begin
+            exec.complex::conj
+        end";
+
+    let [re, im] = rand_array::<Felt, 2>();
+    let expected = [-im, re + im].map(|v| v.as_int());
+    build_test!(source, &[re.as_int(), im.as_int()]).expect_stack(&expected);
+}
+
+#[test]
+fn conj_is_involution() {
+    let source = "
+        use.std::math::complex
+        begin
+            exec.complex::conj
+            exec.complex::conj
+        end";
+
+    let [re, im] = rand_array::<Felt, 2>().map(|v| v.as_int());
+    build_test!(source, &[re, im]).expect_stack(&[im, re]);
+}
+
+#[test]
+fn mul_by_conj_is_in_base_field() {
+    let source = "
+        use.std::math::complex
+        begin
+            dup.1 dup.1 exec.complex::conj
+            exec.complex::mul
+        end";
+
+    // z * conj(z) is the norm of z, and thus, its imaginary part is zero
+    let [re, im] = rand_array::<Felt, 2>();
+    let norm = re * re + re * im + Felt::new(2) * im * im;
+    build_test!(source, &[re.as_int(), im.as_int()]).expect_stack(&[0, norm.as_int()]);
+}
@@ -0,0 +1,129 @@
+use crate::build_test;
+use test_utils::{rand::rand_value, Felt, FieldElement, StarkField, TestError, ONE, ZERO};
+
+#[test]
+fn pow_signed() {
+    let source = "
+        use.std::math::felt
+        begin
+            exec.felt::pow_signed
+        end";
+
+    let base = rand_value::<Felt>();
+    let cases = [
+        // non-negative exponents
+        (base, 0),
+        (base, 1),
+        (base, 7),
+        (base, rand_value::<u32>() as u64),
+        (base, Felt::MODULUS / 2),
+        (ZERO, 0),
+        (ZERO, 5),
+        // negative exponents
+        (base, Felt::MODULUS - 1),
+        (base, Felt::MODULUS - 7),
+        (base, Felt::MODULUS / 2 + 1),
+        (ONE, Felt::MODULUS - 3),
+    ];
+
+    for (base, exp) in cases {
+        let expected = if exp > Felt::MODULUS / 2 {
+            base.exp(Felt::MODULUS - exp).inv()
+        } else {
+            base.exp(exp)
+        };
+
+        let test = build_test!(source, &[base.as_int(), exp]);
+        test.expect_stack(&[expected.as_int()]);
+    }
+}
+
+#[test]
+fn pow_signed_negative_exp_of_zero() {
+    let source = "
+        use.std::math::felt
+        begin
+            exec.felt::pow_signed
+        end";
+
+    let test = build_test!(source, &[0, Felt::MODULUS - 1]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn pow_ct() {
+    let source = "
+        use.std::math::felt
+        begin
+            exec.felt::pow_ct
+        end";
+
+    let base = rand_value::<Felt>();
+    let exponents = [0, 1, 2, 7, 1 << 32, rand_value::<u64>() % Felt::MODULUS, Felt::MODULUS - 1];
+
+    let mut cycle_counts = Vec::new();
+    for exp in exponents {
+        // the result must match the native exp instruction
+        let expected = base.exp(exp).as_int();
+        build_test!("begin exp end", &[base.as_int(), exp]).expect_stack(&[expected]);
+
+        let test = build_test!(source, &[base.as_int(), exp]);
+        test.expect_stack(&[expected]);
+        cycle_counts.push(test.execute_process().unwrap().cycle_count());
+    }
+
+    // the number of cycles must not depend on the exponent
+    assert!(cycle_counts.iter().all(|&count| count == cycle_counts[0]));
+
+    // powers of zero are computed as well
+    build_test!(source, &[0, 0]).expect_stack(&[1]);
+    build_test!(source, &[0, 5]).expect_stack(&[0]);
+}
+
+#[test]
+fn try_inv() {
+    let source = "
+        use.std::math::felt
+        begin
+            exec.felt::try_inv
+        end";
+
+    // the inverse of zero is undefined, so the flag is not set
+    let test = build_test!(source, &[0]);
+    test.expect_stack(&[0, 0]);
+
+    let a = rand_value::<Felt>();
+    for value in [ONE, Felt::new(64), Felt::new(Felt::MODULUS - 1), a] {
+        if value == ZERO {
+            continue;
+        }
+        let test = build_test!(source, &[value.as_int()]);
+        test.expect_stack(&[1, value.inv().as_int()]);
+    }
+}
+
+#[test]
+fn try_inv_times_value_is_one() {
+    let source = "
+        use.std::math::felt
+        begin
+            dup exec.felt::try_inv assert mul
+        end";
+
+    let a = rand_value::<u64>() % (Felt::MODULUS - 1) + 1;
+    for value in [1, 2, Felt::MODULUS - 1, a] {
+        let test = build_test!(source, &[value]);
+        test.expect_stack(&[1]);
+    }
+}
+
+#[test]
+fn inv_of_zero() {
+    let source = "
+        begin
+            inv
+        end";
+
+    let test = build_test!(source, &[0]);
+    test.expect_error(TestError::ExecutionError("DivideByZero"));
+}
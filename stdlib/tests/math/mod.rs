@@ -1,6 +1,9 @@
+mod complex;
 mod ecgfp5;
+mod felt;
 mod ntt512;
 mod poly512;
 mod secp256k1;
 mod u256_mod;
+mod u32_mod;
 mod u64_mod;
@@ -0,0 +1,116 @@
+use crate::build_test;
+use num_bigint::BigUint;
+use test_utils::rand::rand_array;
+
+// FIELD ARITHMETIC
+// ================================================================================================
+
+#[test]
+fn test_secp256k1_field_add() {
+    let p = modulus();
+    for (a, b) in sample_pairs() {
+        let expected = (&a + &b) % &p;
+        run_binary_op("add", &a, &b, &expected);
+    }
+}
+
+#[test]
+fn test_secp256k1_field_sub() {
+    let p = modulus();
+    for (a, b) in sample_pairs() {
+        let expected = (&a + &p - &b) % &p;
+        run_binary_op("sub", &a, &b, &expected);
+    }
+}
+
+#[test]
+fn test_secp256k1_field_mul() {
+    let p = modulus();
+    for (a, b) in sample_pairs() {
+        let expected = (&a * &b) % &p;
+        run_binary_op("mul", &a, &b, &expected);
+    }
+}
+
+#[test]
+fn test_secp256k1_field_inv() {
+    let p = modulus();
+    let source = "
+    use.std::math::secp256k1::field
+
+    begin
+        exec.field::inv
+    end";
+
+    for (a, _) in sample_pairs() {
+        // the inverse is computed via Fermat's little theorem, i.e., a^-1 = a^(p - 2)
+        let expected = a.modpow(&(&p - 2u32), &p);
+
+        let test = build_test!(source, &to_stack(&[&a]));
+        test.expect_stack(&to_limbs(&expected));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the secp256k1 base field prime p = 2^256 - 2^32 - 977.
+fn modulus() -> BigUint {
+    (BigUint::from(1u32) << 256) - (BigUint::from(1u32) << 32) - 977u32
+}
+
+/// Returns pairs of field elements covering edge cases around zero and the modulus, as well as
+/// random elements.
+fn sample_pairs() -> Vec<(BigUint, BigUint)> {
+    let p = modulus();
+    let zero = BigUint::from(0u32);
+    let one = BigUint::from(1u32);
+    let p_minus_1 = &p - 1u32;
+    let p_minus_2 = &p - 2u32;
+    let rand_a = BigUint::from_slice(&rand_array::<u32, 8>()) % &p;
+    let rand_b = BigUint::from_slice(&rand_array::<u32, 8>()) % &p;
+
+    vec![
+        (zero.clone(), zero.clone()),
+        (one.clone(), zero),
+        (p_minus_1.clone(), one.clone()),
+        (one, p_minus_1.clone()),
+        (p_minus_1.clone(), p_minus_1.clone()),
+        (p_minus_2, p_minus_1),
+        (rand_a.clone(), rand_b.clone()),
+        (rand_b, rand_a),
+    ]
+}
+
+/// Executes the specified binary operation of the field module against the provided operands and
+/// checks that the result matches the expected value.
+fn run_binary_op(op: &str, a: &BigUint, b: &BigUint, expected: &BigUint) {
+    let source = format!(
+        "
+    use.std::math::secp256k1::field
+
+    begin
+        exec.field::{op}
+    end"
+    );
+
+    let test = build_test!(&source, &to_stack(&[a, b]));
+    test.expect_stack(&to_limbs(expected));
+}
+
+/// Returns the eight 32-bit limbs of the provided value, least significant limb first.
+fn to_limbs(value: &BigUint) -> [u64; 8] {
+    let mut limbs = [0u64; 8];
+    for (limb, digit) in limbs.iter_mut().zip(value.to_u32_digits()) {
+        *limb = digit as u64;
+    }
+    limbs
+}
+
+/// Returns stack inputs which place the limbs of the provided values on the stack such that the
+/// least significant limb of the first value is at the top of the stack.
+fn to_stack(values: &[&BigUint]) -> Vec<u64> {
+    let mut stack = values.iter().flat_map(|&value| to_limbs(value)).collect::<Vec<_>>();
+    stack.reverse();
+    stack
+}
@@ -1,3 +1,4 @@
 mod base_field;
+mod field;
 mod group;
 mod scalar_field;
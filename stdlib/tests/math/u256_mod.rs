@@ -1,6 +1,7 @@
 use crate::build_test;
+use core::cmp::Ordering;
 use num_bigint::BigUint;
-use test_utils::rand::rand_vector;
+use test_utils::{rand::rand_vector, Felt, StarkField};
 
 // MULTIPLICATION
 // ================================================================================================
@@ -33,6 +34,37 @@ fn mul_unsafe() {
     build_test!(source, &operands).expect_stack(&result);
 }
 
+// COMPARISONS
+// ================================================================================================
+
+#[test]
+fn cmp() {
+    for (a, b) in comparison_cases() {
+        let expected = match a.cmp(&b) {
+            Ordering::Less => Felt::MODULUS - 1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        };
+        build_comparison_test("cmp", &a, &b).expect_stack(&[expected]);
+    }
+}
+
+#[test]
+fn lt() {
+    for (a, b) in comparison_cases() {
+        let expected = (a < b) as u64;
+        build_comparison_test("lt", &a, &b).expect_stack(&[expected]);
+    }
+}
+
+#[test]
+fn eq() {
+    for (a, b) in comparison_cases() {
+        let expected = (a == b) as u64;
+        build_comparison_test("eq", &a, &b).expect_stack(&[expected]);
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -40,3 +72,45 @@ fn rand_u256() -> BigUint {
     let limbs = rand_vector::<u64>(8).iter().map(|&v| v as u32).collect::<Vec<_>>();
     BigUint::new(limbs)
 }
+
+/// Returns pairs of values which are equal, differ in the most significant limb, and differ only in
+/// the least significant limb, in both orders.
+fn comparison_cases() -> Vec<(BigUint, BigUint)> {
+    let a = rand_u256();
+    let high = BigUint::from(1_u32) << 224;
+    let low = BigUint::from(1_u32);
+
+    // clear the most significant bit and the least significant limb of the smaller value, so that
+    // adding the difference to it changes only the targeted limb
+    let smaller: BigUint = (a.clone() % (BigUint::from(1_u32) << 255)) >> 32 << 32;
+
+    let mut cases = vec![(a.clone(), a), (BigUint::default(), BigUint::default())];
+    for delta in [high, low] {
+        let larger = smaller.clone() + delta;
+        cases.push((smaller.clone(), larger.clone()));
+        cases.push((larger, smaller.clone()));
+    }
+    cases
+}
+
+/// Builds a test which executes the specified comparison procedure of `std::math::u256` against
+/// the provided values.
+fn build_comparison_test(procedure: &str, a: &BigUint, b: &BigUint) -> test_utils::Test {
+    let source = format!(
+        "
+        use.std::math::u256
+        begin
+            exec.u256::{procedure}
+        end"
+    );
+
+    let operands = u256_to_limbs(a).into_iter().chain(u256_to_limbs(b)).collect::<Vec<_>>();
+    build_test!(&source, &operands)
+}
+
+/// Returns the 32-bit limbs of the provided value, starting with the least significant limb.
+fn u256_to_limbs(value: &BigUint) -> Vec<u64> {
+    let mut limbs = value.to_u32_digits().iter().map(|&v| v as u64).collect::<Vec<_>>();
+    limbs.resize(8, 0);
+    limbs
+}
@@ -0,0 +1,250 @@
+use crate::build_test;
+use test_utils::{rand::rand_value, Felt, StarkField, TestError, U32_BOUND};
+
+// CONVERSIONS
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn to_bytes() {
+    let source = "
+        use.std::math::u32
+        begin
+            exec.u32::to_bytes
+        end";
+
+    // --- simple cases ---------------------------------------------------------------------------
+    let test = build_test!(source, &[0x12345678]);
+    test.expect_stack(&[0x78, 0x56, 0x34, 0x12]);
+
+    let test = build_test!(source, &[0]);
+    test.expect_stack(&[0, 0, 0, 0]);
+
+    let test = build_test!(source, &[u32::MAX as u64]);
+    test.expect_stack(&[0xff, 0xff, 0xff, 0xff]);
+
+    // --- random value ---------------------------------------------------------------------------
+    let a = rand_value::<u64>() as u32;
+    let expected = a.to_le_bytes().map(|b| b as u64);
+    let test = build_test!(source, &[a as u64]);
+    test.expect_stack(&expected);
+
+    // --- input is not a u32 ---------------------------------------------------------------------
+    let test = build_test!(source, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn from_bytes() {
+    let source = "
+        use.std::math::u32
+        begin
+            exec.u32::from_bytes
+        end";
+
+    // --- simple case ----------------------------------------------------------------------------
+    // the least significant byte is expected to be at the top of the stack
+    let test = build_test!(source, &[0x12, 0x34, 0x56, 0x78]);
+    test.expect_stack(&[0x12345678]);
+
+    // --- random value ---------------------------------------------------------------------------
+    let a = rand_value::<u64>() as u32;
+    let mut bytes = a.to_le_bytes().map(|b| b as u64);
+    bytes.reverse();
+    let test = build_test!(source, &bytes);
+    test.expect_stack(&[a as u64]);
+}
+
+#[test]
+fn from_bytes_fail() {
+    let source = "
+        use.std::math::u32
+        begin
+            exec.u32::from_bytes
+        end";
+
+    // --- byte out of range ----------------------------------------------------------------------
+    let test = build_test!(source, &[0x12, 256, 0x56, 0x78]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // --- value is not a u32 ---------------------------------------------------------------------
+    let test = build_test!(source, &[0x12, 0x34, 0x56, U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn to_bytes_from_bytes_round_trip() {
+    let source = "
+        use.std::math::u32
+        begin
+            exec.u32::to_bytes
+            exec.u32::from_bytes
+        end";
+
+    for a in [0, 1, 255, 256, 0xdeadbeef, u32::MAX, rand_value::<u64>() as u32] {
+        let test = build_test!(source, &[a as u64]);
+        test.expect_stack(&[a as u64]);
+    }
+}
+
+#[test]
+fn from_felt() {
+    let source = "
+        use.std::math::u32
+        begin
+            exec.u32::from_felt
+        end";
+
+    // --- values which fit into 32 bits ----------------------------------------------------------
+    for a in [0, 1, u32::MAX as u64, rand_value::<u32>() as u64] {
+        let test = build_test!(source, &[a]);
+        test.expect_stack(&[a]);
+    }
+
+    // --- values which do not fit into 32 bits ---------------------------------------------------
+    for a in [U32_BOUND, U32_BOUND + 1, Felt::MODULUS - 1] {
+        let test = build_test!(source, &[a]);
+        test.expect_error(TestError::ExecutionError("FailedAssertion"));
+    }
+}
+
+#[test]
+fn to_felt() {
+    let source = "
+        use.std::math::u32
+        begin
+            exec.u32::to_felt
+        end";
+
+    let a = rand_value::<u32>() as u64;
+    let test = build_test!(source, &[a]);
+    test.expect_stack(&[a]);
+
+    // --- input is not a u32 ---------------------------------------------------------------------
+    let test = build_test!(source, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn from_felt_to_felt_round_trip() {
+    let source = "
+        use.std::math::u32
+        begin
+            exec.u32::from_felt
+            exec.u32::to_felt
+        end";
+
+    // the rest of the stack is not affected by the conversions
+    for a in [0, 1, 0xdeadbeef, u32::MAX as u64, rand_value::<u32>() as u64] {
+        let test = build_test!(source, &[7, 8, a]);
+        test.expect_stack(&[a, 8, 7]);
+    }
+}
+
+// VALIDATION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn is_u32() {
+    let source = "
+        use.std::math::u32
+        begin
+            exec.u32::is_u32
+        end";
+
+    // --- valid u32 values -----------------------------------------------------------------------
+    build_test!(source, &[0]).expect_stack(&[1]);
+    build_test!(source, &[u32::MAX as u64]).expect_stack(&[1]);
+    build_test!(source, &[rand_value::<u64>() as u32 as u64]).expect_stack(&[1]);
+
+    // --- values which are out of range ----------------------------------------------------------
+    build_test!(source, &[U32_BOUND]).expect_stack(&[0]);
+    build_test!(source, &[Felt::MODULUS - 1]).expect_stack(&[0]);
+}
+
+#[test]
+fn is_u32_word() {
+    let source = "
+        use.std::math::u32
+        begin
+            exec.u32::is_u32_word
+        end";
+
+    // --- all lanes are u32 values ---------------------------------------------------------------
+    build_test!(source, &[1, 2, 3, u32::MAX as u64]).expect_stack(&[1]);
+
+    // --- a single lane is out of range ----------------------------------------------------------
+    for i in 0..4 {
+        let mut word = [1, 2, 3, 4];
+        word[i] = U32_BOUND;
+        build_test!(source, &word).expect_stack(&[0]);
+    }
+}
+
+#[test]
+fn assert_all_u32() {
+    let source = "
+        use.std::math::u32
+        begin
+            exec.u32::assert_all_u32
+        end";
+
+    // --- all lanes are u32 values ---------------------------------------------------------------
+    build_test!(source, &[1, 2, 3, u32::MAX as u64]).expect_stack(&[u32::MAX as u64, 3, 2, 1]);
+
+    // --- a single lane is out of range ----------------------------------------------------------
+    let test = build_test!(source, &[1, 2, U32_BOUND, 4]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// ARITHMETIC OPERATIONS
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn checked_neg() {
+    let source = "
+        use.std::math::u32
+        begin
+            exec.u32::checked_neg
+        end";
+
+    // --- edge cases -----------------------------------------------------------------------------
+    for a in [0, 1, 0x80000000, u32::MAX] {
+        let expected = (a as i32).wrapping_neg() as u32;
+        let test = build_test!(source, &[a as u64]);
+        test.expect_stack(&[expected as u64]);
+    }
+
+    // --- random value ---------------------------------------------------------------------------
+    let a = rand_value::<u64>() as u32;
+    let expected = (a as i32).wrapping_neg() as u32;
+    let test = build_test!(source, &[a as u64]);
+    test.expect_stack(&[expected as u64]);
+
+    // --- input is not a u32 ---------------------------------------------------------------------
+    let test = build_test!(source, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn checked_pow2() {
+    let source = "
+        use.std::math::u32
+        begin
+            exec.u32::checked_pow2
+        end";
+
+    // --- exponents within range -----------------------------------------------------------------
+    for a in [0, 1, 16, 31] {
+        let test = build_test!(source, &[a]);
+        test.expect_stack(&[1 << a]);
+    }
+
+    // --- exponents out of range -----------------------------------------------------------------
+    for a in [32, 63] {
+        let test = build_test!(source, &[a]);
+        test.expect_error(TestError::ExecutionError("FailedAssertion"));
+    }
+
+    let test = build_test!(source, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
@@ -257,6 +257,40 @@ fn wrapping_mul() {
     test.expect_stack(&[c1, c0]);
 }
 
+/// A procedure name paired with the reference implementation of the operation it performs.
+type BinOp = (&'static str, fn(u64, u64) -> u64);
+
+#[test]
+fn wrapping_ops_boundary_values() {
+    let values = [0, 1, U32_BOUND - 1, U32_BOUND, u64::MAX - 1, u64::MAX];
+    let ops: [BinOp; 3] = [
+        ("wrapping_add", u64::wrapping_add),
+        ("wrapping_sub", u64::wrapping_sub),
+        ("wrapping_mul", u64::wrapping_mul),
+    ];
+
+    for (procedure, op) in ops {
+        let source = format!(
+            "
+            use.std::math::u64
+            begin
+                exec.u64::{procedure}
+            end"
+        );
+
+        for a in values {
+            for b in values {
+                let (a1, a0) = split_u64(a);
+                let (b1, b0) = split_u64(b);
+                let (c1, c0) = split_u64(op(a, b));
+
+                let test = build_test!(&source, &[a0, a1, b0, b1]);
+                test.expect_stack(&[c1, c0]);
+            }
+        }
+    }
+}
+
 #[test]
 fn checked_mul() {
     let source = "
@@ -326,6 +360,48 @@ fn checked_mul_fail() {
     test.expect_error(TestError::ExecutionError("FailedAssertion"));
 }
 
+#[test]
+fn mul_full() {
+    let source = "
+    use.std::math::u64
+    begin
+        exec.u64::mul_full
+    end";
+
+    // maximum values, zero operands and a mid-range pair
+    for (a, b) in [
+        (u64::MAX, u64::MAX),
+        (0, rand_value()),
+        (rand_value(), 0),
+        (0xdeadbeef_12345678, 0x1_00000001),
+    ] {
+        let c = a as u128 * b as u128;
+
+        let (a1, a0) = split_u64(a);
+        let (b1, b0) = split_u64(b);
+        let (c3, c2, c1, c0) = split_u128(c);
+
+        let test = build_test!(source, &[a0, a1, b0, b1]);
+        test.expect_stack(&[c3, c2, c1, c0]);
+    }
+}
+
+#[test]
+fn mul_full_fail() {
+    let source = "
+    use.std::math::u64
+    begin
+        exec.u64::mul_full
+    end";
+
+    for i in 0..4 {
+        let mut stack_init = [1, 2, 3, 4];
+        stack_init[i] = U32_BOUND;
+        let test = build_test!(source, &stack_init);
+        test.expect_error(TestError::ExecutionError("NotU32Value"));
+    }
+}
+
 #[test]
 fn overflowing_mul() {
     let source = "
@@ -1158,6 +1234,231 @@ fn unchecked_rotr() {
     build_test!(source, &[5, a0, a1, b as u64]).expect_stack(&[c1, c0, 5]);
 }
 
+#[test]
+fn checked_rotl() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::checked_rotl
+        end";
+
+    let a: u64 = rand_value();
+    let (a1, a0) = split_u64(a);
+
+    // rotate by 0, by 32 (swaps the limbs), and by arbitrary amounts
+    for b in [0_u32, 32, 1, 31, 33, 63, rand_value::<u32>() % 64] {
+        let c = a.rotate_left(b);
+        let (c1, c0) = split_u64(c);
+
+        build_test!(source, &[5, a0, a1, b as u64]).expect_stack(&[c1, c0, 5]);
+    }
+}
+
+#[test]
+fn checked_rotl_fail() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::checked_rotl
+        end";
+
+    // rotation value is out of range
+    let test = build_test!(source, &[1, 2, 64]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // the value is not represented using 32-bit limbs
+    let test = build_test!(source, &[1, U32_BOUND, 5]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn checked_rotr() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::checked_rotr
+        end";
+
+    let a: u64 = rand_value();
+    let (a1, a0) = split_u64(a);
+
+    // rotate by 0, by 32 (swaps the limbs), and by arbitrary amounts
+    for b in [0_u32, 32, 1, 31, 33, 63, rand_value::<u32>() % 64] {
+        let c = a.rotate_right(b);
+        let (c1, c0) = split_u64(c);
+
+        build_test!(source, &[5, a0, a1, b as u64]).expect_stack(&[c1, c0, 5]);
+    }
+}
+
+#[test]
+fn checked_rotr_fail() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::checked_rotr
+        end";
+
+    // rotation value is out of range
+    let test = build_test!(source, &[1, 2, 64]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // the value is not represented using 32-bit limbs
+    let test = build_test!(source, &[U32_BOUND, 1, 5]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+// BIT COUNTING
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn clz() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::clz
+        end";
+
+    // --- zero, powers of two in both limbs, and max value ---------------------------------------
+    let mut values = vec![0, u64::MAX];
+    values.extend((0..64).map(|i| 1_u64 << i));
+    for a in values {
+        let (a1, a0) = split_u64(a);
+        build_test!(source, &[a0, a1]).expect_stack(&[a.leading_zeros() as u64]);
+    }
+
+    // --- random values --------------------------------------------------------------------------
+    let a: u64 = rand_value();
+    let (a1, a0) = split_u64(a);
+    build_test!(source, &[a0, a1]).expect_stack(&[a.leading_zeros() as u64]);
+
+    let a = rand_value::<u64>() as u32 as u64;
+    build_test!(source, &[a, 0]).expect_stack(&[a.leading_zeros() as u64]);
+
+    // --- invalid limbs --------------------------------------------------------------------------
+    build_test!(source, &[0, U32_BOUND]).expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn bit_length() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::bit_length
+        end";
+
+    // --- zero, powers of two in both limbs, and max value ---------------------------------------
+    let mut values = vec![0, u64::MAX];
+    values.extend((0..64).map(|i| 1_u64 << i));
+    for a in values {
+        let (a1, a0) = split_u64(a);
+        let expected = 64 - a.leading_zeros() as u64;
+        build_test!(source, &[a0, a1]).expect_stack(&[expected]);
+    }
+
+    // --- random value ---------------------------------------------------------------------------
+    let a: u64 = rand_value();
+    let (a1, a0) = split_u64(a);
+    build_test!(source, &[a0, a1]).expect_stack(&[64 - a.leading_zeros() as u64]);
+}
+
+#[test]
+fn log2() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::log2
+        end";
+
+    // --- powers of two in both limbs, values just below them, and max value --------------------
+    let mut values = vec![u64::MAX];
+    values.extend((0..64).map(|i| 1_u64 << i));
+    values.extend((1..64).map(|i| (1_u64 << i) - 1));
+    for a in values {
+        let (a1, a0) = split_u64(a);
+        let expected = 63 - a.leading_zeros() as u64;
+        build_test!(source, &[a0, a1]).expect_stack(&[expected]);
+    }
+
+    // --- random value ---------------------------------------------------------------------------
+    let a: u64 = rand_value::<u64>().max(1);
+    let (a1, a0) = split_u64(a);
+    build_test!(source, &[a0, a1]).expect_stack(&[63 - a.leading_zeros() as u64]);
+
+    // --- zero -----------------------------------------------------------------------------------
+    build_test!(source, &[0, 0]).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// CONVERSIONS
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn from_bytes() {
+    let values = [0, 1, 0x0123456789abcdef, u32::MAX as u64, u64::MAX, rand_value::<u64>()];
+    for a in values {
+        let (a1, a0) = split_u64(a);
+
+        // the first byte is expected to be at the top of the stack
+        let mut le_bytes = a.to_le_bytes().map(|b| b as u64);
+        le_bytes.reverse();
+        assert_eq!(a, u64::from_le_bytes(a.to_le_bytes()));
+        build_test!(from_bytes_source("from_le_bytes"), &le_bytes).expect_stack(&[a1, a0]);
+
+        let mut be_bytes = a.to_be_bytes().map(|b| b as u64);
+        be_bytes.reverse();
+        assert_eq!(a, u64::from_be_bytes(a.to_be_bytes()));
+        build_test!(from_bytes_source("from_be_bytes"), &be_bytes).expect_stack(&[a1, a0]);
+    }
+}
+
+#[test]
+fn from_bytes_fail() {
+    // --- byte out of range ----------------------------------------------------------------------
+    for procedure in ["from_le_bytes", "from_be_bytes"] {
+        let test = build_test!(from_bytes_source(procedure), &[1, 2, 3, 4, 5, 256, 7, 8]);
+        test.expect_error(TestError::ExecutionError("FailedAssertion"));
+    }
+}
+
+#[test]
+fn to_bytes() {
+    let values = [0, 1, 0x0123456789abcdef, u32::MAX as u64, u64::MAX, rand_value::<u64>()];
+    for a in values {
+        let (a1, a0) = split_u64(a);
+
+        let expected = a.to_le_bytes().map(|b| b as u64);
+        build_test!(to_bytes_source("to_le_bytes"), &[a0, a1]).expect_stack(&expected);
+
+        let expected = a.to_be_bytes().map(|b| b as u64);
+        build_test!(to_bytes_source("to_be_bytes"), &[a0, a1]).expect_stack(&expected);
+    }
+
+    // --- invalid limbs --------------------------------------------------------------------------
+    for procedure in ["to_le_bytes", "to_be_bytes"] {
+        let test = build_test!(to_bytes_source(procedure), &[0, U32_BOUND]);
+        test.expect_error(TestError::ExecutionError("NotU32Value"));
+    }
+}
+
+#[test]
+fn to_bytes_from_bytes_round_trip() {
+    for endianness in ["le", "be"] {
+        let source = format!(
+            "
+            use.std::math::u64
+            begin
+                exec.u64::to_{endianness}_bytes
+                exec.u64::from_{endianness}_bytes
+            end"
+        );
+
+        for a in [0, 0x0123456789abcdef, u64::MAX, rand_value::<u64>()] {
+            let (a1, a0) = split_u64(a);
+            build_test!(&source, &[a0, a1]).expect_stack(&[a1, a0]);
+        }
+    }
+}
+
 // RANDOMIZED TESTS
 // ================================================================================================
 
@@ -1328,11 +1629,51 @@ proptest! {
 
         build_test!(source, &[5, a0, a1, b as u64]).prop_expect_stack(&[c1, c0, 5])?;
     }
+
+    #[test]
+    fn clz_proptest(a in any::<u64>()) {
+
+        let c = a.leading_zeros() as u64;
+
+        let (a1, a0) = split_u64(a);
+
+        let source = "
+        use.std::math::u64
+        begin
+            exec.u64::clz
+        end";
+
+        build_test!(source, &[5, a0, a1]).prop_expect_stack(&[c, 5])?;
+    }
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns the source of a program which assembles a u64 value from bytes using the specified
+/// procedure.
+fn from_bytes_source(procedure: &str) -> String {
+    format!(
+        "
+        use.std::math::u64
+        begin
+            exec.u64::{procedure}
+        end"
+    )
+}
+
+/// Returns the source of a program which splits a u64 value into bytes using the specified
+/// procedure.
+fn to_bytes_source(procedure: &str) -> String {
+    format!(
+        "
+        use.std::math::u64
+        begin
+            exec.u64::{procedure}
+        end"
+    )
+}
+
 /// Split the provided u64 value into 32 high and low bits.
 fn split_u64(value: u64) -> (u64, u64) {
     (value >> 32, value as u32 as u64)
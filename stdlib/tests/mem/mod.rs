@@ -47,6 +47,32 @@ fn test_memcopy() {
     assert_eq!(process.get_memory_value(0, 2004), Some([ZERO, ONE, ZERO, ONE]), "Address 2004");
 }
 
+#[test]
+fn test_zero_locals() {
+    // locals 0 - 2 are zeroed, and local 3 is left untouched
+    let source = "
+    use.std::mem
+
+    proc.foo.4
+        push.1.2.3.4
+        loc_storew.0 loc_storew.1 loc_storew.2 loc_storew.3
+        dropw
+
+        locaddr.0.3 push.3 exec.mem::zero_locals
+
+        padw loc_loadw.0 padw loc_loadw.1 padw loc_loadw.2 padw loc_loadw.3
+    end
+
+    begin
+        exec.foo
+    end
+    ";
+
+    let mut expected = vec![0; 16];
+    expected[..4].copy_from_slice(&[4, 3, 2, 1]);
+    build_test!(source, &[]).expect_stack(&expected);
+}
+
 #[test]
 fn test_pipe_double_words_to_memory() {
     let mem_addr = 1000;
@@ -1,5 +1,9 @@
 use crate::build_test;
-use test_utils::{proptest::prelude::*, rand::rand_vector, STACK_TOP_SIZE};
+use processor::code_blocks::CodeBlock;
+use test_utils::{
+    proptest::prelude::*, rand::rand_vector, StackInputs, StarkField, Test, TestError, Word,
+    STACK_TOP_SIZE,
+};
 
 #[test]
 fn truncate_stack() {
@@ -8,6 +12,38 @@ fn truncate_stack() {
     test.expect_stack(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4]);
 }
 
+#[test]
+fn stack_guard_neutral_body() {
+    let source = build_stack_guard_source("push.1 push.2 add drop");
+    let test = build_test!(&source, &[7]);
+    test.expect_stack(&[5, 7]);
+}
+
+#[test]
+fn stack_guard_unbalanced_body() {
+    // the body leaves an extra element on the stack
+    let source = build_stack_guard_source("push.1 push.2");
+    let test = build_test!(&source, &[7]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // the body consumes an element of the stack
+    let source = build_stack_guard_source("drop");
+    let test = build_test!(&source, &[7]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn require_caller_allowed() {
+    let test = build_require_caller_test("bar");
+    test.expect_stack(&[9]);
+}
+
+#[test]
+fn require_caller_not_allowed() {
+    let test = build_require_caller_test("baz");
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
 proptest! {
     #[test]
     fn truncate_stack_proptest(test_values in prop::collection::vec(any::<u64>(), STACK_TOP_SIZE), n in 1_usize..100) {
@@ -27,3 +63,65 @@ proptest! {
         build_test!(&source, &test_values).prop_expect_stack(&expected_values)?;
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a program which pushes 5 onto the stack and then executes the provided body in a
+/// procedure guarded by `std::sys::stack_guard`.
+fn build_stack_guard_source(body: &str) -> String {
+    format!(
+        "
+        use.std::sys::stack_guard
+
+        proc.guarded.1
+            exec.stack_guard::enter loc_store.0
+            {body}
+            loc_load.0 exec.stack_guard::exit
+        end
+
+        begin
+            push.5
+            exec.guarded
+        end"
+    )
+}
+
+/// Returns a test in which the specified procedure invokes a kernel procedure guarded by
+/// `std::sys::access::require_caller`; only `bar` is allowed to invoke the guarded procedure.
+fn build_require_caller_test(caller: &str) -> Test {
+    let kernel_source = "
+        use.std::sys::access
+
+        export.protected
+            exec.access::require_caller
+        end";
+
+    let source = format!(
+        "
+        proc.bar
+            syscall.protected
+        end
+
+        proc.baz
+            push.1 drop
+            syscall.protected
+        end
+
+        begin
+            call.{caller}
+        end"
+    );
+
+    let mut test = build_test!(&source);
+    test.kernel = Some(kernel_source.to_string());
+
+    // the body of `bar` consists of a single syscall to the only procedure of the kernel
+    let kernel = test.compile().kernel().clone();
+    let bar_hash: Word = CodeBlock::new_syscall(kernel.proc_hashes()[0]).hash().into();
+
+    let mut inputs = vec![9];
+    inputs.extend(bar_hash.iter().map(StarkField::as_int));
+    test.stack_inputs = StackInputs::try_from_values(inputs).unwrap();
+    test
+}
@@ -19,8 +19,8 @@ pub use vm_core::chiplets::hasher::{hash_elements, STATE_WIDTH};
 
 pub use assembly::{Library, MaslLibrary};
 pub use processor::{
-    AdviceInputs, AdviceProvider, ExecutionError, ExecutionTrace, Process, StackInputs,
-    VmStateIterator,
+    AdviceInputs, AdviceProvider, AdviceRead, ExecutionError, ExecutionTrace, Process,
+    ScriptedAdviceProvider, StackInputs, VmStateIterator,
 };
 pub use prover::{prove, MemAdviceProvider, ProofOptions};
 pub use test_case::test_case;
@@ -275,6 +275,57 @@ impl Test {
     }
 }
 
+// DETERMINISM CHECKS
+// ================================================================================================
+
+/// Executes and proves the specified program twice with the same inputs and asserts that both runs
+/// result in identical stack outputs and identical proof bytes.
+///
+/// Only the top `num_outputs` elements of the stack are compared. This is useful for catching
+/// non-determinism in the processor or in advice providers (e.g., leaking iteration order of
+/// hash-based collections into the execution trace).
+#[cfg(feature = "internals")]
+pub fn execute_twice_assert_equal(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+    num_outputs: usize,
+    options: ProofOptions,
+) {
+    let mut runs = (0..2).map(|_| {
+        let advice_provider = MemAdviceProvider::from(advice_inputs.clone());
+        let (outputs, proof) =
+            prover::prove(program, stack_inputs.clone(), advice_provider, options.clone())
+                .expect("failed to execute and prove the program");
+        (outputs.stack_truncated(num_outputs).to_vec(), proof.to_bytes())
+    });
+
+    let (first_outputs, first_proof) = runs.next().expect("missing first run");
+    let (second_outputs, second_proof) = runs.next().expect("missing second run");
+
+    assert_eq!(first_outputs, second_outputs, "stack outputs differ between runs");
+    assert!(first_proof == second_proof, "proof bytes differ between runs");
+}
+
+// CYCLE BUDGET CHECKS
+// ================================================================================================
+
+/// Executes the specified program with the provided stack inputs and asserts that the execution
+/// completes within `max_cycles` VM cycles.
+///
+/// This is useful for guarding against performance regressions in frequently used procedures.
+pub fn assert_max_cycles(program: &Program, stack_inputs: StackInputs, max_cycles: u64) {
+    let mut process =
+        Process::new(program.kernel().clone(), stack_inputs, MemAdviceProvider::default());
+    process.execute(program).expect("failed to execute the program");
+
+    let cycle_count = process.cycle_count();
+    assert!(
+        cycle_count <= max_cycles,
+        "program executed in {cycle_count} cycles, exceeding the budget of {max_cycles} cycles"
+    );
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
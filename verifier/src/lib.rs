@@ -2,9 +2,13 @@
 
 use air::{HashFunction, ProcessorAir, PublicInputs};
 use core::fmt;
-use vm_core::crypto::{
-    hash::{Blake3_192, Blake3_256, Rpo256},
-    random::{RpoRandomCoin, WinterRandomCoin},
+use vm_core::{
+    crypto::{
+        hash::{Blake3_192, Blake3_256, Rpo256},
+        random::{RpoRandomCoin, WinterRandomCoin},
+    },
+    stack::MAX_STACK_OUTPUTS,
+    utils::{collections::Vec, string::ToString},
 };
 use winter_verifier::verify as verify_proof;
 
@@ -12,11 +16,12 @@ use winter_verifier::verify as verify_proof;
 // ================================================================================================
 
 pub use vm_core::{chiplets::hasher::Digest, Kernel, ProgramInfo, StackInputs, StackOutputs, Word};
-pub use winter_verifier::VerifierError;
+pub use winter_verifier::{StarkProof, VerifierError};
 pub mod math {
     pub use vm_core::{Felt, FieldElement, StarkField};
 }
-pub use air::ExecutionProof;
+pub use air::{air_info, AirInfo, ExecutionProof};
+pub use vm_core::crypto::random::Challenge;
 
 // VERIFIER
 // ================================================================================================
@@ -37,13 +42,22 @@ pub use air::ExecutionProof;
 /// order on the stack. This is the reverse of the order of the `stack_inputs` slice.
 ///
 /// # Errors
-/// Returns an error if the provided proof does not prove a correct execution of the program.
+/// Returns an error if:
+/// - The number of stack outputs is greater than [MAX_STACK_OUTPUTS]. This is checked before the
+///   proof is verified.
+/// - The provided proof does not prove a correct execution of the program.
 pub fn verify(
     program_info: ProgramInfo,
     stack_inputs: StackInputs,
     stack_outputs: StackOutputs,
     proof: ExecutionProof,
 ) -> Result<u32, VerificationError> {
+    // stack inputs cannot exceed MAX_STACK_INPUTS by construction; only outputs need checking
+    let num_outputs = stack_outputs.stack().len();
+    if num_outputs > MAX_STACK_OUTPUTS {
+        return Err(VerificationError::OutputLengthExceeded(MAX_STACK_OUTPUTS, num_outputs));
+    }
+
     // get security level of the proof
     let security_level = proof.security_level();
 
@@ -66,6 +80,121 @@ pub fn verify(
     Ok(security_level)
 }
 
+/// Returns the security level of the proof if the specified program was executed correctly against
+/// the specified inputs and outputs, and the outputs start with the expected prefix.
+///
+/// The proof is verified in the same way as in [verify()] against the full `stack_outputs`. In
+/// addition, the elements at the top of the stack (i.e., the first elements of `stack_outputs`)
+/// are compared against `expected_prefix`. This allows the caller to check only the outputs it is
+/// interested in, while still providing the full outputs needed to verify the proof.
+///
+/// # Errors
+/// Returns an error if:
+/// - The stack outputs do not start with `expected_prefix`, including the case when the prefix
+///   is longer than the outputs. The prefix is checked before the proof is verified.
+/// - The provided proof does not prove a correct execution of the program.
+pub fn verify_prefix(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    expected_prefix: &[u64],
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+) -> Result<u32, VerificationError> {
+    let outputs = stack_outputs.stack();
+    let mismatch = expected_prefix
+        .iter()
+        .enumerate()
+        .find(|&(i, expected)| outputs.get(i) != Some(expected));
+    if let Some((i, _)) = mismatch {
+        return Err(VerificationError::OutputPrefixMismatch(i));
+    }
+
+    verify(program_info, stack_inputs, stack_outputs, proof)
+}
+
+/// Returns the security level of the proof if the specified program was executed correctly against
+/// stack inputs with the specified hash.
+///
+/// This verifies proofs generated by `execute_with_hashed_inputs()` of the prover, in which the
+/// only public stack inputs are the RPO hash of the actual inputs (taken in the order in which they
+/// were provided to [StackInputs::new()]). Thus, the verifier does not need to know the inputs
+/// themselves.
+///
+/// # Errors
+/// Returns an error if the provided proof does not prove a correct execution of the program
+/// against inputs with the specified hash.
+pub fn verify_hashed(
+    program_info: ProgramInfo,
+    inputs_hash: Digest,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+) -> Result<u32, VerificationError> {
+    let stack_inputs = StackInputs::new(inputs_hash.as_elements().to_vec())
+        .expect("a digest is a valid set of stack inputs");
+    verify(program_info, stack_inputs, stack_outputs, proof)
+}
+
+/// Verifies the proof in the same way as [verify()], and additionally returns the ordered list of
+/// Fiat-Shamir challenges drawn from the public coin by the prover when the proof was generated.
+///
+/// The challenges are recovered from the transcript of the proof (see
+/// [ExecutionProof::challenges()]). Thus, for a valid proof, the returned challenges are identical
+/// to the challenges returned by the prover when the proof was generated.
+///
+/// # Errors
+/// Returns an error if the provided proof does not prove a correct execution of the program.
+pub fn verify_with_transcript(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+) -> Result<(u32, Vec<Challenge>), VerificationError> {
+    let pub_inputs =
+        PublicInputs::new(program_info.clone(), stack_inputs.clone(), stack_outputs.clone());
+    let challenges = proof.challenges(pub_inputs);
+    let security_level = verify(program_info, stack_inputs, stack_outputs, proof)?;
+    let challenges = challenges.map_err(|err| {
+        VerificationError::VerifierError(VerifierError::ProofDeserializationError(err.to_string()))
+    })?;
+
+    Ok((security_level, challenges))
+}
+
+/// Verifies proofs of execution for a batch of programs which are not required to be the same.
+///
+/// Each instance consists of the program info, stack inputs, stack outputs, and a proof in the
+/// same format as accepted by [verify()]. Instances are verified one at a time in the order in
+/// which they are provided. Each proof is verified independently via [verify()]; thus, verifying
+/// a batch costs the same as verifying each of its proofs on its own.
+///
+/// # Errors
+/// Returns the index of the first instance which failed verification together with the cause of
+/// the failure. Instances following the failed one are not verified.
+pub fn verify_mixed<I>(instances: I) -> Result<(), (usize, VerificationError)>
+where
+    I: IntoIterator<Item = (ProgramInfo, StackInputs, StackOutputs, ExecutionProof)>,
+{
+    for (idx, (program_info, stack_inputs, stack_outputs, proof)) in
+        instances.into_iter().enumerate()
+    {
+        verify(program_info, stack_inputs, stack_outputs, proof).map_err(|err| (idx, err))?;
+    }
+
+    Ok(())
+}
+
+// PROOF INSPECTION
+// ================================================================================================
+
+/// Returns the length of the execution trace attested to by the specified proof.
+///
+/// The length is derived from the size of the low-degree extension domain and the blowup factor
+/// recorded in the proof. The proof is not verified; thus, the returned value should be trusted
+/// only after the proof has been verified via [verify()] or one of its variants.
+pub fn proof_trace_length(proof: &StarkProof) -> usize {
+    proof.lde_domain_size() / proof.options().blowup_factor()
+}
+
 // ERRORS
 // ================================================================================================
 
@@ -75,6 +204,19 @@ pub enum VerificationError {
     VerifierError(VerifierError),
     InputNotFieldElement(u64),
     OutputNotFieldElement(u64),
+    OutputPrefixMismatch(usize),
+    OutputLengthExceeded(usize, usize),
+}
+
+impl VerificationError {
+    /// Returns the category of the verification failure if this error was caused by the proof
+    /// failing verification, or `None` otherwise.
+    pub fn failure(&self) -> Option<VerificationFailure> {
+        match self {
+            VerificationError::VerifierError(err) => Some(err.into()),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for VerificationError {
@@ -84,9 +226,62 @@ impl fmt::Display for VerificationError {
             VerifierError(e) => write!(f, "{e}"),
             InputNotFieldElement(i) => write!(f, "the input {i} is not a valid field element!"),
             OutputNotFieldElement(o) => write!(f, "the output {o} is not a valid field element!"),
+            OutputPrefixMismatch(i) => {
+                write!(f, "the outputs do not match the expected prefix at position {i}")
+            }
+            OutputLengthExceeded(max, len) => {
+                write!(f, "the number of stack outputs {len} exceeds the maximum of {max}")
+            }
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for VerificationError {}
+
+/// Describes which part of the STARK proof failed verification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerificationFailure {
+    /// Constraint evaluations at the out-of-domain point are inconsistent with the out-of-domain
+    /// trace frame.
+    OodConsistency,
+    /// Queried trace or constraint evaluation values could not be authenticated against their
+    /// Merkle commitments.
+    MerkleAuthentication,
+    /// The FRI proof of the low-degree of the composition polynomial is invalid.
+    Fri,
+    /// The proof-of-work for the query seed is invalid.
+    ProofOfWork,
+    /// The proof is malformed and could not be deserialized.
+    Deserialization,
+    /// The proof failed verification for any other reason (e.g., unsupported proof parameters).
+    Other,
+}
+
+impl From<&VerifierError> for VerificationFailure {
+    fn from(err: &VerifierError) -> Self {
+        match err {
+            VerifierError::InconsistentOodConstraintEvaluations => Self::OodConsistency,
+            VerifierError::TraceQueryDoesNotMatchCommitment
+            | VerifierError::ConstraintQueryDoesNotMatchCommitment => Self::MerkleAuthentication,
+            VerifierError::FriVerificationFailed(_) => Self::Fri,
+            VerifierError::QuerySeedProofOfWorkVerificationFailed => Self::ProofOfWork,
+            VerifierError::ProofDeserializationError(_) => Self::Deserialization,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl fmt::Display for VerificationFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use VerificationFailure::*;
+        match self {
+            OodConsistency => write!(f, "out-of-domain constraint evaluations are inconsistent"),
+            MerkleAuthentication => write!(f, "queried values do not match their commitments"),
+            Fri => write!(f, "FRI proof verification failed"),
+            ProofOfWork => write!(f, "query seed proof-of-work verification failed"),
+            Deserialization => write!(f, "proof deserialization failed"),
+            Other => write!(f, "proof verification failed"),
+        }
+    }
+}